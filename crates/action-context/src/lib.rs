@@ -1,6 +1,7 @@
 use clap::ValueEnum;
 use moon_affected::Affected;
 use moon_common::path::WorkspaceRelativePathBuf;
+use moon_common::FileWriter;
 use moon_target::Target;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -42,6 +43,10 @@ pub struct ActionContext {
     /// Projects and tasks that are affected (via `--affected`).
     pub affected: Option<Affected>,
 
+    /// Writes files when syncing, or captures them when only checking (via `--check`).
+    #[serde(skip)]
+    pub file_writer: FileWriter,
+
     /// Initial target locators passed to `moon run`, `moon ci`, etc.
     pub initial_targets: FxHashSet<Target>,
 
@@ -99,6 +104,10 @@ impl ActionContext {
         map
     }
 
+    pub fn is_check_only(&self) -> bool {
+        self.file_writer.is_check_only()
+    }
+
     pub fn is_primary_target<T: AsRef<Target>>(&self, target: T) -> bool {
         self.primary_targets.contains(target.as_ref())
     }
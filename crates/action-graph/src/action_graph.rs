@@ -2,6 +2,7 @@ use crate::action_graph_error::ActionGraphError;
 use graph_cycles::Cycles;
 use moon_action::ActionNode;
 use moon_common::{color, is_test_env};
+use moon_graph_utils::{format_cytoscape, format_graphml, format_mermaid};
 use petgraph::dot::{Config, Dot};
 use petgraph::prelude::*;
 use petgraph::visit::{IntoEdgeReferences, IntoNodeReferences};
@@ -136,6 +137,18 @@ impl ActionGraph {
 
         format!("{dot:?}")
     }
+
+    pub fn to_mermaid(&self) -> String {
+        format_mermaid(&self.labeled_graph())
+    }
+
+    pub fn to_graphml(&self) -> String {
+        format_graphml(&self.labeled_graph())
+    }
+
+    pub fn to_cytoscape(&self) -> miette::Result<String> {
+        format_cytoscape(&self.labeled_graph())
+    }
 }
 
 pub struct ActionGraphIter<'graph> {
@@ -4,7 +4,7 @@ use moon_action::{
     SyncProjectNode,
 };
 use moon_action_context::{ActionContext, TargetState};
-use moon_affected::{AffectedTracker, DownstreamScope, UpstreamScope};
+use moon_affected::{AffectedTracker, DownstreamScope, ManifestDiff, UpstreamScope};
 use moon_common::path::WorkspaceRelativePathBuf;
 use moon_common::{color, Id};
 use moon_config::{PlatformType, TaskDependencyConfig};
@@ -154,6 +154,20 @@ impl<'app> ActionGraphBuilder<'app> {
         Ok(())
     }
 
+    pub fn set_manifest_diffs(
+        &mut self,
+        manifest_diffs: FxHashMap<WorkspaceRelativePathBuf, ManifestDiff>,
+        hoisted_dependencies: &[String],
+    ) -> miette::Result<()> {
+        self.affected
+            .as_mut()
+            .expect("Affected tracker not set!")
+            .with_manifest_diffs(manifest_diffs)
+            .with_hoisted_dependencies(hoisted_dependencies);
+
+        Ok(())
+    }
+
     pub fn set_query(&mut self, input: &'app str) -> miette::Result<()> {
         self.all_query = Some(build_query(input)?);
 
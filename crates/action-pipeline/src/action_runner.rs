@@ -71,6 +71,7 @@ pub async fn run_action(
                 action_context,
                 app_context,
                 workspace_graph.clone(),
+                toolchain_registry,
                 inner,
             )
             .await;
@@ -178,6 +179,7 @@ pub async fn run_action(
                 action_context,
                 app_context,
                 workspace_graph.clone(),
+                toolchain_registry,
                 inner,
             )
             .await;
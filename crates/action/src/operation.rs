@@ -4,6 +4,7 @@ use moon_time::chrono::NaiveDateTime;
 use moon_time::now_timestamp;
 use serde::{Deserialize, Serialize};
 use std::future::Future;
+use std::path::Path;
 use std::process::Output;
 use std::time::{Duration, Instant};
 
@@ -86,6 +87,7 @@ impl Operation {
     pub fn label(&self) -> &str {
         match &self.meta {
             OperationMeta::NoOperation => "NoOperation",
+            OperationMeta::FileMutation(_) => "FileMutation",
             OperationMeta::OutputHydration(_) => "OutputHydration",
             OperationMeta::ProcessExecution(_) => "ProcessExecution",
             OperationMeta::SyncOperation(_) => "SyncOperation",
@@ -213,6 +215,15 @@ impl Operation {
         Self::new(OperationMeta::ArchiveCreation)
     }
 
+    pub fn file_mutation(path: impl AsRef<Path>) -> Self {
+        Self::new(OperationMeta::FileMutation(Box::new(
+            OperationMetaFileMutation {
+                path: path.as_ref().to_path_buf(),
+                ..Default::default()
+            },
+        )))
+    }
+
     pub fn hash_generation() -> Self {
         Self::new(OperationMeta::HashGeneration(Default::default()))
     }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::process::ExitStatus;
 use std::sync::Arc;
 
@@ -7,6 +8,14 @@ pub struct OperationMetaHash {
     pub hash: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OperationMetaFileMutation {
+    #[serde(skip)]
+    pub diff: Option<String>,
+
+    pub path: PathBuf,
+}
+
 #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct OperationMetaLabel {
     pub label: String,
@@ -55,6 +64,7 @@ pub enum OperationMeta {
     // Processes
     #[default]
     NoOperation,
+    FileMutation(Box<OperationMetaFileMutation>),
     OutputHydration(Box<OperationMetaOutput>),
     ProcessExecution(Box<OperationMetaOutput>),
     SyncOperation(Box<OperationMetaLabel>),
@@ -75,6 +85,10 @@ impl OperationMeta {
         matches!(self, Self::HashGeneration(_))
     }
 
+    pub fn is_file_mutation(&self) -> bool {
+        matches!(self, Self::FileMutation(_))
+    }
+
     pub fn is_no_operation(&self) -> bool {
         matches!(self, Self::NoOperation)
     }
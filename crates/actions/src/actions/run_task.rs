@@ -3,16 +3,24 @@ use moon_action_context::ActionContext;
 use moon_app_context::AppContext;
 use moon_common::color;
 use moon_task_runner::TaskRunner;
+use moon_toolchain_plugin::ToolchainRegistry;
 use moon_workspace_graph::WorkspaceGraph;
 use std::sync::Arc;
 use tracing::{instrument, warn};
 
-#[instrument(skip(action, action_context, app_context, workspace_graph))]
+#[instrument(skip(
+    action,
+    action_context,
+    app_context,
+    workspace_graph,
+    toolchain_registry
+))]
 pub async fn run_task(
     action: &mut Action,
     action_context: Arc<ActionContext>,
     app_context: Arc<AppContext>,
     workspace_graph: WorkspaceGraph,
+    toolchain_registry: Arc<ToolchainRegistry>,
     node: &RunTaskNode,
 ) -> miette::Result<ActionStatus> {
     let project_id = node
@@ -26,9 +34,10 @@ pub async fn run_task(
     // and error is bubbled up the stack
     action.allow_failure = task.options.allow_failure;
 
-    let result = TaskRunner::new(&app_context, &project, &task)?
-        .run(&action_context, &action.node)
-        .await?;
+    let mut runner = TaskRunner::new(&app_context, &project, &task)?;
+    runner.set_toolchain_registry(toolchain_registry);
+
+    let result = runner.run(&action_context, &action.node).await?;
 
     action.flaky = result.operations.is_flaky();
     action.status = result.operations.get_final_status();
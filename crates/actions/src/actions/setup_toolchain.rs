@@ -48,6 +48,17 @@ pub async fn setup_toolchain(
         return Ok(ActionStatus::Skipped);
     }
 
+    // Installing tools writes outside of the workspace,
+    // so avoid it when only checking for pending changes
+    if action_context.is_check_only() {
+        debug!(
+            "Skipping {} toolchain setup because only checking for changes",
+            log_label
+        );
+
+        return Ok(ActionStatus::Skipped);
+    }
+
     debug!("Setting up {} toolchain", log_label);
 
     let mut state = cache_engine.state.load_state::<ToolCacheState>(format!(
@@ -1,20 +1,29 @@
+use crate::operations::run_plugin_operation;
 use crate::utils::should_skip_action_matching;
 use moon_action::{Action, ActionStatus, SyncProjectNode};
 use moon_action_context::ActionContext;
 use moon_app_context::AppContext;
 use moon_common::{color, is_ci};
 use moon_platform::PlatformManager;
+use moon_toolchain_plugin::ToolchainRegistry;
 use moon_workspace_graph::WorkspaceGraph;
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
 use tracing::{debug, instrument, warn};
 
-#[instrument(skip(_action, action_context, app_context, workspace_graph))]
+#[instrument(skip(
+    action,
+    action_context,
+    app_context,
+    workspace_graph,
+    toolchain_registry
+))]
 pub async fn sync_project(
-    _action: &mut Action,
+    action: &mut Action,
     action_context: Arc<ActionContext>,
     app_context: Arc<AppContext>,
     workspace_graph: WorkspaceGraph,
+    toolchain_registry: Arc<ToolchainRegistry>,
     node: &SyncProjectNode,
 ) -> miette::Result<ActionStatus> {
     // Include tasks for snapshot!
@@ -56,6 +65,36 @@ pub async fn sync_project(
         .sync_project(&action_context, &project, &dependencies)
         .await?;
 
+    // Then sync the project through each toolchain plugin,
+    // and run the operations they return
+    if toolchain_registry.has_plugins() {
+        let sync_context = toolchain_registry.create_context();
+        let dependency_list = dependencies
+            .values()
+            .map(|dep| dep.as_ref())
+            .collect::<Vec<_>>();
+
+        for plugin_id in toolchain_registry.get_plugin_ids() {
+            let plugin = toolchain_registry.load(plugin_id).await?;
+
+            if !plugin.applies_to_project(&project) {
+                continue;
+            }
+
+            if let Some(result) = plugin
+                .sync_project(&project, &dependency_list, sync_context.clone())
+                .await?
+            {
+                for op in result.operations {
+                    action.operations.push(
+                        run_plugin_operation(&app_context, op, action_context.is_check_only())
+                            .await?,
+                    );
+                }
+            }
+        }
+    }
+
     // If files have been modified in CI, we should update the status to warning,
     // as these modifications should be committed to the repo!
     if mutated_files && is_ci() {
@@ -1,5 +1,6 @@
 use crate::operations::{
-    run_plugin_operation, sync_codeowners, sync_config_schemas, sync_vcs_hooks,
+    check_codeowners, check_vcs_hooks, run_plugin_operation, sync_codeowners, sync_config_schemas,
+    sync_vcs_hooks,
 };
 use crate::utils::should_skip_action;
 use miette::IntoDiagnostic;
@@ -17,7 +18,7 @@ use tracing::{debug, instrument};
 #[instrument(skip_all)]
 pub async fn sync_workspace(
     action: &mut Action,
-    _action_context: Arc<ActionContext>,
+    action_context: Arc<ActionContext>,
     app_context: Arc<AppContext>,
     workspace_graph: WorkspaceGraph,
     toolchain_registry: Arc<ToolchainRegistry>,
@@ -40,10 +41,14 @@ pub async fn sync_workspace(
 
     debug!("Syncing workspace");
 
+    let check_only = action_context.is_check_only();
+
     // Run operations in parallel
     let mut operation_futures: Vec<task::JoinHandle<miette::Result<Vec<Operation>>>> = vec![];
 
-    {
+    // Schemas are written to the cache and are not committed,
+    // so there's no need to check them for pending changes
+    if !check_only {
         debug!("Syncing config schemas");
 
         let app_context = Arc::clone(&app_context);
@@ -69,6 +74,10 @@ pub async fn sync_workspace(
         let app_context = Arc::clone(&app_context);
 
         operation_futures.push(task::spawn(async move {
+            if check_only {
+                return check_codeowners(&app_context, &workspace_graph);
+            }
+
             let op = Operation::sync_operation("Codeowners")
                 .track_async_with_check(
                     || sync_codeowners(&app_context, &workspace_graph, false),
@@ -90,6 +99,10 @@ pub async fn sync_workspace(
         let app_context = Arc::clone(&app_context);
 
         operation_futures.push(task::spawn(async move {
+            if check_only {
+                return check_vcs_hooks(&app_context).await;
+            }
+
             let op = Operation::sync_operation("VCS hooks")
                 .track_async_with_check(|| sync_vcs_hooks(&app_context, false), |result| result)
                 .await?;
@@ -116,11 +129,13 @@ pub async fn sync_workspace(
         }
 
         for result in sync_results {
+            let app_context = Arc::clone(&app_context);
+
             operation_futures.push(task::spawn(async move {
                 let mut ops = vec![];
 
                 for op in result.operations {
-                    ops.push(run_plugin_operation(op).await?);
+                    ops.push(run_plugin_operation(&app_context, op, check_only).await?);
                 }
 
                 Ok(ops)
@@ -0,0 +1,43 @@
+use crate::utils::create_unified_diff;
+use moon_action::{ActionStatus, Operation, OperationMeta};
+use starbase_utils::fs;
+use std::path::Path;
+use tracing::debug;
+
+/// Compare the contents of a file on disk against the contents that a sync
+/// would write (`None` if the file would be removed), without applying them.
+/// If they differ, the operation is marked as invalid and includes a diff.
+pub fn check_file_mutation(
+    workspace_root: &Path,
+    path: &Path,
+    next: Option<&str>,
+) -> miette::Result<Operation> {
+    let mut op = Operation::file_mutation(path);
+
+    let current = if path.exists() {
+        Some(fs::read_file(path)?)
+    } else {
+        None
+    };
+
+    if current.as_deref() == next {
+        op.finish(ActionStatus::Skipped);
+
+        return Ok(op);
+    }
+
+    debug!(file = ?path, "File has pending changes, but only checking");
+
+    if let OperationMeta::FileMutation(meta) = &mut op.meta {
+        meta.diff = Some(create_unified_diff(
+            workspace_root,
+            path,
+            current.as_deref(),
+            next,
+        ));
+    }
+
+    op.finish(ActionStatus::Invalid);
+
+    Ok(op)
+}
@@ -1,8 +1,12 @@
+mod check_file_mutation;
+mod plugin_operation_error;
 mod run_plugin_operation;
 mod sync_codeowners;
 mod sync_config_schemas;
 mod sync_vcs_hooks;
 
+pub use check_file_mutation::*;
+pub use plugin_operation_error::*;
 pub use run_plugin_operation::*;
 pub use sync_codeowners::*;
 pub use sync_config_schemas::*;
@@ -0,0 +1,21 @@
+use miette::Diagnostic;
+use starbase_styles::{Style, Stylize};
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug, Diagnostic)]
+pub enum PluginOperationError {
+    #[diagnostic(code(plugin_operation::outside_workspace))]
+    #[error(
+        "Unable to modify file {}, as it's outside of the workspace. Plugins may only modify files within the workspace.",
+        .path.style(Style::Path),
+    )]
+    OutsideWorkspace { path: PathBuf },
+
+    #[diagnostic(code(plugin_operation::unresolved_path))]
+    #[error(
+        "Unable to modify file {}, as the virtual path could not be resolved to a real path.",
+        .path.style(Style::Path),
+    )]
+    UnresolvedPath { path: PathBuf },
+}
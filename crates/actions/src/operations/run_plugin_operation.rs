@@ -1,10 +1,74 @@
-use moon_action::Operation;
-use moon_pdk_api::Operation as PluginOperation;
+use super::check_file_mutation;
+use super::plugin_operation_error::PluginOperationError;
+use miette::IntoDiagnostic;
+use moon_action::{ActionStatus, Operation};
+use moon_app_context::AppContext;
+use moon_pdk_api::{FileMergeInput, Operation as PluginOperation, VirtualPath};
 use moon_process::Command;
+use starbase_utils::fs;
+use starbase_utils::json::{self, JsonValue};
+use starbase_utils::toml::{self, TomlValue};
+use std::path::{Component, Path, PathBuf};
+use tracing::debug;
+
+/// Run an operation returned from a plugin. If `check_only` is true,
+/// file based operations will not be applied, and will instead be marked
+/// as invalid (with a diff) when the file would have been changed.
+pub async fn run_plugin_operation(
+    app_context: &AppContext,
+    operation: PluginOperation,
+    check_only: bool,
+) -> miette::Result<Operation> {
+    let workspace_root = &app_context.workspace_root;
 
-pub async fn run_plugin_operation(operation: PluginOperation) -> miette::Result<Operation> {
     match operation {
+        PluginOperation::FileDelete(input) => {
+            let path = to_real_path(workspace_root, &input.path)?;
+
+            track_file_mutation(workspace_root, &path, check_only, || {
+                Ok(path.exists().then_some(FileChange::Delete))
+            })
+        }
+        PluginOperation::FileWrite(input) => {
+            let path = to_real_path(workspace_root, &input.path)?;
+
+            track_file_mutation(workspace_root, &path, check_only, || {
+                Ok(
+                    if read_file_if_exists(&path)?.is_some_and(|content| content == input.content) {
+                        None
+                    } else {
+                        Some(FileChange::Write(input.content))
+                    },
+                )
+            })
+        }
+        PluginOperation::JsonMerge(input) => {
+            let path = to_real_path(workspace_root, &input.path)?;
+
+            track_file_mutation(workspace_root, &path, check_only, || {
+                merge_json_file(&path, input)
+            })
+        }
+        PluginOperation::TomlMerge(input) => {
+            let path = to_real_path(workspace_root, &input.path)?;
+
+            track_file_mutation(workspace_root, &path, check_only, || {
+                merge_toml_file(&path, input)
+            })
+        }
         PluginOperation::ProcessExecution(process) => {
+            if check_only {
+                debug!(
+                    command = &process.command,
+                    "Not executing process as only checking for changes"
+                );
+
+                let mut op = Operation::process_execution(&process.command);
+                op.finish(ActionStatus::Skipped);
+
+                return Ok(op);
+            }
+
             Operation::process_execution(&process.command)
                 .track_async_with_check(
                     || async {
@@ -30,3 +94,177 @@ pub async fn run_plugin_operation(operation: PluginOperation) -> miette::Result<
         }
     }
 }
+
+/// Resolve the virtual path to a real path, and ensure it's within the workspace,
+/// as plugins should never be able to modify files outside of it.
+fn to_real_path(workspace_root: &Path, path: &VirtualPath) -> miette::Result<PathBuf> {
+    let Some(real_path) = path.real_path() else {
+        return Err(PluginOperationError::UnresolvedPath {
+            path: path.to_path_buf(),
+        }
+        .into());
+    };
+
+    // Resolve `..` and `.` lexically, as the file may not exist yet
+    let mut normalized = PathBuf::new();
+
+    for component in real_path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        };
+    }
+
+    if normalized == workspace_root || !normalized.starts_with(workspace_root) {
+        return Err(PluginOperationError::OutsideWorkspace { path: real_path }.into());
+    }
+
+    Ok(normalized)
+}
+
+fn read_file_if_exists(path: &Path) -> miette::Result<Option<String>> {
+    Ok(if path.exists() {
+        Some(fs::read_file(path)?)
+    } else {
+        None
+    })
+}
+
+enum FileChange {
+    Delete,
+    Write(String),
+}
+
+/// Track a mutation to the provided file. The callback must return `None`
+/// if nothing has changed, otherwise the change to apply.
+fn track_file_mutation(
+    workspace_root: &Path,
+    path: &Path,
+    check_only: bool,
+    get_change: impl FnOnce() -> miette::Result<Option<FileChange>>,
+) -> miette::Result<Operation> {
+    let mut op = Operation::file_mutation(path);
+
+    let change = match get_change() {
+        Ok(change) => change,
+        Err(error) => {
+            op.finish(ActionStatus::Failed);
+
+            return Err(error);
+        }
+    };
+
+    let Some(change) = change else {
+        op.finish(ActionStatus::Skipped);
+
+        return Ok(op);
+    };
+
+    if check_only {
+        return check_file_mutation(
+            workspace_root,
+            path,
+            match &change {
+                FileChange::Delete => None,
+                FileChange::Write(content) => Some(content.as_str()),
+            },
+        );
+    }
+
+    op.track(|| {
+        match change {
+            FileChange::Delete => fs::remove_file(path)?,
+            FileChange::Write(content) => fs::write_file(path, content)?,
+        };
+
+        Ok(())
+    })
+}
+
+fn merge_json_file(path: &Path, input: FileMergeInput) -> miette::Result<Option<FileChange>> {
+    let current = read_file_if_exists(path)?;
+    let prev: JsonValue = match &current {
+        Some(content) => json::parse(content)?,
+        None => JsonValue::Object(Default::default()),
+    };
+
+    let next = json::merge(&prev, &input.data);
+
+    if current.is_some() && prev == next {
+        return Ok(None);
+    }
+
+    let editor_config = fs::get_editor_config_props(path)?;
+    let mut content = json::format_with_identation(&next, &editor_config.indent)?;
+    editor_config.apply_eof(&mut content);
+
+    Ok(Some(FileChange::Write(content)))
+}
+
+fn merge_toml_file(path: &Path, input: FileMergeInput) -> miette::Result<Option<FileChange>> {
+    let current = read_file_if_exists(path)?;
+    let prev: TomlValue = match &current {
+        Some(content) => toml::parse(content)?,
+        None => TomlValue::Table(Default::default()),
+    };
+
+    let next = merge_toml(&prev, &input.data)?;
+
+    if current.is_some() && prev == next {
+        return Ok(None);
+    }
+
+    Ok(Some(FileChange::Write(toml::format(&next, true)?)))
+}
+
+/// Merge JSON data into a TOML value. Since TOML has no concept of null,
+/// null values in the data will remove the key instead.
+fn merge_toml(prev: &TomlValue, next: &JsonValue) -> miette::Result<TomlValue> {
+    match (prev, next) {
+        (TomlValue::Table(prev_table), JsonValue::Object(next_map)) => {
+            let mut table = prev_table.clone();
+
+            for (key, value) in next_map {
+                if value.is_null() {
+                    table.remove(key);
+                    continue;
+                }
+
+                let value = match prev_table.get(key) {
+                    Some(prev_value) => merge_toml(prev_value, value)?,
+                    None => convert_to_toml(value)?,
+                };
+
+                table.insert(key.to_owned(), value);
+            }
+
+            Ok(TomlValue::Table(table))
+        }
+        _ => convert_to_toml(next),
+    }
+}
+
+fn convert_to_toml(value: &JsonValue) -> miette::Result<TomlValue> {
+    fn remove_nulls(value: &JsonValue) -> JsonValue {
+        match value {
+            JsonValue::Array(list) => JsonValue::Array(
+                list.iter()
+                    .filter(|item| !item.is_null())
+                    .map(remove_nulls)
+                    .collect(),
+            ),
+            JsonValue::Object(map) => JsonValue::Object(
+                map.iter()
+                    .filter(|(_, item)| !item.is_null())
+                    .map(|(key, item)| (key.to_owned(), remove_nulls(item)))
+                    .collect(),
+            ),
+            other => other.to_owned(),
+        }
+    }
+
+    TomlValue::try_from(remove_nulls(value)).into_diagnostic()
+}
@@ -1,22 +1,19 @@
+use super::check_file_mutation;
+use moon_action::Operation;
 use moon_app_context::AppContext;
-use moon_codeowners::{CodeownersGenerator, CodeownersHash};
+use moon_codeowners::{
+    CodeownersGenerator, CodeownersHash, CodeownersMapGenerator, CodeownersResolver,
+};
 use moon_config::CodeownersOrderBy;
+use moon_project::Project;
 use moon_workspace_graph::WorkspaceGraph;
 use std::path::PathBuf;
 use tracing::instrument;
 
-#[instrument(skip_all)]
-pub async fn sync_codeowners(
+fn get_sorted_projects<'graph>(
     app_context: &AppContext,
-    workspace_graph: &WorkspaceGraph,
-    force: bool,
-) -> miette::Result<Option<PathBuf>> {
-    let mut generator = CodeownersGenerator::new(
-        &app_context.workspace_root,
-        app_context.workspace_config.vcs.provider,
-    )?;
-
-    // Sort the projects based on config
+    workspace_graph: &'graph WorkspaceGraph,
+) -> Vec<&'graph Project> {
     let mut projects = workspace_graph.projects.get_all_unexpanded();
     let order_by = app_context.workspace_config.codeowners.order_by;
 
@@ -25,8 +22,17 @@ pub async fn sync_codeowners(
         CodeownersOrderBy::ProjectName => a.id.cmp(&d.id),
     });
 
-    // Generate a hash for the codeowners file
-    let mut codeowners_hash = CodeownersHash::new(&app_context.workspace_config.codeowners);
+    projects
+}
+
+fn create_generator(
+    app_context: &AppContext,
+    projects: &[&Project],
+) -> miette::Result<CodeownersGenerator> {
+    let mut generator = CodeownersGenerator::new(
+        &app_context.workspace_root,
+        app_context.workspace_config.vcs.provider,
+    )?;
 
     if !app_context
         .workspace_config
@@ -39,8 +45,6 @@ pub async fn sync_codeowners(
 
     for project in projects {
         if !project.config.owners.paths.is_empty() {
-            codeowners_hash.add_project(&project.id, &project.config.owners);
-
             generator.add_project_entry(
                 &project.id,
                 project.source.as_str(),
@@ -50,12 +54,80 @@ pub async fn sync_codeowners(
         }
     }
 
+    Ok(generator)
+}
+
+fn create_map_generator(
+    app_context: &AppContext,
+    projects: &[&Project],
+) -> miette::Result<Option<CodeownersMapGenerator>> {
+    if !app_context.workspace_config.codeowners.ownership_map {
+        return Ok(None);
+    }
+
+    let mut generator = CodeownersMapGenerator::new(&app_context.workspace_root);
+
+    generator.add_workspace_entries(&app_context.workspace_config.codeowners)?;
+
+    for project in projects {
+        generator.add_project_entry(
+            &project.id,
+            project.source.as_str(),
+            &project.config.owners,
+            &app_context.workspace_config.codeowners,
+        )?;
+    }
+
+    Ok(Some(generator))
+}
+
+/// Create a resolver with the same rules, in the same order,
+/// as the generated `CODEOWNERS` file.
+pub fn create_codeowners_resolver(
+    app_context: &AppContext,
+    workspace_graph: &WorkspaceGraph,
+) -> miette::Result<CodeownersResolver> {
+    let mut resolver = CodeownersResolver::new(&app_context.workspace_root);
+
+    resolver.add_workspace_entries(&app_context.workspace_config.codeowners)?;
+
+    for project in get_sorted_projects(app_context, workspace_graph) {
+        resolver.add_project_entry(&project.id, project.source.as_str(), &project.config.owners)?;
+    }
+
+    Ok(resolver)
+}
+
+#[instrument(skip_all)]
+pub async fn sync_codeowners(
+    app_context: &AppContext,
+    workspace_graph: &WorkspaceGraph,
+    force: bool,
+) -> miette::Result<Option<PathBuf>> {
+    // Sort the projects based on config
+    let projects = get_sorted_projects(app_context, workspace_graph);
+    let generator = create_generator(app_context, &projects)?;
+    let map_generator = create_map_generator(app_context, &projects)?;
+
+    // Generate a hash for the codeowners file
+    let mut codeowners_hash = CodeownersHash::new(&app_context.workspace_config.codeowners);
+
+    for project in &projects {
+        if !project.config.owners.paths.is_empty() {
+            codeowners_hash.add_project(&project.id, &project.config.owners);
+        }
+    }
+
     let file_path = generator.file_path.clone();
 
     // Force run the generator and bypass cache
     if force {
         generator.generate()?;
 
+        if let Some(map_generator) = map_generator {
+            map_generator.generate()?;
+        }
+
         return Ok(Some(file_path));
     }
 
@@ -63,6 +135,10 @@ pub async fn sync_codeowners(
     if app_context
         .cache_engine
         .execute_if_changed("codeowners.json", codeowners_hash, || async {
+            if let Some(map_generator) = map_generator {
+                map_generator.generate()?;
+            }
+
             generator.generate()
         })
         .await?
@@ -73,6 +149,31 @@ pub async fn sync_codeowners(
     Ok(None)
 }
 
+#[instrument(skip_all)]
+pub fn check_codeowners(
+    app_context: &AppContext,
+    workspace_graph: &WorkspaceGraph,
+) -> miette::Result<Vec<Operation>> {
+    let projects = get_sorted_projects(app_context, workspace_graph);
+    let generator = create_generator(app_context, &projects)?;
+
+    let mut ops = vec![check_file_mutation(
+        &app_context.workspace_root,
+        &generator.file_path,
+        Some(&generator.content),
+    )?];
+
+    if let Some(map_generator) = create_map_generator(app_context, &projects)? {
+        ops.push(check_file_mutation(
+            &app_context.workspace_root,
+            &map_generator.file_path,
+            Some(&map_generator.render()?),
+        )?);
+    }
+
+    Ok(ops)
+}
+
 #[instrument(skip_all)]
 pub async fn unsync_codeowners(app_context: &AppContext) -> miette::Result<PathBuf> {
     let codeowners = CodeownersGenerator::new(
@@ -84,5 +185,7 @@ pub async fn unsync_codeowners(app_context: &AppContext) -> miette::Result<PathB
 
     codeowners.cleanup()?;
 
+    CodeownersMapGenerator::new(&app_context.workspace_root).cleanup()?;
+
     Ok(file_path)
 }
@@ -1,8 +1,11 @@
+use super::check_file_mutation;
+use moon_action::Operation;
 use moon_app_context::AppContext;
 use moon_common::color;
 use moon_config::Version;
 use moon_config_schema::json_schemas::generate_json_schemas;
 use moon_hash::hash_content;
+use starbase_utils::fs;
 use tracing::{instrument, warn};
 
 hash_content!(
@@ -39,3 +42,28 @@ pub async fn sync_config_schemas(app_context: &AppContext, force: bool) -> miett
 
     Ok(true)
 }
+
+#[instrument(skip_all)]
+pub async fn check_config_schemas(app_context: &AppContext) -> miette::Result<Vec<Operation>> {
+    let out_dir = app_context.cache_engine.cache_dir.join("schemas");
+    let temp_dir = app_context.cache_engine.temp_dir.join("schemas");
+    let mut ops = vec![];
+
+    // Schemas can only be rendered to the file system,
+    // so generate them in a temporary location to compare against
+    generate_json_schemas(&temp_dir)?;
+
+    for temp_file in fs::read_dir(&temp_dir)? {
+        let contents = fs::read_file(temp_file.path())?;
+
+        ops.push(check_file_mutation(
+            &app_context.workspace_root,
+            &out_dir.join(temp_file.file_name()),
+            Some(&contents),
+        )?);
+    }
+
+    fs::remove_dir_all(&temp_dir)?;
+
+    Ok(ops)
+}
@@ -1,3 +1,5 @@
+use super::check_file_mutation;
+use moon_action::Operation;
 use moon_app_context::AppContext;
 use moon_vcs_hooks::{HooksGenerator, HooksHash};
 use tracing::instrument;
@@ -30,6 +32,26 @@ pub async fn sync_vcs_hooks(app_context: &AppContext, force: bool) -> miette::Re
         .await
 }
 
+#[instrument(skip_all)]
+pub async fn check_vcs_hooks(app_context: &AppContext) -> miette::Result<Vec<Operation>> {
+    let generator = HooksGenerator::new(
+        &app_context.vcs,
+        &app_context.workspace_config.vcs,
+        &app_context.workspace_root,
+    );
+    let mut ops = vec![];
+
+    for (file_path, contents) in generator.render().await? {
+        ops.push(check_file_mutation(
+            &app_context.workspace_root,
+            &file_path,
+            Some(&contents),
+        )?);
+    }
+
+    Ok(ops)
+}
+
 #[instrument(skip_all)]
 pub async fn unsync_vcs_hooks(app_context: &AppContext) -> miette::Result<()> {
     HooksGenerator::new(
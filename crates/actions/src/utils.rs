@@ -1,4 +1,6 @@
+use similar::TextDiff;
 use std::env;
+use std::path::Path;
 
 pub fn should_skip_action(key: &str) -> Option<String> {
     should_skip_action_matching(key, "")
@@ -14,6 +16,37 @@ pub fn should_skip_action_matching<V: AsRef<str>>(key: &str, pattern: V) -> Opti
     None
 }
 
+/// Create a unified diff between the current contents of a file (`None` if it
+/// does not exist), and the contents that would be written (`None` if deleted).
+pub fn create_unified_diff(
+    workspace_root: &Path,
+    path: &Path,
+    current: Option<&str>,
+    next: Option<&str>,
+) -> String {
+    let label = path
+        .strip_prefix(workspace_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    TextDiff::from_lines(current.unwrap_or_default(), next.unwrap_or_default())
+        .unified_diff()
+        .header(
+            &if current.is_some() {
+                format!("a/{label}")
+            } else {
+                "/dev/null".into()
+            },
+            &if next.is_some() {
+                format!("b/{label}")
+            } else {
+                "/dev/null".into()
+            },
+        )
+        .to_string()
+}
+
 fn matches_pattern(value: &str, pattern: &str) -> bool {
     if value.contains(',') {
         return value.split(',').any(|v| matches_pattern(v, pattern));
@@ -77,4 +110,24 @@ mod tests {
         assert!(matches_pattern("foo,bar", "bar"));
         assert!(!matches_pattern("foo,bar", "baz"));
     }
+
+    #[test]
+    fn unified_diffs() {
+        let root = Path::new("/root");
+
+        assert_eq!(
+            create_unified_diff(root, &root.join("file.txt"), Some("a\nb\n"), Some("a\nc\n")),
+            "--- a/file.txt\n+++ b/file.txt\n@@ -1,2 +1,2 @@\n a\n-b\n+c\n"
+        );
+
+        assert_eq!(
+            create_unified_diff(root, &root.join("file.txt"), None, Some("a\n")),
+            "--- /dev/null\n+++ b/file.txt\n@@ -0,0 +1 @@\n+a\n"
+        );
+
+        assert_eq!(
+            create_unified_diff(root, &root.join("file.txt"), Some("a\n"), Some("a\n")),
+            ""
+        );
+    }
 }
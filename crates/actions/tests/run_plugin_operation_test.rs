@@ -0,0 +1,356 @@
+use moon_action::{ActionStatus, Operation, OperationMeta};
+use moon_actions::operations::run_plugin_operation;
+use moon_app_context::AppContext;
+use moon_pdk_api::{
+    ExecCommandInput, FileDeleteInput, FileMergeInput, FileWriteInput,
+    Operation as PluginOperation, VirtualPath,
+};
+use moon_test_utils2::{create_empty_moon_sandbox, generate_app_context_from_sandbox, MoonSandbox};
+use starbase_utils::json::{self, json, JsonValue};
+use std::fs;
+
+fn create_context() -> (MoonSandbox, AppContext) {
+    let sandbox = create_empty_moon_sandbox();
+    let context = generate_app_context_from_sandbox(sandbox.path());
+
+    (sandbox, context)
+}
+
+fn to_virtual_path(sandbox: &MoonSandbox, file: &str) -> VirtualPath {
+    VirtualPath::OnlyReal(sandbox.path().join(file))
+}
+
+fn get_diff(op: &Operation) -> Option<&str> {
+    match &op.meta {
+        OperationMeta::FileMutation(meta) => meta.diff.as_deref(),
+        _ => None,
+    }
+}
+
+mod file_write {
+    use super::*;
+
+    fn create_write(sandbox: &MoonSandbox, content: &str) -> PluginOperation {
+        PluginOperation::FileWrite(FileWriteInput {
+            content: content.into(),
+            path: to_virtual_path(sandbox, "file.txt"),
+        })
+    }
+
+    #[tokio::test]
+    async fn writes_file() {
+        let (sandbox, context) = create_context();
+
+        let op = run_plugin_operation(&context, create_write(&sandbox, "content"), false)
+            .await
+            .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Passed);
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("file.txt")).unwrap(),
+            "content"
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_when_content_is_the_same() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.txt", "content");
+
+        let op = run_plugin_operation(&context, create_write(&sandbox, "content"), false)
+            .await
+            .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Skipped);
+    }
+
+    #[tokio::test]
+    async fn doesnt_write_when_checking() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.txt", "before\n");
+
+        let op = run_plugin_operation(&context, create_write(&sandbox, "after\n"), true)
+            .await
+            .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Invalid);
+        assert_eq!(
+            get_diff(&op).unwrap(),
+            "--- a/file.txt\n+++ b/file.txt\n@@ -1 +1 @@\n-before\n+after\n"
+        );
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("file.txt")).unwrap(),
+            "before\n"
+        );
+    }
+}
+
+mod file_delete {
+    use super::*;
+
+    fn create_delete(sandbox: &MoonSandbox) -> PluginOperation {
+        PluginOperation::FileDelete(FileDeleteInput {
+            path: to_virtual_path(sandbox, "file.txt"),
+        })
+    }
+
+    #[tokio::test]
+    async fn deletes_file() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.txt", "content");
+
+        let op = run_plugin_operation(&context, create_delete(&sandbox), false)
+            .await
+            .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Passed);
+        assert!(!sandbox.path().join("file.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn skips_when_file_doesnt_exist() {
+        let (sandbox, context) = create_context();
+
+        let op = run_plugin_operation(&context, create_delete(&sandbox), false)
+            .await
+            .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Skipped);
+    }
+
+    #[tokio::test]
+    async fn doesnt_delete_when_checking() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.txt", "content");
+
+        let op = run_plugin_operation(&context, create_delete(&sandbox), true)
+            .await
+            .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Invalid);
+        assert!(sandbox.path().join("file.txt").exists());
+    }
+}
+
+mod json_merge {
+    use super::*;
+
+    #[tokio::test]
+    async fn merges_into_existing_file() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.json", r#"{ "a": 1, "nested": { "b": 2 } }"#);
+
+        let op = run_plugin_operation(
+            &context,
+            PluginOperation::JsonMerge(FileMergeInput {
+                data: json!({ "nested": { "c": 3 } }),
+                path: to_virtual_path(&sandbox, "file.json"),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Passed);
+
+        let data: JsonValue = json::read_file(sandbox.path().join("file.json")).unwrap();
+
+        assert_eq!(data, json!({ "a": 1, "nested": { "b": 2, "c": 3 } }));
+    }
+
+    #[tokio::test]
+    async fn creates_missing_file() {
+        let (sandbox, context) = create_context();
+
+        run_plugin_operation(
+            &context,
+            PluginOperation::JsonMerge(FileMergeInput {
+                data: json!({ "a": 1 }),
+                path: to_virtual_path(&sandbox, "file.json"),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert!(sandbox.path().join("file.json").exists());
+    }
+
+    #[tokio::test]
+    async fn skips_when_data_is_the_same() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.json", r#"{ "a": 1 }"#);
+
+        let op = run_plugin_operation(
+            &context,
+            PluginOperation::JsonMerge(FileMergeInput {
+                data: json!({ "a": 1 }),
+                path: to_virtual_path(&sandbox, "file.json"),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Skipped);
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("file.json")).unwrap(),
+            r#"{ "a": 1 }"#
+        );
+    }
+}
+
+mod toml_merge {
+    use super::*;
+
+    #[tokio::test]
+    async fn merges_into_existing_file() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.toml", "[package]\nname = \"a\"\n");
+
+        let op = run_plugin_operation(
+            &context,
+            PluginOperation::TomlMerge(FileMergeInput {
+                data: json!({ "package": { "version": "1.0.0" } }),
+                path: to_virtual_path(&sandbox, "file.toml"),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Passed);
+
+        let content = fs::read_to_string(sandbox.path().join("file.toml")).unwrap();
+
+        assert!(content.contains("name = \"a\""));
+        assert!(content.contains("version = \"1.0.0\""));
+    }
+
+    #[tokio::test]
+    async fn doesnt_write_when_checking() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file("file.toml", "[package]\nname = \"a\"\n");
+
+        let op = run_plugin_operation(
+            &context,
+            PluginOperation::TomlMerge(FileMergeInput {
+                data: json!({ "package": { "name": "b" } }),
+                path: to_virtual_path(&sandbox, "file.toml"),
+            }),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Invalid);
+        assert!(get_diff(&op).unwrap().contains("+name = \"b\""));
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("file.toml")).unwrap(),
+            "[package]\nname = \"a\"\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_keys_with_null_values() {
+        let (sandbox, context) = create_context();
+        sandbox.create_file(
+            "file.toml",
+            "[package]\nname = \"a\"\nversion = \"0.0.0\"\n",
+        );
+
+        let op = run_plugin_operation(
+            &context,
+            PluginOperation::TomlMerge(FileMergeInput {
+                data: json!({ "package": { "version": null, "edition": "2021", "authors": ["a", null] } }),
+                path: to_virtual_path(&sandbox, "file.toml"),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Passed);
+
+        let content = fs::read_to_string(sandbox.path().join("file.toml")).unwrap();
+
+        assert!(content.contains("name = \"a\""));
+        assert!(content.contains("edition = \"2021\""));
+        assert!(content.contains("authors = [\"a\"]"));
+        assert!(!content.contains("version"));
+    }
+}
+
+mod paths {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "outside of the workspace")]
+    async fn errors_when_writing_outside_workspace() {
+        let (sandbox, context) = create_context();
+
+        run_plugin_operation(
+            &context,
+            PluginOperation::FileWrite(FileWriteInput {
+                content: "content".into(),
+                path: to_virtual_path(&sandbox, "../file.txt"),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "outside of the workspace")]
+    async fn errors_when_deleting_outside_workspace() {
+        let (_sandbox, context) = create_context();
+
+        run_plugin_operation(
+            &context,
+            PluginOperation::FileDelete(FileDeleteInput {
+                path: VirtualPath::OnlyReal(std::env::temp_dir().join("file.txt")),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "outside of the workspace")]
+    async fn errors_when_merging_outside_workspace() {
+        let (sandbox, context) = create_context();
+
+        run_plugin_operation(
+            &context,
+            PluginOperation::JsonMerge(FileMergeInput {
+                data: json!({ "key": "value" }),
+                path: to_virtual_path(&sandbox, "nested/../../file.json"),
+            }),
+            false,
+        )
+        .await
+        .unwrap();
+    }
+}
+
+mod process_execution {
+    use super::*;
+
+    #[tokio::test]
+    async fn skips_when_checking() {
+        let (_sandbox, context) = create_context();
+
+        let op = run_plugin_operation(
+            &context,
+            PluginOperation::ProcessExecution(ExecCommandInput::pipe("unknown-bin", ["arg"])),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(op.status, ActionStatus::Skipped);
+        assert!(op.meta.is_process_execution());
+    }
+}
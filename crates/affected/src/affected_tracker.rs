@@ -1,4 +1,5 @@
 use crate::affected::*;
+use crate::manifest_diff::*;
 use moon_common::path::WorkspaceRelativePathBuf;
 use moon_common::{color, Id};
 use moon_project::Project;
@@ -7,11 +8,15 @@ use moon_workspace_graph::{GraphConnections, WorkspaceGraph};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::env;
 use std::fmt;
+use std::sync::{Arc, RwLock};
 use tracing::{debug, trace};
 
 pub struct AffectedTracker<'app> {
     workspace_graph: &'app WorkspaceGraph,
     touched_files: &'app FxHashSet<WorkspaceRelativePathBuf>,
+    manifest_diffs: FxHashMap<WorkspaceRelativePathBuf, ManifestDiff>,
+    hoisted_dependencies: FxHashSet<String>,
+    declared_dependencies: RwLock<FxHashMap<(Id, ManifestKind), Arc<FxHashSet<String>>>>,
 
     projects: FxHashMap<Id, FxHashSet<AffectedBy>>,
     project_downstream: DownstreamScope,
@@ -32,6 +37,9 @@ impl<'app> AffectedTracker<'app> {
         Self {
             workspace_graph,
             touched_files,
+            manifest_diffs: FxHashMap::default(),
+            hoisted_dependencies: FxHashSet::default(),
+            declared_dependencies: RwLock::new(FxHashMap::default()),
             projects: FxHashMap::default(),
             project_downstream: DownstreamScope::None,
             project_upstream: UpstreamScope::Deep,
@@ -82,6 +90,31 @@ impl<'app> AffectedTracker<'app> {
         affected
     }
 
+    pub fn with_manifest_diffs(
+        &mut self,
+        manifest_diffs: FxHashMap<WorkspaceRelativePathBuf, ManifestDiff>,
+    ) -> &mut Self {
+        debug!(
+            files = ?manifest_diffs.keys().map(|file| file.as_str()).collect::<Vec<_>>(),
+            "Using content-aware diffs for manifests"
+        );
+
+        self.manifest_diffs = manifest_diffs;
+        self
+    }
+
+    pub fn with_hoisted_dependencies<I, V>(&mut self, names: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        self.hoisted_dependencies = names
+            .into_iter()
+            .map(|name| name.as_ref().to_owned())
+            .collect();
+        self
+    }
+
     pub fn with_project_scopes(
         &mut self,
         upstream_scope: UpstreamScope,
@@ -338,7 +371,9 @@ impl<'app> AffectedTracker<'app> {
         let globset = task.create_globset()?;
 
         for file in self.touched_files.iter() {
-            if task.input_files.contains(file) || globset.matches(file.as_str()) {
+            if (task.input_files.contains(file) || globset.matches(file.as_str()))
+                && self.is_manifest_change_relevant(task, file)?
+            {
                 return Ok(Some(AffectedBy::TouchedFile(file.to_owned())));
             }
         }
@@ -346,6 +381,89 @@ impl<'app> AffectedTracker<'app> {
         Ok(None)
     }
 
+    /// When a shared manifest has only changed dependencies, and the task matched
+    /// it through a glob, the task is only affected if its project declares one
+    /// of them, the task runs one of them, or one of them is hoisted.
+    fn is_manifest_change_relevant(
+        &self,
+        task: &Task,
+        file: &WorkspaceRelativePathBuf,
+    ) -> miette::Result<bool> {
+        let (Some(diff), Some(kind)) = (self.manifest_diffs.get(file), ManifestKind::detect(file))
+        else {
+            return Ok(true);
+        };
+
+        // The manifest was explicitly configured as an input
+        if task.input_files.contains(file) {
+            return Ok(true);
+        }
+
+        if diff.other {
+            return Ok(true);
+        }
+
+        let Some(project_id) = task.target.get_project_id() else {
+            return Ok(true);
+        };
+
+        let project = self.workspace_graph.get_project(project_id)?;
+
+        // The project owns the manifest
+        if project.is_root_level() {
+            return Ok(true);
+        }
+
+        if diff.dependencies.contains(&task.command) {
+            return Ok(true);
+        }
+
+        // Hoisted dependencies are used by all projects without being declared
+        if diff
+            .dependencies
+            .iter()
+            .any(|name| self.hoisted_dependencies.contains(name))
+        {
+            return Ok(true);
+        }
+
+        let relevant = self
+            .get_declared_dependencies(&project, kind)
+            .iter()
+            .any(|name| diff.dependencies.contains(name));
+
+        if !relevant {
+            trace!(
+                task_target = task.target.as_str(),
+                file = file.as_str(),
+                "Manifest has changed, but not in a way that affects the task"
+            );
+        }
+
+        Ok(relevant)
+    }
+
+    fn get_declared_dependencies(
+        &self,
+        project: &Project,
+        kind: ManifestKind,
+    ) -> Arc<FxHashSet<String>> {
+        let key = (project.id.clone(), kind);
+
+        if let Some(names) = self.declared_dependencies.read().unwrap().get(&key) {
+            return Arc::clone(names);
+        }
+
+        let names = Arc::new(kind.load_declared_dependencies(&project.root));
+
+        self.declared_dependencies
+            .write()
+            .unwrap()
+            .insert(key, Arc::clone(&names));
+
+        names
+    }
+
     pub fn is_task_marked(&self, task: &Task) -> bool {
         self.tasks.contains_key(&task.target)
     }
@@ -489,6 +607,8 @@ impl fmt::Debug for AffectedTracker<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AffectedTracker")
             .field("touched_files", &self.touched_files)
+            .field("manifest_diffs", &self.manifest_diffs)
+            .field("hoisted_dependencies", &self.hoisted_dependencies)
             .field("projects", &self.projects)
             .field("project_downstream", &self.project_downstream)
             .field("project_upstream", &self.project_upstream)
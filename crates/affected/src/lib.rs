@@ -1,5 +1,7 @@
 mod affected;
 mod affected_tracker;
+mod manifest_diff;
 
 pub use affected::*;
 pub use affected_tracker::*;
+pub use manifest_diff::*;
@@ -0,0 +1,231 @@
+use moon_common::path::WorkspaceRelativePathBuf;
+use rustc_hash::FxHashSet;
+use starbase_utils::json::{self, JsonMap, JsonValue};
+use starbase_utils::toml;
+use std::path::Path;
+use tracing::trace;
+
+const PACKAGE_JSON_DEPENDENCIES: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "optionalDependencies",
+    "peerDependencies",
+];
+
+// Metadata fields that don't affect the resolution or behavior of other projects
+const PACKAGE_JSON_METADATA: [&str; 14] = [
+    "author",
+    "bugs",
+    "contributors",
+    "description",
+    "funding",
+    "homepage",
+    "keywords",
+    "license",
+    "main",
+    "name",
+    "private",
+    "repository",
+    "scripts",
+    "version",
+];
+
+const CARGO_TOML_DEPENDENCIES: [&str; 3] =
+    ["build-dependencies", "dependencies", "dev-dependencies"];
+
+// Fields that apply to every crate in the workspace
+const CARGO_TOML_WORKSPACE_WIDE: [&str; 3] = ["patch", "profile", "replace"];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ManifestKind {
+    CargoToml,
+    PackageJson,
+}
+
+impl ManifestKind {
+    /// Detect the kind of manifest from a touched file. Only manifests in the
+    /// workspace root are supported, as they are shared by all projects.
+    pub fn detect(file: &WorkspaceRelativePathBuf) -> Option<Self> {
+        match file.as_str() {
+            "Cargo.toml" => Some(Self::CargoToml),
+            "package.json" => Some(Self::PackageJson),
+            _ => None,
+        }
+    }
+
+    pub fn get_file_name(&self) -> &'static str {
+        match self {
+            Self::CargoToml => "Cargo.toml",
+            Self::PackageJson => "package.json",
+        }
+    }
+
+    pub fn parse(&self, content: &str) -> Option<JsonMap<String, JsonValue>> {
+        let value: Option<JsonValue> = match self {
+            Self::CargoToml => toml::parse(content).ok(),
+            Self::PackageJson => json::parse(content).ok(),
+        };
+
+        match value {
+            Some(JsonValue::Object(map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Load the names of all dependencies declared in a project's manifest.
+    pub fn load_declared_dependencies(&self, project_root: &Path) -> FxHashSet<String> {
+        let mut names = FxHashSet::default();
+
+        let Some(manifest) = std::fs::read_to_string(project_root.join(self.get_file_name()))
+            .ok()
+            .and_then(|content| self.parse(&content))
+        else {
+            return names;
+        };
+
+        let mut extract = |table: &JsonMap<String, JsonValue>, sections: &[&str]| {
+            for section in sections {
+                if let Some(JsonValue::Object(deps)) = table.get(*section) {
+                    names.extend(deps.keys().cloned());
+                }
+            }
+        };
+
+        match self {
+            Self::CargoToml => {
+                extract(&manifest, &CARGO_TOML_DEPENDENCIES);
+
+                if let Some(JsonValue::Object(targets)) = manifest.get("target") {
+                    for target in targets.values() {
+                        if let JsonValue::Object(target) = target {
+                            extract(target, &CARGO_TOML_DEPENDENCIES);
+                        }
+                    }
+                }
+            }
+            Self::PackageJson => {
+                extract(&manifest, &PACKAGE_JSON_DEPENDENCIES);
+            }
+        };
+
+        names
+    }
+}
+
+/// The structural difference of a manifest file between 2 revisions.
+#[derive(Debug, Default, PartialEq)]
+pub struct ManifestDiff {
+    /// Names of dependencies that were added, removed, or changed.
+    pub dependencies: FxHashSet<String>,
+
+    /// A field that may affect all projects has changed.
+    pub other: bool,
+}
+
+impl ManifestDiff {
+    /// Diff the before and after content of a manifest. If either side
+    /// could not be parsed, all fields are considered changed.
+    pub fn new(kind: ManifestKind, before: Option<&str>, after: Option<&str>) -> Self {
+        let (Some(before), Some(after)) = (
+            before.and_then(|content| kind.parse(content)),
+            after.and_then(|content| kind.parse(content)),
+        ) else {
+            trace!(
+                file = kind.get_file_name(),
+                "Unable to parse manifest, treating all fields as changed"
+            );
+
+            return Self {
+                other: true,
+                ..Default::default()
+            };
+        };
+
+        let mut diff = Self::default();
+
+        match kind {
+            ManifestKind::CargoToml => {
+                let empty = JsonMap::new();
+                let before_ws = get_table(&before, "workspace").unwrap_or(&empty);
+                let after_ws = get_table(&after, "workspace").unwrap_or(&empty);
+
+                for key in get_keys(before_ws, after_ws) {
+                    match key {
+                        "dependencies" => diff.diff_dependencies(before_ws, after_ws, key),
+                        "metadata" => {}
+                        _ => diff.diff_field(before_ws, after_ws, key),
+                    };
+                }
+
+                // Other top-level fields belong to the root crate
+                for key in CARGO_TOML_WORKSPACE_WIDE {
+                    diff.diff_field(&before, &after, key);
+                }
+            }
+            ManifestKind::PackageJson => {
+                for key in get_keys(&before, &after) {
+                    if PACKAGE_JSON_DEPENDENCIES.contains(&key) {
+                        diff.diff_dependencies(&before, &after, key);
+                    } else if !PACKAGE_JSON_METADATA.contains(&key) {
+                        diff.diff_field(&before, &after, key);
+                    }
+                }
+            }
+        };
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.other && self.dependencies.is_empty()
+    }
+
+    fn diff_dependencies(
+        &mut self,
+        before: &JsonMap<String, JsonValue>,
+        after: &JsonMap<String, JsonValue>,
+        key: &str,
+    ) {
+        let empty = JsonMap::new();
+        let before = get_table(before, key).unwrap_or(&empty);
+        let after = get_table(after, key).unwrap_or(&empty);
+
+        for name in get_keys(before, after) {
+            if before.get(name) != after.get(name) {
+                self.dependencies.insert(name.to_owned());
+            }
+        }
+    }
+
+    fn diff_field(
+        &mut self,
+        before: &JsonMap<String, JsonValue>,
+        after: &JsonMap<String, JsonValue>,
+        key: &str,
+    ) {
+        if before.get(key) != after.get(key) {
+            self.other = true;
+        }
+    }
+}
+
+fn get_table<'a>(
+    map: &'a JsonMap<String, JsonValue>,
+    key: &str,
+) -> Option<&'a JsonMap<String, JsonValue>> {
+    match map.get(key) {
+        Some(JsonValue::Object(table)) => Some(table),
+        _ => None,
+    }
+}
+
+fn get_keys<'a>(
+    before: &'a JsonMap<String, JsonValue>,
+    after: &'a JsonMap<String, JsonValue>,
+) -> FxHashSet<&'a str> {
+    before
+        .keys()
+        .chain(after.keys())
+        .map(|key| key.as_str())
+        .collect()
+}
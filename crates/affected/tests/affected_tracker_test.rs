@@ -957,3 +957,144 @@ mod affected_tasks {
         }
     }
 }
+
+mod affected_manifests {
+    use super::*;
+    use moon_common::path::WorkspaceRelativePathBuf;
+    use moon_test_utils2::generate_workspace_graph_from_sandbox;
+    use starbase_sandbox::create_sandbox;
+
+    fn create_diff(dependencies: &[&str], other: bool) -> ManifestDiff {
+        ManifestDiff {
+            dependencies: FxHashSet::from_iter(dependencies.iter().map(|name| name.to_string())),
+            other,
+        }
+    }
+
+    async fn track_with_diff(file: &str, diff: ManifestDiff) -> Vec<String> {
+        track_with_hoisted_diff(file, diff, &[]).await
+    }
+
+    async fn track_with_hoisted_diff(
+        file: &str,
+        diff: ManifestDiff,
+        hoisted_dependencies: &[&str],
+    ) -> Vec<String> {
+        let sandbox = create_sandbox("manifests");
+        let workspace_graph = generate_workspace_graph_from_sandbox(sandbox.path()).await;
+        let touched_files = FxHashSet::from_iter([WorkspaceRelativePathBuf::from(file)]);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, &touched_files);
+        tracker
+            .with_manifest_diffs(FxHashMap::from_iter([(file.into(), diff)]))
+            .with_hoisted_dependencies(hoisted_dependencies);
+        tracker.track_tasks().unwrap();
+        let affected = tracker.build();
+
+        let mut targets = affected
+            .tasks
+            .keys()
+            .map(|target| target.to_string())
+            .collect::<Vec<_>>();
+        targets.sort();
+        targets
+    }
+
+    #[tokio::test]
+    async fn affects_all_if_other_fields_changed() {
+        assert_eq!(
+            track_with_diff("package.json", create_diff(&[], true)).await,
+            ["app:build", "app:lint", "lib:build", "lib:check"]
+        );
+    }
+
+    #[tokio::test]
+    async fn affects_only_explicit_inputs_if_nothing_relevant_changed() {
+        assert_eq!(
+            track_with_diff("package.json", create_diff(&[], false)).await,
+            ["lib:check"]
+        );
+    }
+
+    #[tokio::test]
+    async fn affects_projects_that_declare_a_changed_dependency() {
+        assert_eq!(
+            track_with_diff("package.json", create_diff(&["react"], false)).await,
+            ["app:build", "app:lint", "lib:check"]
+        );
+
+        assert_eq!(
+            track_with_diff("Cargo.toml", create_diff(&["windows-sys"], false)).await,
+            ["lib:build"]
+        );
+    }
+
+    #[tokio::test]
+    async fn affects_tasks_that_run_a_changed_dependency() {
+        assert_eq!(
+            track_with_diff("package.json", create_diff(&["eslint"], false)).await,
+            ["app:lint", "lib:check"]
+        );
+    }
+
+    #[tokio::test]
+    async fn affects_only_tasks_that_use_a_changed_dev_dependency() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(r#"{ "devDependencies": { "eslint": "9.0.0", "typescript": "5.6.0" } }"#),
+            Some(r#"{ "devDependencies": { "eslint": "9.1.0", "typescript": "5.6.0" } }"#),
+        );
+
+        assert_eq!(
+            track_with_diff("package.json", diff).await,
+            ["app:lint", "lib:check"]
+        );
+    }
+
+    #[tokio::test]
+    async fn doesnt_affect_projects_that_dont_use_a_changed_dev_dependency() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(r#"{ "devDependencies": { "eslint": "9.0.0", "typescript": "5.6.0" } }"#),
+            Some(r#"{ "devDependencies": { "eslint": "9.0.0", "typescript": "5.7.0" } }"#),
+        );
+
+        assert_eq!(track_with_diff("package.json", diff).await, ["lib:check"]);
+    }
+
+    #[tokio::test]
+    async fn affects_all_if_hoisted_dependency_changed() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(r#"{ "devDependencies": { "eslint": "9.0.0", "typescript": "5.6.0" } }"#),
+            Some(r#"{ "devDependencies": { "eslint": "9.0.0", "typescript": "5.7.0" } }"#),
+        );
+
+        assert_eq!(
+            track_with_hoisted_diff("package.json", diff, &["typescript"]).await,
+            ["app:build", "app:lint", "lib:build", "lib:check"]
+        );
+    }
+
+    #[tokio::test]
+    async fn affects_by_file_without_a_diff() {
+        let sandbox = create_sandbox("manifests");
+        let workspace_graph = generate_workspace_graph_from_sandbox(sandbox.path()).await;
+        let touched_files = FxHashSet::from_iter(["Cargo.toml".into()]);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, &touched_files);
+        tracker.track_tasks().unwrap();
+        let affected = tracker.build();
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter([(
+                Target::parse("lib:build").unwrap(),
+                AffectedTaskState {
+                    files: FxHashSet::from_iter(["Cargo.toml".into()]),
+                    ..Default::default()
+                }
+            )])
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use moon_affected::*;
+use rustc_hash::FxHashSet;
+
+fn deps(names: &[&str]) -> FxHashSet<String> {
+    FxHashSet::from_iter(names.iter().map(|name| name.to_string()))
+}
+
+mod package_json {
+    use super::*;
+
+    #[test]
+    fn no_changes() {
+        let content = r#"{ "devDependencies": { "eslint": "^9.0.0" } }"#;
+        let diff = ManifestDiff::new(ManifestKind::PackageJson, Some(content), Some(content));
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn ignores_formatting_and_order() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(r#"{ "name": "root", "devDependencies": { "a": "1", "b": "2" } }"#),
+            Some("{\n  \"devDependencies\": {\n    \"b\": \"2\",\n    \"a\": \"1\"\n  },\n  \"name\": \"root\"\n}"),
+        );
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn ignores_metadata() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(r#"{ "version": "1.0.0", "scripts": { "a": "b" } }"#),
+            Some(r#"{ "version": "2.0.0", "scripts": { "a": "c" } }"#),
+        );
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn tracks_changed_dependencies() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(
+                r#"{ "dependencies": { "a": "1", "b": "1" }, "optionalDependencies": { "c": "1" } }"#,
+            ),
+            Some(r#"{ "dependencies": { "a": "2", "b": "1" }, "peerDependencies": { "d": "1" } }"#),
+        );
+
+        assert_eq!(diff.dependencies, deps(&["a", "c", "d"]));
+        assert!(!diff.other);
+    }
+
+    #[test]
+    fn tracks_changed_dev_dependencies() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(r#"{ "devDependencies": { "eslint": "9.0.0", "typescript": "5.6.0" } }"#),
+            Some(r#"{ "devDependencies": { "eslint": "9.0.0", "typescript": "5.7.0" } }"#),
+        );
+
+        assert_eq!(diff.dependencies, deps(&["typescript"]));
+        assert!(!diff.other);
+    }
+
+    #[test]
+    fn tracks_other_fields() {
+        let diff = ManifestDiff::new(
+            ManifestKind::PackageJson,
+            Some(r#"{ "workspaces": ["packages/*"] }"#),
+            Some(r#"{ "workspaces": ["packages/*", "apps/*"] }"#),
+        );
+
+        assert!(diff.other);
+    }
+
+    #[test]
+    fn other_if_missing_or_invalid() {
+        assert!(ManifestDiff::new(ManifestKind::PackageJson, None, Some("{}")).other);
+        assert!(ManifestDiff::new(ManifestKind::PackageJson, Some("{"), Some("{}")).other);
+    }
+}
+
+mod cargo_toml {
+    use super::*;
+
+    #[test]
+    fn tracks_changed_workspace_dependencies() {
+        let diff = ManifestDiff::new(
+            ManifestKind::CargoToml,
+            Some("[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\nserde = \"1.0.0\"\nregex = \"1.0.0\"\n"),
+            Some("[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\nserde = \"1.0.1\"\nregex = \"1.0.0\"\n"),
+        );
+
+        assert_eq!(diff.dependencies, deps(&["serde"]));
+        assert!(!diff.other);
+    }
+
+    #[test]
+    fn ignores_root_crate_and_metadata() {
+        let diff = ManifestDiff::new(
+            ManifestKind::CargoToml,
+            Some("[package]\nname = \"root\"\n\n[dependencies]\nserde = \"1\"\n\n[workspace.metadata]\na = 1\n"),
+            Some("[package]\nname = \"root\"\n\n[dependencies]\nserde = \"2\"\n\n[workspace.metadata]\na = 2\n"),
+        );
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn tracks_workspace_wide_fields() {
+        let diff = ManifestDiff::new(
+            ManifestKind::CargoToml,
+            Some("[profile.release]\nlto = false\n"),
+            Some("[profile.release]\nlto = true\n"),
+        );
+
+        assert!(diff.other);
+
+        let diff = ManifestDiff::new(
+            ManifestKind::CargoToml,
+            Some("[workspace]\nmembers = [\"a\"]\n"),
+            Some("[workspace]\nmembers = [\"a\", \"b\"]\n"),
+        );
+
+        assert!(diff.other);
+    }
+}
@@ -113,6 +113,9 @@ pub enum Commands {
     Sync {
         #[command(subcommand)]
         command: Option<SyncCommands>,
+
+        #[arg(long, help = "Check for pending changes without applying them")]
+        check: bool,
     },
 
     // moon task <target>
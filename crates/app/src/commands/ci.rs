@@ -1,17 +1,19 @@
 use crate::app_error::AppError;
 use crate::components::run_action_pipeline;
-use crate::queries::touched_files::{query_touched_files, QueryTouchedFilesOptions};
+use crate::queries::touched_files::{
+    query_manifest_diffs, query_touched_files, QueryTouchedFilesOptions,
+};
 use crate::session::CliSession;
 use ci_env::CiOutput;
 use clap::Args;
 use moon_action_context::ActionContext;
 use moon_action_graph::{ActionGraph, RunRequirements};
-use moon_affected::{DownstreamScope, UpstreamScope};
+use moon_affected::{DownstreamScope, ManifestDiff, UpstreamScope};
 use moon_common::path::WorkspaceRelativePathBuf;
 use moon_console::Console;
 use moon_task::{Target, TargetLocator};
 use moon_workspace_graph::WorkspaceGraph;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::sync::Arc;
@@ -88,7 +90,10 @@ async fn gather_touched_files(
     console: &mut CiConsole,
     session: &CliSession,
     args: &CiArgs,
-) -> miette::Result<FxHashSet<WorkspaceRelativePathBuf>> {
+) -> miette::Result<(
+    FxHashSet<WorkspaceRelativePathBuf>,
+    FxHashMap<WorkspaceRelativePathBuf, ManifestDiff>,
+)> {
     console.print_header("Gathering touched files")?;
 
     let mut base = args.base.clone();
@@ -136,7 +141,9 @@ async fn gather_touched_files(
     console.write_line(files.join("\n"))?;
     console.print_footer()?;
 
-    Ok(result.files)
+    let manifest_diffs = query_manifest_diffs(&vcs, &session.workspace_root, &result).await?;
+
+    Ok((result.files, manifest_diffs))
 }
 
 /// Gather potential runnable targets.
@@ -206,11 +213,16 @@ async fn generate_action_graph(
     workspace_graph: &WorkspaceGraph,
     targets: &TargetList,
     touched_files: &FxHashSet<WorkspaceRelativePathBuf>,
+    manifest_diffs: FxHashMap<WorkspaceRelativePathBuf, ManifestDiff>,
 ) -> miette::Result<(ActionGraph, ActionContext)> {
     console.print_header("Generating action graph")?;
 
     let mut action_graph_builder = session.build_action_graph(workspace_graph).await?;
     action_graph_builder.set_touched_files(touched_files)?;
+    action_graph_builder.set_manifest_diffs(
+        manifest_diffs,
+        &session.workspace_config.runner.hoisted_dependencies,
+    )?;
     action_graph_builder.set_affected_scopes(UpstreamScope::Deep, DownstreamScope::Deep)?;
 
     // Run dependents to ensure consumers still work correctly
@@ -248,7 +260,8 @@ pub async fn ci(session: CliSession, args: CiArgs) -> AppResult {
     };
 
     let workspace_graph = session.get_workspace_graph().await?;
-    let touched_files = gather_touched_files(&mut console, &session, &args).await?;
+    let (touched_files, manifest_diffs) =
+        gather_touched_files(&mut console, &session, &args).await?;
     let targets = gather_potential_targets(&mut console, &workspace_graph, &args).await?;
 
     if targets.is_empty() {
@@ -264,6 +277,7 @@ pub async fn ci(session: CliSession, args: CiArgs) -> AppResult {
         &workspace_graph,
         &targets,
         &touched_files,
+        manifest_diffs,
     )
     .await?;
 
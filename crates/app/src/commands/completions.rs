@@ -1,12 +1,15 @@
 use crate::app::Cli;
+use crate::commands::ext::create_extension_command;
 use crate::session::CliSession;
-use clap::{Args, CommandFactory};
+use crate::systems::startup;
+use clap::{Args, Command, CommandFactory};
 use clap_complete::{generate, Shell};
 use clap_complete_nushell::Nushell;
 use miette::IntoDiagnostic;
+use moon_plugin::PluginId;
 use starbase::AppResult;
 use starbase_shell::ShellType;
-use tracing::instrument;
+use tracing::{debug, instrument};
 
 #[derive(Args, Clone, Debug)]
 pub struct CompletionsArgs {
@@ -14,6 +17,26 @@ pub struct CompletionsArgs {
     shell: Option<ShellType>,
 }
 
+/// Load extensions that have been configured in the current workspace (if there is one),
+/// and create commands from their arguments, so that they can be completed.
+async fn load_extension_commands(mut session: CliSession) -> miette::Result<Vec<Command>> {
+    session.workspace_root = startup::find_workspace_root(&session.working_dir)?;
+    session.workspace_config =
+        startup::load_workspace_config(session.config_loader.clone(), &session.workspace_root)
+            .await?;
+
+    let extensions = session.get_extension_registry().await?;
+    let mut commands = vec![];
+
+    for id in session.workspace_config.extensions.keys() {
+        let plugin = extensions.load(&PluginId::raw(id)).await?;
+
+        commands.push(create_extension_command(id.as_str(), &plugin.metadata));
+    }
+
+    Ok(commands)
+}
+
 #[instrument(skip_all)]
 pub async fn completions(session: CliSession, args: CompletionsArgs) -> AppResult {
     let shell = match args.shell {
@@ -24,6 +47,16 @@ pub async fn completions(session: CliSession, args: CompletionsArgs) -> AppResul
     session.console.quiet();
 
     let mut app = Cli::command();
+
+    match load_extension_commands(session.clone()).await {
+        Ok(commands) => {
+            app = app.mut_subcommand("ext", |ext| ext.subcommands(commands));
+        }
+        Err(error) => {
+            debug!("Unable to load extensions for completions: {error}");
+        }
+    };
+
     let mut stdio = std::io::stdout();
 
     let clap_shell = match shell {
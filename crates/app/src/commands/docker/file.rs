@@ -1,9 +1,13 @@
 use crate::session::CliSession;
 use clap::Args;
-use moon_common::{color, Id};
-use moon_config::PlatformType;
+use miette::IntoDiagnostic;
+use moon_common::{color, path, Id};
+use moon_config::{NodePackageManager, PlatformType, ToolchainConfig};
 use moon_console::prompts::{Select, Text};
+use moon_console::Console;
 use moon_docker::*;
+use moon_project::Project;
+use moon_workspace_graph::WorkspaceGraph;
 use starbase::AppResult;
 use starbase_utils::fs;
 use tracing::{debug, instrument};
@@ -25,6 +29,12 @@ pub struct DockerFileArgs {
     #[arg(long, help = "Base Docker image to use")]
     image: Option<String>,
 
+    #[arg(
+        long = "project",
+        help = "ID of an additional project to build and start in the same Dockerfile"
+    )]
+    projects: Vec<Id>,
+
     #[arg(long, help = "Do not prune the workspace in the build stage")]
     no_prune: bool,
 
@@ -45,16 +55,26 @@ pub async fn file(session: CliSession, args: DockerFileArgs) -> AppResult {
 
     // Ensure the project exists
     let project = workspace_graph.get_project(&args.id)?;
-    let tasks = workspace_graph.get_tasks_from_project(&project.id)?;
 
     // Build the options
+    let file_config = &session.workspace_config.docker.file;
     let mut options = GenerateDockerfileOptions {
+        cache_mounts: file_config.cache_mounts,
+        copy_link: file_config.copy_link,
         disable_toolchain: args.no_toolchain,
-        project: args.id,
         prune: !args.no_prune,
+        toolchain_stage: file_config.toolchain_stage,
         ..GenerateDockerfileOptions::default()
     };
 
+    if options.cache_mounts {
+        options.package_stores = get_package_stores(&session.toolchain_config);
+    }
+
+    if options.toolchain_stage {
+        options.toolchain_files = get_toolchain_files(&session)?;
+    }
+
     debug!("Gathering Dockerfile options");
 
     if let Some(image) = args.image {
@@ -83,9 +103,83 @@ pub async fn file(session: CliSession, args: DockerFileArgs) -> AppResult {
 
     debug!(image = &options.image, "Using Docker image");
 
-    let build_task_id = if let Some(id) = &args.build_task {
+    options.projects.push(gather_project_tasks(
+        console,
+        &workspace_graph,
+        &project,
+        args.build_task.as_ref(),
+        args.start_task.as_ref(),
+        args.defaults,
+    )?);
+
+    for id in &args.projects {
+        let other_project = workspace_graph.get_project(id)?;
+
+        // Skip the main project and repeated projects (including by alias)
+        if options
+            .projects
+            .iter()
+            .any(|existing| existing.id == other_project.id)
+        {
+            debug!(
+                project_id = other_project.id.as_str(),
+                "Skipping additional project as it has already been included"
+            );
+
+            continue;
+        }
+
+        options.projects.push(gather_project_tasks(
+            console,
+            &workspace_graph,
+            &other_project,
+            None,
+            None,
+            args.defaults,
+        )?);
+    }
+
+    // Generate the file
+    let out = args.dest.unwrap_or("Dockerfile".into());
+    let out_file = project.root.join(&out);
+
+    debug!(
+        dockerfile = ?out_file,
+        projects = ?options
+            .projects
+            .iter()
+            .map(|project| project.id.as_str())
+            .collect::<Vec<_>>(),
+        "Generating Dockerfile in project",
+    );
+
+    fs::write_file(out_file, generate_dockerfile(options)?)?;
+
+    console.out.write_line(format!(
+        "Generated {}",
+        color::rel_path(project.source.join(out))
+    ))?;
+
+    Ok(None)
+}
+
+fn gather_project_tasks(
+    console: &Console,
+    workspace_graph: &WorkspaceGraph,
+    project: &Project,
+    build_task: Option<&Id>,
+    start_task: Option<&Id>,
+    defaults: bool,
+) -> miette::Result<GenerateDockerfileProject> {
+    let tasks = workspace_graph.get_tasks_from_project(&project.id)?;
+    let mut result = GenerateDockerfileProject {
+        id: project.id.clone(),
+        ..GenerateDockerfileProject::default()
+    };
+
+    let build_task_id = if let Some(id) = build_task {
         Some(id)
-    } else if args.defaults {
+    } else if defaults {
         project.config.docker.file.build_task.as_ref()
     } else {
         let mut ids = tasks.iter().map(|task| &task.id).collect::<Vec<_>>();
@@ -100,7 +194,7 @@ pub async fn file(session: CliSession, args: DockerFileArgs) -> AppResult {
             .and_then(|id| ids.iter().position(|cursor_id| cursor_id == &id));
 
         console.prompt_select_skippable(
-            Select::new("Build task?", ids)
+            Select::new(&format!("Build task for {}?", project.id), ids)
                 .with_help_message("Skip build with ESC")
                 .with_starting_cursor(starting_cursor.unwrap_or(0)),
         )?
@@ -114,14 +208,14 @@ pub async fn file(session: CliSession, args: DockerFileArgs) -> AppResult {
 
         debug!(task = target.as_str(), "Using build task");
 
-        options.build_task = Some(target);
+        result.build_task = Some(target);
     } else {
-        debug!("Not using a build task");
+        debug!(project = project.id.as_str(), "Not using a build task");
     }
 
-    let start_task_id = if let Some(id) = &args.start_task {
+    let start_task_id = if let Some(id) = start_task {
         Some(id)
-    } else if args.defaults {
+    } else if defaults {
         project.config.docker.file.start_task.as_ref()
     } else {
         let mut ids = tasks.iter().map(|task| &task.id).collect::<Vec<_>>();
@@ -136,7 +230,7 @@ pub async fn file(session: CliSession, args: DockerFileArgs) -> AppResult {
             .and_then(|id| ids.iter().position(|cursor_id| cursor_id == &id));
 
         console.prompt_select_skippable(
-            Select::new("Start task?", ids)
+            Select::new(&format!("Start task for {}?", project.id), ids)
                 .with_help_message("Skip start with ESC")
                 .with_starting_cursor(starting_cursor.unwrap_or(0)),
         )?
@@ -150,29 +244,76 @@ pub async fn file(session: CliSession, args: DockerFileArgs) -> AppResult {
 
         debug!(task = target.as_str(), "Using start task");
 
-        options.start_task = Some(target);
+        result.start_task = Some(target);
     } else {
-        debug!("Not using a start task");
+        debug!(project = project.id.as_str(), "Not using a start task");
     }
 
-    // Generate the file
-    let out = args.dest.unwrap_or("Dockerfile".into());
-    let out_file = project.root.join(&out);
+    Ok(result)
+}
 
-    debug!(
-        dockerfile = ?out_file,
-        project = options.project.as_str(),
-        "Generating Dockerfile in project",
-    );
+// Linux locations of each package manager's global store, relative
+// to the root user, as this is the default user in most images
+fn get_package_stores(toolchain_config: &ToolchainConfig) -> Vec<String> {
+    let mut stores = vec![];
 
-    fs::write_file(out_file, generate_dockerfile(options)?)?;
+    if toolchain_config.bun.is_some() {
+        stores.push("/root/.bun/install/cache");
+    }
 
-    console.out.write_line(format!(
-        "Generated {}",
-        color::rel_path(project.source.join(out))
-    ))?;
+    // Matches `DENO_DIR` in the official image
+    if toolchain_config.deno.is_some() {
+        stores.push("/deno-dir");
+    }
 
-    Ok(None)
+    if let Some(node) = &toolchain_config.node {
+        match node.package_manager {
+            NodePackageManager::Bun => stores.push("/root/.bun/install/cache"),
+            NodePackageManager::Npm => stores.push("/root/.npm"),
+            NodePackageManager::Pnpm => stores.push("/root/.local/share/pnpm/store"),
+            NodePackageManager::Yarn => {
+                stores.push("/root/.yarn/berry/cache");
+                stores.push("/usr/local/share/.cache/yarn");
+            }
+        };
+    }
+
+    if toolchain_config.python.is_some() {
+        stores.push("/root/.cache/pip");
+    }
+
+    // Matches `CARGO_HOME` in the official image
+    if toolchain_config.rust.is_some() {
+        stores.push("/usr/local/cargo/git");
+        stores.push("/usr/local/cargo/registry");
+    }
+
+    stores.sort();
+    stores.dedup();
+    stores.into_iter().map(String::from).collect()
+}
+
+// Only the files that determine which tools are installed, so that
+// the toolchain stage is not rebuilt when sources change
+fn get_toolchain_files(session: &CliSession) -> miette::Result<Vec<String>> {
+    let mut files = vec![];
+    let root = &session.workspace_root;
+
+    for file in session
+        .config_loader
+        .get_toolchain_files(root)
+        .into_iter()
+        .chain(session.config_loader.get_workspace_files(root))
+        .chain([root.join(".prototools")])
+    {
+        if file.exists() {
+            files.push(path::to_virtual_string(
+                file.strip_prefix(root).into_diagnostic()?,
+            )?);
+        }
+    }
+
+    Ok(files)
 }
 
 fn get_base_image_from_platform(platform: &PlatformType) -> &str {
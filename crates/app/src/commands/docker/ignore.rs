@@ -0,0 +1,50 @@
+use crate::session::CliSession;
+use moon_common::consts::CONFIG_DIRNAME;
+use moon_docker::generate_docker_ignore;
+use starbase::AppResult;
+use starbase_styles::color;
+use starbase_utils::fs;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all)]
+pub async fn ignore(session: CliSession) -> AppResult {
+    let ignore_file = session.workspace_root.join(".dockerignore");
+    let workspace_graph = session.get_workspace_graph().await?;
+
+    // Caches are not portable, and vendor directories are installed
+    // within the image, so these should never be copied
+    let mut entries = vec![
+        format!("{CONFIG_DIRNAME}/cache"),
+        "**/node_modules".to_owned(),
+    ];
+
+    // Outputs are created within the image when building
+    for task in workspace_graph.get_tasks_with_internal()? {
+        for output in task.output_files.iter().chain(task.output_globs.iter()) {
+            if !output.as_str().starts_with('!') {
+                entries.push(output.to_string());
+            }
+        }
+    }
+
+    debug!(
+        ignore_file = ?ignore_file,
+        "Generating {} from the workspace graph",
+        color::file(".dockerignore"),
+    );
+
+    let existing = if ignore_file.exists() {
+        fs::read_file(&ignore_file)?
+    } else {
+        String::new()
+    };
+
+    fs::write_file(&ignore_file, generate_docker_ignore(&existing, entries))?;
+
+    session
+        .console
+        .out
+        .write_line(format!("Generated {}", color::file(".dockerignore")))?;
+
+    Ok(None)
+}
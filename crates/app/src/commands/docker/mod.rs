@@ -1,10 +1,12 @@
 mod docker_error;
 mod file;
+mod ignore;
 mod prune;
 mod scaffold;
 mod setup;
 
 pub use file::*;
+pub use ignore::*;
 pub use prune::*;
 pub use scaffold::*;
 pub use setup::*;
@@ -16,9 +18,18 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Subcommand)]
 pub enum DockerCommands {
-    #[command(name = "file", about = "Generate a default Dockerfile for a project.")]
+    #[command(
+        name = "file",
+        about = "Generate a default Dockerfile for one or many projects."
+    )]
     File(DockerFileArgs),
 
+    #[command(
+        name = "ignore",
+        about = "Generate a .dockerignore file from the workspace graph."
+    )]
+    Ignore,
+
     #[command(
         name = "prune",
         about = "Remove extraneous files and folders within a Dockerfile."
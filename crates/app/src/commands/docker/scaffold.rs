@@ -5,6 +5,7 @@ use clap::Args;
 use moon_common::consts::*;
 use moon_common::{path, Id};
 use moon_config::LanguageType;
+use moon_docker::normalize_ignore_entry;
 use moon_project_graph::{GraphConnections, ProjectGraph};
 use moon_rust_lang::cargo_toml::{CargoTomlCache, CargoTomlExt};
 use moon_toolchain::detect::detect_language_files;
@@ -422,13 +423,7 @@ pub fn check_docker_ignore(workspace_root: &Path) -> miette::Result<()> {
 
         // Check lines so we can match exactly and avoid comments or nested paths
         for line in ignore.lines() {
-            if line
-                .trim()
-                .trim_start_matches("./")
-                .trim_start_matches('/')
-                .trim_end_matches('/')
-                == ".moon/cache"
-            {
+            if normalize_ignore_entry(line) == ".moon/cache" {
                 is_ignored = true;
                 break;
             }
@@ -445,7 +440,8 @@ pub fn check_docker_ignore(workspace_root: &Path) -> miette::Result<()> {
         );
 
         warn!(
-            "If you're not building from the workspace root, or are ignoring by other means, you can ignore this warning"
+            "Run {} to generate one, or if you're not building from the workspace root, or are ignoring by other means, you can ignore this warning",
+            color::shell("moon docker ignore"),
         );
     }
 
@@ -1,9 +1,14 @@
 use crate::app_error::AppError;
 use crate::session::CliSession;
-use clap::Args;
+use clap::builder::PossibleValuesParser;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Args, Command};
+use miette::IntoDiagnostic;
 use moon_common::Id;
+use moon_pdk_api::{ExtensionArgType, ExtensionMetadataOutput};
 use moon_plugin::PluginId;
+use serde_json::Value;
 use starbase::AppResult;
+use std::collections::BTreeMap;
 use tracing::instrument;
 
 #[derive(Args, Clone, Debug)]
@@ -11,11 +16,117 @@ pub struct ExtArgs {
     #[arg(required = true, help = "ID of the extension to execute")]
     id: Id,
 
-    // Passthrough args (after --)
-    #[arg(last = true, help = "Arguments to pass through to the extension")]
+    // Passthrough args (after the ID, optionally after --)
+    #[arg(
+        trailing_var_arg = true,
+        allow_hyphen_values = true,
+        help = "Arguments to pass through to the extension"
+    )]
     pub passthrough: Vec<String>,
 }
 
+/// Create a command from the arguments declared by an extension, which is
+/// used for parsing, `--help`, and completions. Arguments are accepted directly
+/// after the extension ID, so they match the `moon ext <id>` subcommands
+/// that are registered for completions.
+pub fn create_extension_command(id: &str, metadata: &ExtensionMetadataOutput) -> Command {
+    let mut command = Command::new(id.to_owned())
+        .bin_name(format!("moon ext {id}"))
+        .disable_version_flag(true)
+        .no_binary_name(true);
+
+    if let Some(description) = &metadata.description {
+        command = command.about(description.to_owned());
+    }
+
+    for arg_config in &metadata.args {
+        let mut arg = Arg::new(arg_config.name.clone()).required(arg_config.required);
+
+        if !arg_config.positional {
+            arg = arg.long(arg_config.name.clone());
+
+            if let Some(short) = arg_config.short {
+                arg = arg.short(short);
+            }
+        }
+
+        if let Some(description) = &arg_config.description {
+            arg = arg.help(description.to_owned());
+        }
+
+        arg = match arg_config.type_of {
+            ExtensionArgType::Boolean => arg.action(ArgAction::SetTrue),
+            ExtensionArgType::Number => arg.value_parser(value_parser!(f64)),
+            ExtensionArgType::String => {
+                if arg_config.choices.is_empty() {
+                    arg
+                } else {
+                    arg.value_parser(PossibleValuesParser::new(arg_config.choices.clone()))
+                }
+            }
+        };
+
+        if arg_config.type_of != ExtensionArgType::Boolean {
+            if arg_config.multiple {
+                arg = arg.action(ArgAction::Append);
+
+                if arg_config.positional {
+                    arg = arg.num_args(1..);
+                }
+            }
+
+            if let Some(default_value) = &arg_config.default_value {
+                arg = arg.default_value(default_value.to_owned());
+            }
+        }
+
+        command = command.arg(arg);
+    }
+
+    command
+}
+
+fn extract_extension_args(
+    metadata: &ExtensionMetadataOutput,
+    matches: &ArgMatches,
+) -> BTreeMap<String, Value> {
+    let mut parsed_args = BTreeMap::default();
+
+    for arg_config in &metadata.args {
+        let name = &arg_config.name;
+
+        let value = match arg_config.type_of {
+            ExtensionArgType::Boolean => Some(Value::Bool(matches.get_flag(name))),
+            ExtensionArgType::Number => {
+                let to_value = |num: &f64| Value::from(*num);
+
+                if arg_config.multiple {
+                    matches
+                        .get_many::<f64>(name)
+                        .map(|nums| Value::from_iter(nums.map(to_value)))
+                } else {
+                    matches.get_one::<f64>(name).map(to_value)
+                }
+            }
+            ExtensionArgType::String => {
+                if arg_config.multiple {
+                    matches
+                        .get_many::<String>(name)
+                        .map(|values| Value::from_iter(values.cloned()))
+                } else {
+                    matches.get_one::<String>(name).cloned().map(Value::from)
+                }
+            }
+        };
+
+        if let Some(value) = value {
+            parsed_args.insert(name.to_owned(), value);
+        }
+    }
+
+    parsed_args
+}
+
 #[instrument(skip_all)]
 pub async fn ext(session: CliSession, args: ExtArgs) -> AppResult {
     if !session.workspace_config.extensions.contains_key(&args.id) {
@@ -28,10 +139,157 @@ pub async fn ext(session: CliSession, args: ExtArgs) -> AppResult {
     // Load the plugin
     let plugin = extensions.load(&id).await?;
 
-    // Execute the plugin
+    // Parse and validate arguments, if the plugin has declared them
+    let mut parsed_args = BTreeMap::default();
+
+    if !plugin.metadata.args.is_empty() {
+        match create_extension_command(args.id.as_str(), &plugin.metadata)
+            .try_get_matches_from(&args.passthrough)
+        {
+            Ok(matches) => {
+                parsed_args = extract_extension_args(&plugin.metadata, &matches);
+            }
+            // Also handles `--help`
+            Err(error) => {
+                error.print().into_diagnostic()?;
+
+                return Ok(Some(error.exit_code() as u8));
+            }
+        };
+    }
+
+    // Execute the plugin, and only build the graph when requested
+    let workspace_graph = if plugin.metadata.requires_workspace_graph {
+        Some(session.get_workspace_graph().await?)
+    } else {
+        None
+    };
+
     plugin
-        .execute(args.passthrough, extensions.create_context())
+        .execute(
+            args.passthrough,
+            parsed_args,
+            workspace_graph.as_ref(),
+            extensions.create_context(),
+        )
         .await?;
 
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{Cli, Commands};
+    use clap::{CommandFactory, Parser};
+    use moon_pdk_api::ExtensionArg;
+    use serde_json::json;
+
+    fn create_metadata() -> ExtensionMetadataOutput {
+        ExtensionMetadataOutput {
+            args: vec![
+                ExtensionArg {
+                    name: "file".into(),
+                    positional: true,
+                    required: true,
+                    ..Default::default()
+                },
+                ExtensionArg {
+                    name: "dry-run".into(),
+                    type_of: ExtensionArgType::Boolean,
+                    ..Default::default()
+                },
+                ExtensionArg {
+                    name: "level".into(),
+                    short: Some('l'),
+                    type_of: ExtensionArgType::Number,
+                    default_value: Some("1".into()),
+                    ..Default::default()
+                },
+                ExtensionArg {
+                    name: "format".into(),
+                    choices: vec!["json".into(), "yaml".into()],
+                    multiple: true,
+                    ..Default::default()
+                },
+            ],
+            name: "Example".into(),
+            ..Default::default()
+        }
+    }
+
+    fn parse(args: &[&str]) -> Result<BTreeMap<String, Value>, clap::Error> {
+        let metadata = create_metadata();
+        let matches = create_extension_command("example", &metadata).try_get_matches_from(args)?;
+
+        Ok(extract_extension_args(&metadata, &matches))
+    }
+
+    #[test]
+    fn parses_args() {
+        assert_eq!(
+            parse(&[
+                "a.txt",
+                "--dry-run",
+                "-l",
+                "3",
+                "--format",
+                "json",
+                "--format",
+                "yaml"
+            ])
+            .unwrap(),
+            BTreeMap::from_iter([
+                ("dry-run".into(), json!(true)),
+                ("file".into(), json!("a.txt")),
+                ("format".into(), json!(["json", "yaml"])),
+                ("level".into(), json!(3.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn applies_defaults() {
+        assert_eq!(
+            parse(&["a.txt"]).unwrap(),
+            BTreeMap::from_iter([
+                ("dry-run".into(), json!(false)),
+                ("file".into(), json!("a.txt")),
+                ("level".into(), json!(1.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn errors_for_invalid_args() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["a.txt", "--level", "abc"]).is_err());
+        assert!(parse(&["a.txt", "--format", "toml"]).is_err());
+        assert!(parse(&["a.txt", "--unknown"]).is_err());
+    }
+
+    #[test]
+    fn passes_through_args_with_or_without_separator() {
+        for args in [
+            vec!["moon", "ext", "example", "a.txt", "--dry-run"],
+            vec!["moon", "ext", "example", "--", "a.txt", "--dry-run"],
+            vec!["moon", "ext", "example", "--dry-run", "a.txt"],
+        ] {
+            let Commands::Ext(ext_args) = Cli::try_parse_from(&args).unwrap().command else {
+                panic!("Expected ext command");
+            };
+
+            assert_eq!(ext_args.id.as_str(), "example");
+            assert_eq!(ext_args.passthrough.len(), 2);
+        }
+    }
+
+    #[test]
+    fn can_register_as_subcommand() {
+        Cli::command()
+            .mut_subcommand("ext", |ext| {
+                ext.subcommand(create_extension_command("example", &create_metadata()))
+            })
+            .debug_assert();
+    }
+}
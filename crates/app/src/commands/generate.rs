@@ -2,17 +2,79 @@ use crate::helpers::create_theme;
 use crate::session::CliSession;
 use dialoguer::{Confirm, Input, Select};
 use miette::IntoDiagnostic;
-use moon_codegen::{gather_variables, CodeGenerator, FileState};
+use moon_codegen::{
+    gather_variables, gather_variables_for_destination, CodeGenerator, CodegenError, FileState,
+    Template, TemplateContext, TemplateHook, TemplateLock, TemplateProjectsLoader,
+};
+use moon_common::path::RelativePathBuf;
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use tokio::runtime::Handle;
+use tokio::task::block_in_place;
 use tracing::{debug, instrument};
 
 pub use moon_codegen::GenerateArgs;
 
+/// Collect information about each project, keyed by ID and alias,
+/// for use within the template `project()` function.
+async fn load_template_projects(session: &CliSession) -> miette::Result<FxHashMap<String, Value>> {
+    let project_graph = session.get_project_graph().await?;
+    let mut projects = FxHashMap::default();
+
+    for project in project_graph.get_all_unexpanded() {
+        let value = json!({
+            "alias": project.alias,
+            "id": project.id,
+            "language": project.language,
+            "root": project.root,
+            "source": project.source,
+            "stack": project.stack,
+            "tags": project.config.tags,
+            "type": project.type_of,
+        });
+
+        if let Some(alias) = &project.alias {
+            projects.insert(alias.to_owned(), value.clone());
+        }
+
+        projects.insert(project.id.to_string(), value);
+    }
+
+    Ok(projects)
+}
+
+/// Building the project graph is expensive, so only load projects
+/// when a template calls `project()`, and only once.
+fn create_template_projects_loader(session: &CliSession) -> TemplateProjectsLoader {
+    let session = session.clone();
+    let cache = Arc::new(OnceLock::new());
+
+    Arc::new(move || {
+        if let Some(projects) = cache.get() {
+            return Ok(Arc::clone(projects));
+        }
+
+        // Tera functions are synchronous
+        let projects =
+            block_in_place(|| Handle::current().block_on(load_template_projects(&session)))?;
+
+        Ok(Arc::clone(cache.get_or_init(|| Arc::new(projects))))
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
+    let mut args = args;
+
+    // Answers are relative from the working directory
+    if let Some(answers) = &args.answers {
+        args.answers = Some(session.working_dir.join(answers));
+    }
+
     let mut generator = CodeGenerator::new(
         &session.workspace_root,
         &session.workspace_config.generator,
@@ -42,6 +104,10 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
 
     // Create the template instance
     let mut template = generator.get_template(&args.name)?;
+    template.register_workspace_functions(
+        &session.workspace_root,
+        create_template_projects_loader(&session),
+    );
 
     console.write_newline()?;
     console.write_line(format!(
@@ -57,13 +123,8 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
     console.write_newline()?;
     console.flush()?;
 
-    // Gather variables
-    let mut context = gather_variables(&args, &template, &session.console)?;
-    context.insert("working_dir", &session.working_dir);
-    context.insert("workspace_root", &session.workspace_root);
-
-    // Determine the destination path
-    let relative_dest = match &args.dest {
+    // Determine the destination path, which may contain variables
+    let raw_dest = match &args.dest {
         Some(dest) => dest.to_owned(),
         None => {
             if let Some(dest) = &template.config.destination {
@@ -81,13 +142,54 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
             }
         }
     };
-    let relative_from_root = relative_dest.starts_with('/');
-    let relative_dest = template.interpolate_path(&PathBuf::from(relative_dest), &context)?;
-    let dest = relative_dest.to_logical_path(if relative_from_root {
-        &session.workspace_root
+
+    // When updating, inherit the variables from the previous generation,
+    // which requires the destination to be resolved before prompting
+    let (previous_lock, resolved_dest) = if args.update {
+        let context = gather_variables_for_destination(
+            &GenerateArgs {
+                defaults: true,
+                ..args.clone()
+            },
+            &template,
+            &session.console,
+            create_base_context(&session),
+        )?;
+        let (relative_dest, dest) = resolve_dest(&session, &mut template, &raw_dest, &context)?;
+
+        let Some(lock) = TemplateLock::load(&dest, &template.id)? else {
+            return Err(CodegenError::MissingTemplateLock(dest).into());
+        };
+
+        if lock.template != template.id {
+            return Err(CodegenError::TemplateLockMismatch {
+                id: template.id,
+                locked: lock.template,
+            }
+            .into());
+        }
+
+        (Some(lock), Some((relative_dest, dest)))
     } else {
-        &session.working_dir
-    });
+        (None, None)
+    };
+
+    // Gather variables
+    let mut context = gather_variables(
+        &args,
+        &template,
+        &session.console,
+        previous_lock
+            .as_ref()
+            .map(|lock| lock.to_context())
+            .unwrap_or_default(),
+    )?;
+    context.extend(create_base_context(&session));
+
+    let (relative_dest, dest) = match resolved_dest {
+        Some(resolved) => resolved,
+        None => resolve_dest(&session, &mut template, &raw_dest, &context)?,
+    };
 
     debug!(dest = ?dest, "Destination path set");
 
@@ -98,62 +200,91 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
     // Load template files and determine when to overwrite
     template.load_files(&dest, &context)?;
 
-    for file in template.files.values_mut() {
-        if file.is_skipped() {
-            file.state = FileState::Skip;
-            continue;
-        }
-
-        if file.dest_path.exists() {
-            if args.force || file.is_forced() {
-                file.state = FileState::Replace;
+    if let Some(previous_lock) = &previous_lock {
+        template.prepare_update(&dest, previous_lock)?;
+    } else {
+        for file in template.files.values_mut() {
+            if file.is_skipped() {
+                file.state = FileState::Skip;
                 continue;
             }
 
-            // Merge files when applicable
-            if file.is_mergeable().is_some() {
-                let operations = [
-                    "Keep existing file",
-                    "Merge new file into existing file",
-                    "Replace existing with new file",
-                ];
+            if file.dest_path.exists() {
+                if args.force || file.is_forced() {
+                    file.state = FileState::Replace;
+                    continue;
+                }
+
+                // Merge files when applicable
+                if file.is_mergeable().is_some() {
+                    let operations = [
+                        "Keep existing file",
+                        "Merge new file into existing file",
+                        "Replace existing with new file",
+                    ];
+
+                    let index = Select::with_theme(&theme)
+                        .with_prompt(format!(
+                            "File {} already exists, what to do?",
+                            color::path(&file.dest_path)
+                        ))
+                        .default(2)
+                        .items(&operations)
+                        .interact()
+                        .into_diagnostic()?;
+
+                    file.state = match index {
+                        0 => FileState::Skip,
+                        1 => FileState::Merge,
+                        _ => FileState::Replace,
+                    };
 
-                let index = Select::with_theme(&theme)
+                    continue;
+                }
+
+                // Confirm whether to replace file
+                if Confirm::with_theme(&theme)
                     .with_prompt(format!(
-                        "File {} already exists, what to do?",
+                        "File {} already exists, overwrite?",
                         color::path(&file.dest_path)
                     ))
-                    .default(2)
-                    .items(&operations)
                     .interact()
-                    .into_diagnostic()?;
-
-                file.state = match index {
-                    0 => FileState::Skip,
-                    1 => FileState::Merge,
-                    _ => FileState::Replace,
-                };
-
-                continue;
-            }
-
-            // Confirm whether to replace file
-            if Confirm::with_theme(&theme)
-                .with_prompt(format!(
-                    "File {} already exists, overwrite?",
-                    color::path(&file.dest_path)
-                ))
-                .interact()
-                .into_diagnostic()?
-            {
-                file.state = FileState::Replace;
+                    .into_diagnostic()?
+                {
+                    file.state = FileState::Replace;
+                }
             }
         }
     }
 
+    // Record what will be generated, now that file states are final,
+    // but only for templates that opt-in or were previously locked
+    let lock = if template.config.lock || previous_lock.is_some() {
+        Some(TemplateLock::new(
+            &template,
+            &dest,
+            &context,
+            previous_lock.as_ref(),
+        )?)
+    } else {
+        None
+    };
+
     // Generate the files in the destination and print the results
     if !args.dry_run {
-        generator.generate(&template)?;
+        console.flush()?;
+
+        generator
+            .run_hooks(
+                &template,
+                TemplateHook::PreGenerate,
+                &dest,
+                &context,
+                session.get_console()?,
+            )
+            .await?;
+
+        generator.generate(&template, lock.as_ref())?;
     }
 
     console.write_newline()?;
@@ -162,14 +293,16 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
         console.write_line(format!(
             "{} {} {}",
             match &file.state {
+                FileState::Conflict => color::failure("conflict"),
                 FileState::Create => color::success("created"),
                 FileState::Merge => color::success("merged"),
                 FileState::Replace => color::failure("replaced"),
                 FileState::Skip => color::invalid("skipped"),
+                FileState::Update => color::success("updated"),
             },
             match &file.state {
                 FileState::Merge => color::muted("--->"),
-                FileState::Replace => color::muted("->"),
+                FileState::Replace | FileState::Conflict => color::muted("->"),
                 _ => color::muted("-->"),
             },
             color::muted_light(
@@ -182,7 +315,79 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
     }
 
     console.write_newline()?;
-    console.flush()?;
+
+    if template
+        .files
+        .values()
+        .any(|file| file.state == FileState::Conflict)
+    {
+        console.write_line(format!(
+            "Files with conflicts contain {} markers that must be resolved manually.",
+            color::property("<<<<<<<")
+        ))?;
+        console.write_newline()?;
+    }
+
+    // Print hooks instead of running them when a dry run
+    if args.dry_run {
+        for (hook, label) in [
+            (TemplateHook::PreGenerate, "preGenerate"),
+            (TemplateHook::PostGenerate, "postGenerate"),
+        ] {
+            let commands = template.get_hook_commands(hook);
+
+            if commands.is_empty() {
+                continue;
+            }
+
+            console.write_line(format!("Would run {} hooks:", color::property(label)))?;
+
+            for command in commands {
+                console.write_line(format!("  {}", color::shell(command)))?;
+            }
+
+            console.write_newline()?;
+        }
+
+        console.flush()?;
+    } else {
+        console.flush()?;
+
+        generator
+            .run_hooks(
+                &template,
+                TemplateHook::PostGenerate,
+                &dest,
+                &context,
+                session.get_console()?,
+            )
+            .await?;
+    }
 
     Ok(None)
 }
+
+fn create_base_context(session: &CliSession) -> TemplateContext {
+    let mut context = TemplateContext::default();
+    context.insert("working_dir", &session.working_dir);
+    context.insert("workspace_root", &session.workspace_root);
+    context
+}
+
+/// Interpolate variables into the destination, and resolve it relative from
+/// the workspace root (when prefixed with `/`) or the working directory.
+fn resolve_dest(
+    session: &CliSession,
+    template: &mut Template,
+    raw_dest: &str,
+    context: &TemplateContext,
+) -> miette::Result<(RelativePathBuf, PathBuf)> {
+    let relative_dest = template.interpolate_path(&PathBuf::from(raw_dest), context)?;
+    let dest = relative_dest.to_logical_path(if raw_dest.starts_with('/') {
+        &session.workspace_root
+    } else {
+        &session.working_dir
+    });
+
+    Ok((relative_dest, dest))
+}
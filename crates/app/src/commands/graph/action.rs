@@ -1,4 +1,5 @@
 use crate::commands::graph::utils::{action_graph_repr, respond_to_request, setup_server};
+use crate::commands::graph::GraphFormat;
 use crate::session::CliSession;
 use clap::Args;
 use moon_action_graph::RunRequirements;
@@ -21,6 +22,14 @@ pub struct ActionGraphArgs {
 
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Print the graph in the provided format",
+        conflicts_with_all = ["dot", "json"]
+    )]
+    format: Option<GraphFormat>,
 }
 
 #[instrument]
@@ -50,16 +59,19 @@ pub async fn action_graph(session: CliSession, args: ActionGraphArgs) -> AppResu
 
     let action_graph = action_graph_builder.build();
 
-    if args.dot {
-        println!("{}", action_graph.to_dot());
-
-        return Ok(None);
-    }
-
     let graph_info = action_graph_repr(&action_graph).await;
 
-    if args.json {
-        println!("{}", json::format(&graph_info, true)?);
+    if let Some(format) = GraphFormat::resolve(args.format, args.dot, args.json) {
+        println!(
+            "{}",
+            match format {
+                GraphFormat::Cytoscape => action_graph.to_cytoscape()?,
+                GraphFormat::Dot => action_graph.to_dot(),
+                GraphFormat::Graphml => action_graph.to_graphml(),
+                GraphFormat::Json => json::format(&graph_info, true)?,
+                GraphFormat::Mermaid => action_graph.to_mermaid(),
+            }
+        );
 
         return Ok(None);
     }
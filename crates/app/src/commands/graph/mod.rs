@@ -3,3 +3,31 @@ mod dto;
 pub mod project;
 pub mod task;
 mod utils;
+
+use clap::ValueEnum;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+#[value(rename_all = "lowercase")]
+pub enum GraphFormat {
+    Cytoscape,
+    Dot,
+    Graphml,
+    Json,
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Resolve the format from the `--format` option, or the
+    /// `--dot` and `--json` shorthand flags.
+    pub fn resolve(format: Option<GraphFormat>, dot: bool, json: bool) -> Option<GraphFormat> {
+        if format.is_some() {
+            format
+        } else if dot {
+            Some(GraphFormat::Dot)
+        } else if json {
+            Some(GraphFormat::Json)
+        } else {
+            None
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use super::utils::{project_graph_repr, respond_to_request, setup_server};
+use super::GraphFormat;
 use crate::session::CliSession;
 use clap::Args;
 use moon_common::Id;
-use moon_project_graph::{GraphToDot, GraphToJson};
+use moon_project_graph::{
+    GraphToCytoscape, GraphToDot, GraphToGraphml, GraphToJson, GraphToMermaid,
+};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::sync::Arc;
@@ -21,6 +24,14 @@ pub struct ProjectGraphArgs {
 
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Print the graph in the provided format",
+        conflicts_with_all = ["dot", "json"]
+    )]
+    format: Option<GraphFormat>,
 }
 
 #[instrument(skip_all)]
@@ -34,14 +45,17 @@ pub async fn project_graph(session: CliSession, args: ProjectGraphArgs) -> AppRe
     // Force expand all projects
     project_graph.get_all()?;
 
-    if args.dot {
-        println!("{}", project_graph.to_dot());
-
-        return Ok(None);
-    }
-
-    if args.json {
-        println!("{}", project_graph.to_json()?);
+    if let Some(format) = GraphFormat::resolve(args.format, args.dot, args.json) {
+        println!(
+            "{}",
+            match format {
+                GraphFormat::Cytoscape => project_graph.to_cytoscape()?,
+                GraphFormat::Dot => project_graph.to_dot(),
+                GraphFormat::Graphml => project_graph.to_graphml(),
+                GraphFormat::Json => project_graph.to_json()?,
+                GraphFormat::Mermaid => project_graph.to_mermaid(),
+            }
+        );
 
         return Ok(None);
     }
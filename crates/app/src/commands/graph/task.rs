@@ -1,8 +1,9 @@
 use super::utils::{respond_to_request, setup_server, task_graph_repr};
+use super::GraphFormat;
 use crate::session::CliSession;
 use clap::Args;
 use moon_task::Target;
-use moon_task_graph::{GraphToDot, GraphToJson};
+use moon_task_graph::{GraphToCytoscape, GraphToDot, GraphToGraphml, GraphToJson, GraphToMermaid};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::sync::Arc;
@@ -21,6 +22,14 @@ pub struct TaskGraphArgs {
 
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Print the graph in the provided format",
+        conflicts_with_all = ["dot", "json"]
+    )]
+    format: Option<GraphFormat>,
 }
 
 #[instrument(skip_all)]
@@ -34,14 +43,17 @@ pub async fn task_graph(session: CliSession, args: TaskGraphArgs) -> AppResult {
     // Force expand all tasks
     task_graph.get_all()?;
 
-    if args.dot {
-        println!("{}", task_graph.to_dot());
-
-        return Ok(None);
-    }
-
-    if args.json {
-        println!("{}", task_graph.to_json()?);
+    if let Some(format) = GraphFormat::resolve(args.format, args.dot, args.json) {
+        println!(
+            "{}",
+            match format {
+                GraphFormat::Cytoscape => task_graph.to_cytoscape()?,
+                GraphFormat::Dot => task_graph.to_dot(),
+                GraphFormat::Graphml => task_graph.to_graphml(),
+                GraphFormat::Json => task_graph.to_json()?,
+                GraphFormat::Mermaid => task_graph.to_mermaid(),
+            }
+        );
 
         return Ok(None);
     }
@@ -1,16 +1,23 @@
+pub use crate::queries::graph_diff::*;
+pub use crate::queries::graph_stats::*;
 pub use crate::queries::hash::query_hash;
 pub use crate::queries::hash_diff::query_hash_diff;
+pub use crate::queries::owners::*;
 pub use crate::queries::projects::*;
 pub use crate::queries::tasks::*;
 pub use crate::queries::touched_files::*;
 use crate::session::CliSession;
 use clap::{Args, Subcommand};
+use moon_actions::operations::create_codeowners_resolver;
 use moon_affected::{AffectedTracker, DownstreamScope, UpstreamScope};
+use moon_common::path::{standardize_separators, WorkspaceRelativePathBuf};
+use moon_console::Console;
 use moon_vcs::TouchedStatus;
 use starbase::AppResult;
 use starbase_styles::color;
 use starbase_utils::json;
 use std::collections::BTreeMap;
+use std::fmt::Display;
 use tracing::{instrument, warn};
 
 const HEADING_AFFECTED: &str = "Affected by";
@@ -18,6 +25,20 @@ const HEADING_FILTERS: &str = "Filters";
 
 #[derive(Clone, Debug, Subcommand)]
 pub enum QueryCommands {
+    #[command(
+        name = "graph-diff",
+        about = "Diff the project and task graphs between two revisions.",
+        long_about = "Diff the project and task graphs between two revisions, built from the configuration files at each revision, and report added or removed projects and tasks, changed dependencies, commands and inputs, and new constraint violations."
+    )]
+    GraphDiff(QueryGraphDiffArgs),
+
+    #[command(
+        name = "graph-stats",
+        about = "Analyze the project and task graphs.",
+        long_about = "Analyze the project and task graphs, and report the most depended on nodes (blast radius), fan-in and fan-out, the longest dependency chains, cycles, and orphaned projects."
+    )]
+    GraphStats(QueryGraphStatsArgs),
+
     #[command(
         name = "hash",
         about = "Inspect the contents of a generated hash.",
@@ -32,6 +53,13 @@ pub enum QueryCommands {
     )]
     HashDiff(QueryHashDiffArgs),
 
+    #[command(
+        name = "owners",
+        about = "Query the owners of files.",
+        long_about = "Query the owners of files, using the same rules as the generated CODEOWNERS file. When no paths are provided, touched files will be used."
+    )]
+    Owners(QueryOwnersArgs),
+
     #[command(
         name = "projects",
         about = "Query for projects within the project graph.",
@@ -52,6 +80,218 @@ pub enum QueryCommands {
     TouchedFiles(QueryTouchedFilesArgs),
 }
 
+#[derive(Args, Clone, Debug)]
+pub struct QueryGraphDiffArgs {
+    #[arg(long, help = "Base revision to compare against")]
+    base: String,
+
+    #[arg(long, default_value = "HEAD", help = "Head revision to compare with")]
+    head: String,
+
+    #[arg(long, help = "Print the diff in JSON format")]
+    json: bool,
+}
+
+fn write_diff_list<T: Display>(console: &Console, label: &str, items: &[T]) -> miette::Result<()> {
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    console.out.write_line(format!("{label}:"))?;
+
+    for item in items {
+        console.out.write_line(format!("\t{item}"))?;
+    }
+
+    console.out.write_newline()?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn graph_diff(session: CliSession, args: QueryGraphDiffArgs) -> AppResult {
+    let console = &session.console;
+    let result = query_graph_diff(&session, &args.base, &args.head).await?;
+
+    // Write to stdout directly to avoid broken pipe panics
+    if args.json {
+        console.out.write_line(json::format(&result, true)?)?;
+
+        return Ok(None);
+    }
+
+    console
+        .out
+        .write_line(format!("Base: {}", color::symbol(&result.base)))?;
+    console
+        .out
+        .write_line(format!("Head: {}", color::symbol(&result.head)))?;
+    console.out.write_newline()?;
+
+    write_diff_list(console, "Added projects", &result.added_projects)?;
+    write_diff_list(console, "Removed projects", &result.removed_projects)?;
+
+    if !result.changed_projects.is_empty() {
+        console.out.write_line("Changed projects:")?;
+
+        for (id, project) in &result.changed_projects {
+            console.out.write_line(format!("\t{}", color::id(id)))?;
+
+            for dep in &project.added_dependencies {
+                console.out.write_line(format!("\t\t+ depends on {dep}"))?;
+            }
+
+            for dep in &project.removed_dependencies {
+                console.out.write_line(format!("\t\t- depends on {dep}"))?;
+            }
+        }
+
+        console.out.write_newline()?;
+    }
+
+    write_diff_list(console, "Added tasks", &result.added_tasks)?;
+    write_diff_list(console, "Removed tasks", &result.removed_tasks)?;
+
+    if !result.changed_tasks.is_empty() {
+        console.out.write_line("Changed tasks:")?;
+
+        for (target, task) in &result.changed_tasks {
+            console
+                .out
+                .write_line(format!("\t{}", color::label(target)))?;
+
+            if let (Some(base), Some(head)) = (&task.base_command, &task.head_command) {
+                console.out.write_line(format!(
+                    "\t\tcommand: {} → {}",
+                    color::shell(base),
+                    color::shell(head)
+                ))?;
+            }
+
+            for dep in &task.added_deps {
+                console.out.write_line(format!("\t\t+ dep {dep}"))?;
+            }
+
+            for dep in &task.removed_deps {
+                console.out.write_line(format!("\t\t- dep {dep}"))?;
+            }
+
+            for input in &task.added_inputs {
+                console.out.write_line(format!("\t\t+ input {input}"))?;
+            }
+
+            for input in &task.removed_inputs {
+                console.out.write_line(format!("\t\t- input {input}"))?;
+            }
+        }
+
+        console.out.write_newline()?;
+    }
+
+    write_diff_list(console, "New constraint violations", &result.new_violations)?;
+
+    Ok(None)
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct QueryGraphStatsArgs {
+    #[arg(
+        long,
+        default_value_t = 10,
+        help = "Maximum number of depended on nodes and dependency chains to report"
+    )]
+    limit: usize,
+
+    #[arg(long, help = "Print the stats in JSON format")]
+    json: bool,
+}
+
+fn write_graph_stats<K: Display>(
+    console: &Console,
+    label: &str,
+    stats: &QueryGraphStats<K>,
+    limit: usize,
+) -> miette::Result<()> {
+    console
+        .out
+        .write_line(format!("Most depended on {label}s:"))?;
+
+    for node in stats.nodes.iter().take(limit) {
+        console.out.write_line(format!(
+            "\t{} (blast radius: {}, fan-in: {}, fan-out: {})",
+            node.id, node.blast_radius, node.fan_in, node.fan_out
+        ))?;
+    }
+
+    if !stats.longest_chains.is_empty() {
+        console.out.write_newline()?;
+        console.out.write_line(format!("Longest {label} chains:"))?;
+
+        for chain in &stats.longest_chains {
+            console.out.write_line(format!(
+                "\t{}",
+                chain
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" → ")
+            ))?;
+        }
+    }
+
+    if !stats.cycles.is_empty() {
+        console.out.write_newline()?;
+        console
+            .out
+            .write_line(format!("Cycles between {label}s:"))?;
+
+        for cycle in &stats.cycles {
+            console.out.write_line(format!(
+                "\t{}",
+                cycle
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn graph_stats(session: CliSession, args: QueryGraphStatsArgs) -> AppResult {
+    let console = &session.console;
+    let workspace_graph = session.get_workspace_graph().await?;
+    let result = query_graph_stats(&workspace_graph, args.limit)?;
+
+    // Write to stdout directly to avoid broken pipe panics
+    if args.json {
+        console.out.write_line(json::format(&result, true)?)?;
+
+        return Ok(None);
+    }
+
+    write_graph_stats(console, "project", &result.projects, args.limit)?;
+
+    if !result.orphaned_projects.is_empty() {
+        console.out.write_newline()?;
+        console
+            .out
+            .write_line("Orphaned projects (no dependents or tasks):")?;
+
+        for id in &result.orphaned_projects {
+            console.out.write_line(format!("\t{id}"))?;
+        }
+    }
+
+    console.out.write_newline()?;
+    write_graph_stats(console, "task", &result.tasks, args.limit)?;
+
+    Ok(None)
+}
+
 #[derive(Args, Clone, Debug)]
 pub struct QueryHashArgs {
     #[arg(required = true, help = "Hash to inspect")]
@@ -148,6 +388,120 @@ pub async fn hash_diff(session: CliSession, args: QueryHashDiffArgs) -> AppResul
     Ok(None)
 }
 
+#[derive(Args, Clone, Debug)]
+pub struct QueryOwnersArgs {
+    #[arg(help = "Files to resolve owners for, relative from the current directory")]
+    paths: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Report files and projects without an owner",
+        conflicts_with = "paths"
+    )]
+    coverage: bool,
+
+    #[arg(long, help = "Print the owners in JSON format")]
+    json: bool,
+}
+
+#[instrument(skip_all)]
+pub async fn owners(session: CliSession, args: QueryOwnersArgs) -> AppResult {
+    let console = &session.console;
+    let app_context = session.get_app_context()?;
+    let workspace_graph = session.get_workspace_graph().await?;
+    let vcs = session.get_vcs_adapter()?;
+    let resolver = create_codeowners_resolver(&app_context, &workspace_graph)?;
+
+    if args.coverage {
+        let files = vcs.get_file_tree(".").await?;
+        let result = query_owners_coverage(&resolver, &workspace_graph, &files)?;
+
+        // Write to stdout directly to avoid broken pipe panics
+        if args.json {
+            console.out.write_line(json::format(&result, true)?)?;
+
+            return Ok(None);
+        }
+
+        console.out.write_line(format!(
+            "Owned files: {}/{} ({:.2}%)",
+            result.owned_files,
+            result.total_files,
+            result.get_percentage()
+        ))?;
+
+        if !result.unowned_files.is_empty() {
+            console.out.write_newline()?;
+            console.out.write_line("Unowned files:")?;
+
+            for file in &result.unowned_files {
+                console.out.write_line(format!("\t{file}"))?;
+            }
+        }
+
+        if !result.unowned_projects.is_empty() {
+            console.out.write_newline()?;
+            console.out.write_line("Unowned projects:")?;
+
+            for id in &result.unowned_projects {
+                console.out.write_line(format!("\t{id}"))?;
+            }
+        }
+
+        return Ok(None);
+    }
+
+    let files = if args.paths.is_empty() {
+        load_touched_files(&vcs).await?
+    } else {
+        args.paths
+            .iter()
+            .map(|path| {
+                let abs_path = session.working_dir.join(path);
+                let rel_path = abs_path
+                    .strip_prefix(&session.workspace_root)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|_| path.to_owned());
+
+                WorkspaceRelativePathBuf::from(standardize_separators(rel_path)).normalize()
+            })
+            .collect()
+    };
+
+    let result = query_owners(&resolver, &files);
+
+    // Write to stdout directly to avoid broken pipe panics
+    if args.json {
+        console.out.write_line(json::format(&result, true)?)?;
+    } else if !result.files.is_empty() {
+        console.out.write_line(
+            result
+                .files
+                .iter()
+                .map(|(file, owners)| {
+                    format!(
+                        "{} | {} | {}",
+                        file,
+                        if owners.is_owned() {
+                            owners.owners.join(" ")
+                        } else {
+                            "(unowned)".into()
+                        },
+                        owners
+                            .rules
+                            .last()
+                            .map(|rule| rule.pattern.as_str())
+                            .unwrap_or("...")
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )?;
+    }
+
+    Ok(None)
+}
+
 #[derive(Args, Clone, Debug)]
 pub struct QueryProjectsArgs {
     #[arg(help = "Filter projects using a query (takes precedence over options)")]
@@ -1,5 +1,7 @@
 use crate::components::run_action_pipeline;
-use crate::queries::touched_files::{query_touched_files, QueryTouchedFilesOptions};
+use crate::queries::touched_files::{
+    query_manifest_diffs, query_touched_files, QueryTouchedFilesOptions,
+};
 use crate::session::CliSession;
 use clap::Args;
 use moon_action_context::{ActionContext, ProfileType};
@@ -9,11 +11,11 @@ use moon_cache::CacheMode;
 use moon_common::{is_ci, is_test_env};
 use moon_task::TargetLocator;
 use moon_vcs::TouchedStatus;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::string::ToString;
-use tracing::instrument;
+use tracing::{error, instrument};
 
 const HEADING_AFFECTED: &str = "Affected by";
 const HEADING_DEBUGGING: &str = "Debugging";
@@ -87,6 +89,14 @@ pub struct RunArgs {
     )]
     pub status: Vec<TouchedStatus>,
 
+    #[arg(
+        long,
+        help = "Only run target if affected by staged files, and hide unstaged changes while running",
+        help_heading = HEADING_AFFECTED,
+        conflicts_with_all = ["remote", "status"],
+    )]
+    pub staged: bool,
+
     // Passthrough args (after --)
     #[arg(
         last = true,
@@ -96,7 +106,9 @@ pub struct RunArgs {
 }
 
 pub fn is_local(args: &RunArgs) -> bool {
-    if args.affected {
+    if args.staged {
+        true
+    } else if args.affected {
         !args.remote
     } else {
         !is_ci()
@@ -118,17 +130,22 @@ pub async fn run_target(
         cache_engine.force_mode(CacheMode::Write);
     }
 
-    let mut should_run_affected = !args.force && args.affected;
+    let mut should_run_affected = !args.force && (args.affected || args.staged);
+    let status = if args.staged {
+        vec![TouchedStatus::Staged]
+    } else {
+        args.status.clone()
+    };
 
     // Always query for a touched files list as it'll be used by many actions
-    let touched_files = if vcs.is_enabled() {
+    let (touched_files, manifest_diffs) = if vcs.is_enabled() {
         let local = is_local(args);
         let result = query_touched_files(
             &vcs,
             &QueryTouchedFilesOptions {
                 default_branch: !local && !is_test_env(),
                 local,
-                status: args.status.clone(),
+                status: status.clone(),
                 ..QueryTouchedFilesOptions::default()
             },
         )
@@ -138,9 +155,15 @@ pub async fn run_target(
             should_run_affected = false;
         }
 
-        result.files
+        let manifest_diffs = if should_run_affected {
+            query_manifest_diffs(&vcs, &session.workspace_root, &result).await?
+        } else {
+            FxHashMap::default()
+        };
+
+        (result.files, manifest_diffs)
     } else {
-        FxHashSet::default()
+        (FxHashSet::default(), FxHashMap::default())
     };
 
     // Generate a dependency graph for all the targets that need to be ran
@@ -152,6 +175,10 @@ pub async fn run_target(
 
     if should_run_affected {
         action_graph_builder.set_touched_files(&touched_files)?;
+        action_graph_builder.set_manifest_diffs(
+            manifest_diffs,
+            &session.workspace_config.runner.hoisted_dependencies,
+        )?;
         action_graph_builder.set_affected_scopes(UpstreamScope::Deep, DownstreamScope::Deep)?;
     }
 
@@ -172,10 +199,10 @@ pub async fn run_target(
             .join(", ");
 
         if should_run_affected {
-            let status_list = if args.status.is_empty() {
+            let status_list = if status.is_empty() {
                 color::symbol(TouchedStatus::All.to_string())
             } else {
-                args.status
+                status
                     .iter()
                     .map(|s| color::symbol(s.to_string()))
                     .collect::<Vec<_>>()
@@ -200,8 +227,18 @@ pub async fn run_target(
         return Ok(None);
     }
 
+    // Only staged changes should be visible to tasks, so capture the
+    // originally staged files before hiding unstaged changes
+    let (staged_files, hidden_unstaged) = if args.staged {
+        let staged_files = vcs.get_touched_files().await?.staged;
+
+        (staged_files, vcs.hide_unstaged_changes().await?)
+    } else {
+        (FxHashSet::default(), false)
+    };
+
     // Process all tasks in the graph
-    run_action_pipeline(
+    let result = run_action_pipeline(
         session,
         ActionContext {
             passthrough_args: args.passthrough.to_owned(),
@@ -210,7 +247,48 @@ pub async fn run_target(
         },
         action_graph_builder.build(),
     )
-    .await?;
+    .await;
+
+    if args.staged {
+        // Re-stage files that were modified by tasks (formatters, etc),
+        // but only those that were originally staged
+        let stage_result = if result.is_ok() {
+            let staged_files = staged_files
+                .iter()
+                .filter(|file| file.to_logical_path(&session.workspace_root).exists())
+                .map(|file| file.to_string())
+                .collect::<Vec<_>>();
+
+            vcs.stage_files(&staged_files).await
+        } else {
+            Ok(())
+        };
+
+        // Always restore unstaged changes, even if staging failed,
+        // otherwise they would be left stashed away
+        let restore_result = if hidden_unstaged {
+            vcs.restore_unstaged_changes().await
+        } else {
+            Ok(())
+        };
+
+        // Report a failed restore first, as the unstaged changes must be
+        // recovered manually, but log other failures so they are not lost
+        if let Err(restore_error) = restore_result {
+            for error in [result.err(), stage_result.err()].into_iter().flatten() {
+                error!("{error}");
+            }
+
+            return Err(restore_error);
+        }
+
+        result?;
+        stage_result?;
+
+        return Ok(None);
+    }
+
+    result?;
 
     Ok(None)
 }
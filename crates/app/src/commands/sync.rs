@@ -1,6 +1,7 @@
 use super::syncs::codeowners::SyncCodeownersArgs;
 use super::syncs::config_schemas::SyncConfigSchemasArgs;
 use super::syncs::hooks::SyncHooksArgs;
+use super::syncs::projects::SyncProjectsArgs;
 use crate::session::CliSession;
 use clap::Subcommand;
 use starbase::AppResult;
@@ -31,14 +32,14 @@ pub enum SyncCommands {
         name = "projects",
         about = "Sync all projects and configs in the workspace."
     )]
-    Projects,
+    Projects(SyncProjectsArgs),
 }
 
-pub async fn sync(session: CliSession) -> AppResult {
+pub async fn sync(session: CliSession, check: bool) -> AppResult {
     warn!(
         "This command is deprecated. Use {} instead.",
         color::shell("moon sync projects")
     );
 
-    crate::commands::syncs::projects::sync(session).await
+    crate::commands::syncs::projects::sync(session, SyncProjectsArgs { check }).await
 }
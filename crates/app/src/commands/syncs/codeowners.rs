@@ -1,13 +1,17 @@
+use super::{extract_pending_diffs, print_pending_diffs};
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
 use clap::Args;
-use moon_actions::operations::{sync_codeowners, unsync_codeowners};
+use moon_actions::operations::{check_codeowners, sync_codeowners, unsync_codeowners};
 use starbase::AppResult;
 use starbase_styles::color;
 use tracing::instrument;
 
 #[derive(Args, Clone, Debug)]
 pub struct SyncCodeownersArgs {
+    #[arg(long, help = "Check for pending changes without writing the file")]
+    pub check: bool,
+
     #[arg(long, help = "Clean and remove previously generated file")]
     clean: bool,
 
@@ -17,6 +21,14 @@ pub struct SyncCodeownersArgs {
 
 #[instrument(skip_all)]
 pub async fn sync(session: CliSession, args: SyncCodeownersArgs) -> AppResult {
+    if args.check {
+        let context = session.get_app_context()?;
+        let workspace_graph = session.get_workspace_graph().await?;
+        let ops = check_codeowners(&context, &workspace_graph)?;
+
+        return print_pending_diffs(&session, extract_pending_diffs(&ops));
+    }
+
     let done = create_progress_bar("Syncing code owners...");
     let context = session.get_app_context()?;
 
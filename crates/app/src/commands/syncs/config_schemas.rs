@@ -1,18 +1,29 @@
+use super::{extract_pending_diffs, print_pending_diffs};
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
 use clap::Args;
-use moon_actions::operations::sync_config_schemas;
+use moon_actions::operations::{check_config_schemas, sync_config_schemas};
 use starbase::AppResult;
 use tracing::instrument;
 
 #[derive(Args, Clone, Debug)]
 pub struct SyncConfigSchemasArgs {
+    #[arg(long, help = "Check for pending changes without writing schemas")]
+    pub check: bool,
+
     #[arg(long, help = "Bypass cache and force create schemas")]
     force: bool,
 }
 
 #[instrument(skip_all)]
 pub async fn sync(session: CliSession, args: SyncConfigSchemasArgs) -> AppResult {
+    if args.check {
+        let context = session.get_app_context()?;
+        let ops = check_config_schemas(&context).await?;
+
+        return print_pending_diffs(&session, extract_pending_diffs(&ops));
+    }
+
     let done = create_progress_bar("Generating configuration schemas...");
 
     let context = session.get_app_context()?;
@@ -1,13 +1,17 @@
+use super::{extract_pending_diffs, print_pending_diffs};
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
 use clap::Args;
-use moon_actions::operations::{sync_vcs_hooks, unsync_vcs_hooks};
+use moon_actions::operations::{check_vcs_hooks, sync_vcs_hooks, unsync_vcs_hooks};
 use starbase::AppResult;
 use starbase_styles::color;
 use tracing::instrument;
 
 #[derive(Args, Clone, Debug)]
 pub struct SyncHooksArgs {
+    #[arg(long, help = "Check for pending changes without writing hooks")]
+    pub check: bool,
+
     #[arg(long, help = "Clean and remove previously generated hooks")]
     clean: bool,
 
@@ -30,6 +34,13 @@ pub async fn sync(session: CliSession, args: SyncHooksArgs) -> AppResult {
         return Ok(None);
     }
 
+    if args.check {
+        let context = session.get_app_context()?;
+        let ops = check_vcs_hooks(&context).await?;
+
+        return print_pending_diffs(&session, extract_pending_diffs(&ops));
+    }
+
     let done = create_progress_bar(format!(
         "Syncing {} hooks...",
         session.workspace_config.vcs.manager
@@ -2,3 +2,54 @@ pub mod codeowners;
 pub mod config_schemas;
 pub mod hooks;
 pub mod projects;
+
+use crate::session::CliSession;
+use moon_action::{ActionStatus, Operation, OperationMeta};
+use moon_common::color;
+use starbase::AppResult;
+
+/// Extract the diffs from file mutation operations that have pending changes.
+pub fn extract_pending_diffs<'op>(
+    operations: impl IntoIterator<Item = &'op Operation>,
+) -> Vec<String> {
+    operations
+        .into_iter()
+        .filter(|op| op.status == ActionStatus::Invalid)
+        .filter_map(|op| match &op.meta {
+            OperationMeta::FileMutation(meta) => meta.diff.clone(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Print the diffs of pending changes (via `--check`),
+/// and exit with a non-zero code if there are any.
+pub fn print_pending_diffs(session: &CliSession, diffs: Vec<String>) -> AppResult {
+    if diffs.is_empty() {
+        return Ok(None);
+    }
+
+    let console = &session.console;
+
+    for diff in diffs {
+        for line in diff.lines() {
+            console
+                .out
+                .write_line(if line.starts_with("+++") || line.starts_with("---") {
+                    color::muted_light(line)
+                } else if line.starts_with('+') {
+                    color::success(line)
+                } else if line.starts_with('-') {
+                    color::failure(line)
+                } else if line.starts_with("@@") {
+                    color::muted(line)
+                } else {
+                    line.to_owned()
+                })?;
+        }
+
+        console.out.write_newline()?;
+    }
+
+    Ok(Some(1))
+}
@@ -1,12 +1,26 @@
+use super::{extract_pending_diffs, print_pending_diffs};
 use crate::components::run_action_pipeline;
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
+use clap::Args;
+use moon_actions::utils::create_unified_diff;
+use moon_common::FileWriter;
 use starbase::AppResult;
 use tracing::instrument;
 
+#[derive(Args, Clone, Debug)]
+pub struct SyncProjectsArgs {
+    #[arg(long, help = "Check for pending changes without applying them")]
+    pub check: bool,
+}
+
 #[instrument(skip_all)]
-pub async fn sync(session: CliSession) -> AppResult {
-    let done = create_progress_bar("Syncing projects...");
+pub async fn sync(session: CliSession, args: SyncProjectsArgs) -> AppResult {
+    let done = create_progress_bar(if args.check {
+        "Checking projects..."
+    } else {
+        "Syncing projects..."
+    });
 
     let workspace_graph = session.get_workspace_graph().await?;
     let mut project_count = 0;
@@ -17,17 +31,42 @@ pub async fn sync(session: CliSession) -> AppResult {
         project_count += 1;
     }
 
-    run_action_pipeline(
-        &session,
-        action_graph_builder.build_context(),
-        action_graph_builder.build(),
-    )
-    .await?;
+    let file_writer = FileWriter::new(args.check);
+    let mut action_context = action_graph_builder.build_context();
+    action_context.file_writer = file_writer.clone();
+
+    let results =
+        run_action_pipeline(&session, action_context, action_graph_builder.build()).await?;
+
+    if !args.check {
+        done(
+            format!("Successfully synced {project_count} projects"),
+            true,
+        );
+
+        return Ok(None);
+    }
+
+    // Gather diffs from plugin operations and legacy platforms
+    let mut diffs =
+        extract_pending_diffs(results.iter().flat_map(|action| action.operations.iter()));
+
+    for (path, write) in file_writer.take_pending_writes() {
+        diffs.push(create_unified_diff(
+            &session.workspace_root,
+            &path,
+            write.current.as_deref(),
+            write.next.as_deref(),
+        ));
+    }
+
+    if diffs.is_empty() {
+        done(format!("All {project_count} projects are in sync"), true);
+
+        return Ok(None);
+    }
 
-    done(
-        format!("Successfully synced {project_count} projects"),
-        true,
-    );
+    done("Projects are out of sync".into(), false);
 
-    Ok(None)
+    print_pending_diffs(&session, diffs)
 }
@@ -10,6 +10,7 @@ use moon_workspace::{
     WorkspaceBuilderContext,
 };
 use starbase_events::{Emitter, EventState};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -56,11 +57,34 @@ pub async fn run_action_pipeline(
 pub async fn create_workspace_graph_context(
     session: &CliSession,
 ) -> miette::Result<WorkspaceBuilderContext> {
+    let toolchain_registry = session.get_toolchain_registry().await?;
+
+    // Plugins must be loaded before the graph is built,
+    // as they can extend projects and the graph itself
+    toolchain_registry.load_all().await?;
+
+    // Plugins infer from manifests and differ between versions,
+    // so both must be included when hashing the graph cache
+    let mut plugin_file_names = vec![];
+    let mut plugin_versions = BTreeMap::default();
+
+    for plugin_id in toolchain_registry.get_plugin_ids() {
+        let plugin = toolchain_registry.load(plugin_id).await?;
+
+        plugin_file_names.extend(plugin.metadata.manifest_file_names.clone());
+        plugin_versions.insert(
+            plugin_id.to_string(),
+            plugin.metadata.plugin_version.clone(),
+        );
+    }
+
     let context = WorkspaceBuilderContext {
         config_loader: &session.config_loader,
         extend_project: Emitter::<ExtendProjectEvent>::new(),
         extend_project_graph: Emitter::<ExtendProjectGraphEvent>::new(),
         inherited_tasks: &session.tasks_config,
+        plugin_file_names,
+        plugin_versions,
         toolchain_config: &session.toolchain_config,
         vcs: Some(session.get_vcs_adapter()?),
         working_dir: &session.working_dir,
@@ -68,42 +92,91 @@ pub async fn create_workspace_graph_context(
         workspace_root: &session.workspace_root,
     };
 
+    let registry = Arc::clone(&toolchain_registry);
+
     context
         .extend_project
         .on(
-            |event: Arc<ExtendProjectEvent>, data: Arc<RwLock<ExtendProjectData>>| async move {
-                let mut data = data.write().await;
+            move |event: Arc<ExtendProjectEvent>, data: Arc<RwLock<ExtendProjectData>>| {
+                let registry = Arc::clone(&registry);
+
+                async move {
+                    let mut data = data.write().await;
+
+                    for platform in PlatformManager::read().list() {
+                        data.dependencies
+                            .extend(platform.load_project_implicit_dependencies(
+                                &event.project_id,
+                                event.project_source.as_str(),
+                            )?);
 
-                for platform in PlatformManager::read().list() {
-                    data.dependencies
-                        .extend(platform.load_project_implicit_dependencies(
+                        data.tasks.extend(platform.load_project_tasks(
                             &event.project_id,
                             event.project_source.as_str(),
                         )?);
+                    }
 
-                    data.tasks.extend(
-                        platform
-                            .load_project_tasks(&event.project_id, event.project_source.as_str())?,
-                    );
-                }
+                    if registry.has_plugins() {
+                        let plugin_context = registry.create_context();
+
+                        for plugin_id in registry.get_plugin_ids() {
+                            if let Some(result) = registry
+                                .load(plugin_id)
+                                .await?
+                                .extend_project(
+                                    &event.project_id,
+                                    &event.project_source,
+                                    plugin_context.clone(),
+                                )
+                                .await?
+                            {
+                                data.dependencies.extend(result.dependencies);
+                                data.tasks.extend(result.tasks);
+                            }
+                        }
+                    }
 
-                Ok(EventState::Continue)
+                    Ok(EventState::Continue)
+                }
             },
         )
         .await;
 
+    let registry = Arc::clone(&toolchain_registry);
+
     context
         .extend_project_graph
-        .on(|event: Arc<ExtendProjectGraphEvent>, data: Arc<RwLock<ExtendProjectGraphData>>| async move {
-            let mut data = data.write().await;
+        .on(
+            move |event: Arc<ExtendProjectGraphEvent>,
+                  data: Arc<RwLock<ExtendProjectGraphData>>| {
+                let registry = Arc::clone(&registry);
 
+                async move {
+                    let mut data = data.write().await;
 
-            for platform in PlatformManager::write().list_mut() {
-                platform.load_project_graph_aliases(&event.sources, &mut data.aliases)?;
-            }
+                    for platform in PlatformManager::write().list_mut() {
+                        platform.load_project_graph_aliases(&event.sources, &mut data.aliases)?;
+                    }
 
-            Ok(EventState::Continue)
-        })
+                    if registry.has_plugins() {
+                        let plugin_context = registry.create_context();
+
+                        for plugin_id in registry.get_plugin_ids() {
+                            if let Some(result) = registry
+                                .load(plugin_id)
+                                .await?
+                                .extend_project_graph(&event.sources, plugin_context.clone())
+                                .await?
+                            {
+                                data.aliases.extend(result.aliases);
+                            }
+                        }
+                    }
+
+                    Ok(EventState::Continue)
+                }
+            },
+        )
         .await;
 
     Ok(context)
@@ -0,0 +1,322 @@
+use crate::session::CliSession;
+use crate::systems::startup;
+use moon_common::Id;
+use moon_config::DependencyScope;
+use moon_task::Task;
+use moon_vcs::BoxedVcs;
+use moon_workspace::{WorkspaceBuilder, WorkspaceBuilderContext};
+use moon_workspace_graph::WorkspaceGraph;
+use serde::{Deserialize, Serialize};
+use starbase_events::Emitter;
+use starbase_utils::fs;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryGraphDiffProject {
+    pub added_dependencies: Vec<Id>,
+    pub removed_dependencies: Vec<Id>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryGraphDiffTask {
+    pub base_command: Option<String>,
+    pub head_command: Option<String>,
+    pub added_deps: Vec<String>,
+    pub removed_deps: Vec<String>,
+    pub added_inputs: Vec<String>,
+    pub removed_inputs: Vec<String>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryGraphDiffResult {
+    pub base: String,
+    pub head: String,
+    pub added_projects: Vec<Id>,
+    pub removed_projects: Vec<Id>,
+    pub changed_projects: BTreeMap<Id, QueryGraphDiffProject>,
+    pub added_tasks: Vec<String>,
+    pub removed_tasks: Vec<String>,
+    pub changed_tasks: BTreeMap<String, QueryGraphDiffTask>,
+    pub new_violations: Vec<String>,
+}
+
+struct RevisionGraph {
+    graph: WorkspaceGraph,
+    violations: BTreeSet<String>,
+}
+
+fn is_config_file(file: &str) -> bool {
+    file.starts_with(".moon/")
+        || file
+            .rsplit('/')
+            .next()
+            .is_some_and(|name| name == "moon.yml" || name == "moon.pkl")
+}
+
+/// Write the moon configuration files of a revision into the provided directory,
+/// and create the directory structure of all other files, so that project globs
+/// continue to match. File contents are read through VCS, without checking out.
+async fn materialize_revision(vcs: &BoxedVcs, revision: &str, dir: &Path) -> miette::Result<()> {
+    debug!(
+        revision,
+        dir = ?dir,
+        "Materializing configuration files at revision"
+    );
+
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+
+    fs::create_dir_all(dir)?;
+
+    for file in vcs.get_file_tree_at_revision(".", revision).await? {
+        let path = file.to_logical_path(dir);
+
+        if is_config_file(file.as_str()) {
+            if let Some(content) = vcs
+                .get_file_content_at_revision(file.as_str(), revision)
+                .await?
+            {
+                fs::write_file(&path, content.as_bytes())?;
+            }
+        } else if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn build_revision_graph(
+    session: &CliSession,
+    revision: &str,
+    dir: PathBuf,
+) -> miette::Result<RevisionGraph> {
+    let vcs = session.get_vcs_adapter()?;
+
+    materialize_revision(&vcs, revision, &dir).await?;
+
+    let config_loader = session.config_loader.clone();
+    let workspace_config = startup::load_workspace_config(config_loader.clone(), &dir).await?;
+    let tasks_config = startup::load_tasks_configs(config_loader.clone(), &dir).await?;
+    let toolchain_config = startup::load_toolchain_config(
+        config_loader.clone(),
+        session.proto_env.clone(),
+        &dir,
+        &dir,
+    )
+    .await?;
+
+    // Toolchain and plugin extensions are not registered, as they
+    // read manifests from the current working tree, not the revision
+    let context = WorkspaceBuilderContext {
+        config_loader: &config_loader,
+        extend_project: Emitter::new(),
+        extend_project_graph: Emitter::new(),
+        inherited_tasks: &tasks_config,
+        plugin_file_names: vec![],
+        plugin_versions: BTreeMap::default(),
+        toolchain_config: &toolchain_config,
+        vcs: None,
+        working_dir: &dir,
+        workspace_config: &workspace_config,
+        workspace_root: &dir,
+    };
+
+    let mut builder = WorkspaceBuilder::new(context).await?;
+    builder.load_projects().await?;
+    builder.load_tasks().await?;
+
+    let (graph, violations) = builder.build_with_violations().await?;
+
+    Ok(RevisionGraph {
+        graph,
+        violations: violations
+            .into_iter()
+            .map(|violation| violation.to_string())
+            .collect(),
+    })
+}
+
+fn diff_sets<T: Clone + Ord>(base: &BTreeSet<T>, head: &BTreeSet<T>) -> (Vec<T>, Vec<T>) {
+    (
+        head.difference(base).cloned().collect(),
+        base.difference(head).cloned().collect(),
+    )
+}
+
+fn collect_task_deps(task: &Task) -> BTreeSet<String> {
+    task.deps.iter().map(|dep| dep.target.to_string()).collect()
+}
+
+fn collect_task_inputs(task: &Task) -> BTreeSet<String> {
+    task.inputs
+        .iter()
+        .map(|input| input.clone().into())
+        .collect()
+}
+
+fn diff_graphs(
+    base: &WorkspaceGraph,
+    head: &WorkspaceGraph,
+    result: &mut QueryGraphDiffResult,
+) -> miette::Result<()> {
+    let collect_projects = |graph: &WorkspaceGraph| -> miette::Result<BTreeMap<Id, BTreeSet<Id>>> {
+        Ok(graph
+            .get_projects()?
+            .into_iter()
+            .map(|project| {
+                let deps = project
+                    .dependencies
+                    .iter()
+                    .filter(|dep| !matches!(dep.scope, DependencyScope::Root))
+                    .map(|dep| dep.id.clone())
+                    .collect();
+
+                (project.id.clone(), deps)
+            })
+            .collect())
+    };
+
+    let base_projects = collect_projects(base)?;
+    let head_projects = collect_projects(head)?;
+
+    (result.added_projects, result.removed_projects) = diff_sets(
+        &base_projects.keys().cloned().collect(),
+        &head_projects.keys().cloned().collect(),
+    );
+
+    for (id, head_deps) in &head_projects {
+        let Some(base_deps) = base_projects.get(id) else {
+            continue;
+        };
+
+        let (added_dependencies, removed_dependencies) = diff_sets(base_deps, head_deps);
+
+        if !added_dependencies.is_empty() || !removed_dependencies.is_empty() {
+            result.changed_projects.insert(
+                id.to_owned(),
+                QueryGraphDiffProject {
+                    added_dependencies,
+                    removed_dependencies,
+                },
+            );
+        }
+    }
+
+    let collect_tasks = |graph: &WorkspaceGraph| -> miette::Result<BTreeMap<String, Task>> {
+        Ok(graph
+            .get_tasks_with_internal()?
+            .into_iter()
+            .map(|task| (task.target.to_string(), task.as_ref().to_owned()))
+            .collect())
+    };
+
+    let base_tasks = collect_tasks(base)?;
+    let head_tasks = collect_tasks(head)?;
+
+    (result.added_tasks, result.removed_tasks) = diff_sets(
+        &base_tasks.keys().cloned().collect(),
+        &head_tasks.keys().cloned().collect(),
+    );
+
+    for (target, head_task) in &head_tasks {
+        let Some(base_task) = base_tasks.get(target) else {
+            continue;
+        };
+
+        let (added_deps, removed_deps) =
+            diff_sets(&collect_task_deps(base_task), &collect_task_deps(head_task));
+        let (added_inputs, removed_inputs) = diff_sets(
+            &collect_task_inputs(base_task),
+            &collect_task_inputs(head_task),
+        );
+        let base_command = base_task.get_command_line().trim().to_owned();
+        let head_command = head_task.get_command_line().trim().to_owned();
+        let command_changed = base_command != head_command;
+
+        if command_changed
+            || !added_deps.is_empty()
+            || !removed_deps.is_empty()
+            || !added_inputs.is_empty()
+            || !removed_inputs.is_empty()
+        {
+            result.changed_tasks.insert(
+                target.to_owned(),
+                QueryGraphDiffTask {
+                    base_command: command_changed.then_some(base_command),
+                    head_command: command_changed.then_some(head_command),
+                    added_deps,
+                    removed_deps,
+                    added_inputs,
+                    removed_inputs,
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Build the workspace graph from the configuration files of the base and head
+/// revisions, and diff the projects, dependencies, and tasks between them. Also
+/// return constraint violations that only exist in the head revision.
+pub async fn query_graph_diff(
+    session: &CliSession,
+    base: &str,
+    head: &str,
+) -> miette::Result<QueryGraphDiffResult> {
+    debug!(
+        base,
+        head, "Diffing project and task graphs between revisions"
+    );
+
+    // Use a unique directory so that concurrent runs don't collide
+    let temp_dir = session
+        .get_cache_engine()?
+        .temp_dir
+        .join(format!("graph-diff-{}", Uuid::new_v4()));
+    let result = diff_revisions(session, base, head, &temp_dir).await;
+
+    // Remove the materialized configs, even when building a graph failed,
+    // but don't let a failed cleanup hide the result
+    if let Err(error) = fs::remove_dir_all(&temp_dir) {
+        warn!(
+            temp_dir = ?temp_dir,
+            "Failed to remove graph diff temporary directory: {error}"
+        );
+    }
+
+    result
+}
+
+async fn diff_revisions(
+    session: &CliSession,
+    base: &str,
+    head: &str,
+    temp_dir: &Path,
+) -> miette::Result<QueryGraphDiffResult> {
+    let base_graph = build_revision_graph(session, base, temp_dir.join("base")).await?;
+    let head_graph = build_revision_graph(session, head, temp_dir.join("head")).await?;
+
+    let mut result = QueryGraphDiffResult {
+        base: base.to_owned(),
+        head: head.to_owned(),
+        new_violations: head_graph
+            .violations
+            .difference(&base_graph.violations)
+            .cloned()
+            .collect(),
+        ..Default::default()
+    };
+
+    diff_graphs(&base_graph.graph, &head_graph.graph, &mut result)?;
+
+    Ok(result)
+}
@@ -0,0 +1,101 @@
+use moon_common::Id;
+use moon_config::DependencyScope;
+use moon_project_graph::{find_cycles, GraphAnalysis, GraphNodeStats};
+use moon_task::Target;
+use moon_workspace_graph::WorkspaceGraph;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+use tracing::debug;
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryGraphStats<K> {
+    pub cycles: Vec<Vec<K>>,
+    pub longest_chains: Vec<Vec<K>>,
+    pub nodes: Vec<GraphNodeStats<K>>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryGraphStatsResult {
+    pub orphaned_projects: Vec<Id>,
+    pub projects: QueryGraphStats<Id>,
+    pub tasks: QueryGraphStats<Target>,
+}
+
+fn analyze_graph<N, E, K: Hash + Eq>(
+    graph: &impl GraphAnalysis<N, E, K>,
+    cycles: Vec<Vec<K>>,
+    limit: usize,
+) -> QueryGraphStats<K> {
+    let mut longest_chains = graph.get_longest_chains();
+    longest_chains.retain(|chain| chain.len() > 1);
+    longest_chains.truncate(limit);
+
+    QueryGraphStats {
+        cycles,
+        longest_chains,
+        nodes: graph.get_node_stats(),
+    }
+}
+
+/// Analyze the project and task graphs, and return the fan-in, fan-out, and
+/// blast radius of each node, along with declared cycles, the longest dependency chains,
+/// and projects that have no dependents and no tasks.
+pub fn query_graph_stats(
+    workspace_graph: &WorkspaceGraph,
+    limit: usize,
+) -> miette::Result<QueryGraphStatsResult> {
+    debug!("Querying for project and task graph stats");
+
+    let all_projects = workspace_graph.get_projects()?;
+    let all_tasks = workspace_graph.get_tasks_with_internal()?;
+
+    let projects = analyze_graph(
+        workspace_graph.projects.as_ref(),
+        find_cycles(all_projects.iter().map(|project| {
+            (
+                project.id.clone(),
+                project
+                    .dependencies
+                    .iter()
+                    .filter(|dep| !matches!(dep.scope, DependencyScope::Root))
+                    .map(|dep| dep.id.clone())
+                    .collect(),
+            )
+        })),
+        limit,
+    );
+
+    let tasks = analyze_graph(
+        workspace_graph.tasks.as_ref(),
+        find_cycles(all_tasks.iter().map(|task| {
+            (
+                task.target.clone(),
+                task.deps.iter().map(|dep| dep.target.clone()).collect(),
+            )
+        })),
+        limit,
+    );
+
+    let mut orphaned_projects = vec![];
+
+    for project in all_projects {
+        let has_dependents = projects
+            .nodes
+            .iter()
+            .any(|node| node.id == project.id && node.fan_in > 0);
+
+        if !has_dependents && project.task_targets.is_empty() {
+            orphaned_projects.push(project.id.clone());
+        }
+    }
+
+    orphaned_projects.sort();
+
+    Ok(QueryGraphStatsResult {
+        orphaned_projects,
+        projects,
+        tasks,
+    })
+}
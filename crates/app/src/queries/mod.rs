@@ -1,5 +1,8 @@
+pub mod graph_diff;
+pub mod graph_stats;
 pub mod hash;
 pub mod hash_diff;
+pub mod owners;
 pub mod projects;
 pub mod tasks;
 pub mod touched_files;
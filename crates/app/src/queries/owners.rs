@@ -0,0 +1,97 @@
+use moon_codeowners::{CodeownersMatch, CodeownersResolver};
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_common::Id;
+use moon_workspace_graph::WorkspaceGraph;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::debug;
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct QueryOwnersResult {
+    pub files: BTreeMap<WorkspaceRelativePathBuf, CodeownersMatch>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOwnersCoverageResult {
+    pub total_files: usize,
+    pub owned_files: usize,
+    pub unowned_files: Vec<WorkspaceRelativePathBuf>,
+    pub unowned_projects: Vec<Id>,
+}
+
+impl QueryOwnersCoverageResult {
+    pub fn get_percentage(&self) -> f32 {
+        if self.total_files == 0 {
+            return 100.0;
+        }
+
+        (self.owned_files as f32 / self.total_files as f32) * 100.0
+    }
+}
+
+/// Resolve the owners of each file, relative from the workspace root.
+pub fn query_owners<'a>(
+    resolver: &CodeownersResolver,
+    files: impl IntoIterator<Item = &'a WorkspaceRelativePathBuf>,
+) -> QueryOwnersResult {
+    debug!("Querying for file owners");
+
+    let mut result = QueryOwnersResult::default();
+
+    for file in files {
+        result
+            .files
+            .insert(file.to_owned(), resolver.resolve(file.as_str()));
+    }
+
+    result
+}
+
+/// Determine which files and projects have no owner. A project is considered
+/// unowned when its root, and all of its files, have no owner.
+pub fn query_owners_coverage(
+    resolver: &CodeownersResolver,
+    workspace_graph: &WorkspaceGraph,
+    files: &[WorkspaceRelativePathBuf],
+) -> miette::Result<QueryOwnersCoverageResult> {
+    debug!("Querying for ownership coverage");
+
+    let mut result = QueryOwnersCoverageResult {
+        total_files: files.len(),
+        ..Default::default()
+    };
+    let mut owned_files = vec![];
+
+    for file in files {
+        if resolver.resolve(file.as_str()).is_owned() {
+            owned_files.push(file);
+        } else {
+            result.unowned_files.push(file.to_owned());
+        }
+    }
+
+    result.owned_files = owned_files.len();
+    result.unowned_files.sort();
+
+    for project in workspace_graph.get_projects()? {
+        let is_owned = if project.source.as_str() == "." {
+            !owned_files.is_empty()
+        } else {
+            resolver.resolve(project.source.as_str()).is_owned()
+                || owned_files
+                    .iter()
+                    .any(|file| file.starts_with(&project.source))
+        };
+
+        if is_owned {
+            continue;
+        }
+
+        result.unowned_projects.push(project.id.clone());
+    }
+
+    result.unowned_projects.sort();
+
+    Ok(result)
+}
@@ -1,13 +1,15 @@
 use miette::IntoDiagnostic;
+use moon_affected::{ManifestDiff, ManifestKind};
 use moon_common::is_ci;
 use moon_common::path::{standardize_separators, WorkspaceRelativePathBuf};
 use moon_vcs::{BoxedVcs, TouchedStatus};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use starbase_styles::color;
-use starbase_utils::json;
+use starbase_utils::{fs, json};
 use std::env;
 use std::io::{stdin, IsTerminal, Read};
+use std::path::Path;
 use tracing::{debug, trace, warn};
 
 #[derive(Clone, Default, Deserialize, Serialize)]
@@ -26,6 +28,15 @@ pub struct QueryTouchedFilesResult {
     pub files: FxHashSet<WorkspaceRelativePathBuf>,
     pub options: QueryTouchedFilesOptions,
     pub shallow: bool,
+
+    /// The revision that touched files were compared against.
+    #[serde(skip)]
+    pub base_revision: Option<String>,
+
+    /// The revision that touched files were compared from,
+    /// or `None` if compared from the working tree.
+    #[serde(skip)]
+    pub head_revision: Option<String>,
 }
 
 // If we're in a shallow checkout, many diff commands will fail
@@ -60,10 +71,14 @@ pub async fn query_touched_files(
     let base_revision = env::var("MOON_BASE").ok().or(options.base.clone());
     let head_revision = env::var("MOON_HEAD").ok().or(options.head.clone());
 
+    let compared_revisions;
+
     // Check locally touched files
     let touched_files_map = if options.local {
         trace!("Against local");
 
+        compared_revisions = ("HEAD".to_owned(), None);
+
         vcs.get_touched_files().await?
     }
     // Otherwise compare against remote
@@ -80,6 +95,11 @@ pub async fn query_touched_files(
             current_branch
         );
 
+        compared_revisions = (
+            vcs.get_previous_revision("HEAD").await?.to_string(),
+            Some("HEAD".to_owned()),
+        );
+
         vcs.get_touched_files_against_previous_revision(&default_branch)
             .await?
     } else {
@@ -98,6 +118,11 @@ pub async fn query_touched_files(
             head,
         );
 
+        compared_revisions = (
+            vcs.get_merge_base_revision(base, head).await?.to_string(),
+            Some(head.to_owned()),
+        );
+
         vcs.get_touched_files_between_revisions(base, head).await?
     };
 
@@ -150,9 +175,68 @@ pub async fn query_touched_files(
         files: touched_files,
         options: options.to_owned(),
         shallow: false,
+        base_revision: Some(compared_revisions.0),
+        head_revision: compared_revisions.1,
     })
 }
 
+/// Diff the content of touched manifests in the workspace root between the
+/// compared revisions, so that affected detection can ignore changes that
+/// don't impact a project.
+pub async fn query_manifest_diffs(
+    vcs: &BoxedVcs,
+    workspace_root: &Path,
+    result: &QueryTouchedFilesResult,
+) -> miette::Result<FxHashMap<WorkspaceRelativePathBuf, ManifestDiff>> {
+    let mut diffs = FxHashMap::default();
+
+    let Some(base_revision) = &result.base_revision else {
+        return Ok(diffs);
+    };
+
+    // When only staged, compare against the index instead of the working tree,
+    // as the working tree may contain unstaged changes
+    let only_staged = result.options.status == [TouchedStatus::Staged];
+
+    for file in &result.files {
+        let Some(kind) = ManifestKind::detect(file) else {
+            continue;
+        };
+
+        let before = vcs
+            .get_file_content_at_revision(file.as_str(), base_revision)
+            .await?;
+        let after = match &result.head_revision {
+            Some(head_revision) => vcs
+                .get_file_content_at_revision(file.as_str(), head_revision)
+                .await?
+                .map(|content| content.to_string()),
+            None if only_staged => vcs
+                .get_file_content_at_revision(file.as_str(), "")
+                .await?
+                .map(|content| content.to_string()),
+            None => fs::read_file(file.to_logical_path(workspace_root)).ok(),
+        };
+        let diff = ManifestDiff::new(
+            kind,
+            before.as_deref().map(|c| c.as_str()),
+            after.as_deref(),
+        );
+
+        debug!(
+            file = file.as_str(),
+            dependencies = ?diff.dependencies.iter().collect::<Vec<_>>(),
+            other = diff.other,
+            "Diffed manifest against revision {}",
+            color::hash(base_revision),
+        );
+
+        diffs.insert(file.to_owned(), diff);
+    }
+
+    Ok(diffs)
+}
+
 pub async fn load_touched_files(
     vcs: &BoxedVcs,
 ) -> miette::Result<FxHashSet<WorkspaceRelativePathBuf>> {
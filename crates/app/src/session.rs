@@ -7,7 +7,9 @@ use moon_action_graph::ActionGraphBuilder;
 use moon_app_context::AppContext;
 use moon_cache::CacheEngine;
 use moon_common::{is_ci, is_test_env};
-use moon_config::{ConfigLoader, InheritedTasksManager, ToolchainConfig, WorkspaceConfig};
+use moon_config::{
+    ConfigLoader, InheritedTasksManager, ToolchainConfig, VcsBackend, WorkspaceConfig,
+};
 use moon_console::Console;
 use moon_console_reporter::DefaultReporter;
 use moon_env::MoonEnvironment;
@@ -16,7 +18,7 @@ use moon_plugin::{PluginHostData, PluginId};
 use moon_project_graph::ProjectGraph;
 use moon_task_graph::TaskGraph;
 use moon_toolchain_plugin::*;
-use moon_vcs::{BoxedVcs, Git};
+use moon_vcs::{BoxedVcs, Git, Gitoxide};
 use moon_workspace::WorkspaceBuilder;
 use moon_workspace_graph::WorkspaceGraph;
 use once_cell::sync::OnceCell;
@@ -26,7 +28,7 @@ use starbase::{AppResult, AppSession};
 use std::env;
 use std::fmt;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use tokio::try_join;
 use tracing::debug;
 
@@ -48,6 +50,7 @@ pub struct CliSession {
     task_graph: OnceCell<Arc<TaskGraph>>,
     toolchain_registry: OnceCell<Arc<ToolchainRegistry>>,
     vcs_adapter: OnceCell<Arc<BoxedVcs>>,
+    workspace_graph: Arc<OnceLock<WorkspaceGraph>>,
 
     // Configs
     pub tasks_config: Arc<InheritedTasksManager>,
@@ -80,6 +83,7 @@ impl CliSession {
             workspace_root: PathBuf::new(),
             workspace_config: Arc::new(WorkspaceConfig::default()),
             vcs_adapter: OnceCell::new(),
+            workspace_graph: Arc::new(OnceLock::new()),
             cli,
         }
     }
@@ -117,13 +121,13 @@ impl CliSession {
     }
 
     pub async fn get_extension_registry(&self) -> miette::Result<Arc<ExtensionRegistry>> {
-        let workspace_graph = self.get_workspace_graph().await?;
-
+        // Extensions can be loaded without the workspace graph (for completions),
+        // so the graph must be built before executing them
         let item = self.extension_registry.get_or_init(|| {
             let mut registry = ExtensionRegistry::new(PluginHostData {
                 moon_env: Arc::clone(&self.moon_env),
                 proto_env: Arc::clone(&self.proto_env),
-                workspace_graph,
+                workspace_graph: Arc::clone(&self.workspace_graph),
             });
 
             // Convert moon IDs to plugin IDs
@@ -154,13 +158,13 @@ impl CliSession {
     }
 
     pub async fn get_toolchain_registry(&self) -> miette::Result<Arc<ToolchainRegistry>> {
-        let workspace_graph = self.get_workspace_graph().await?;
-
+        // Toolchains are used while building the workspace graph,
+        // so the graph will be provided to plugins once it's built
         let item = self.toolchain_registry.get_or_init(|| {
             let mut registry = ToolchainRegistry::new(PluginHostData {
                 moon_env: Arc::clone(&self.moon_env),
                 proto_env: Arc::clone(&self.proto_env),
-                workspace_graph,
+                workspace_graph: Arc::clone(&self.workspace_graph),
             });
 
             // Convert moon IDs to plugin IDs
@@ -177,13 +181,21 @@ impl CliSession {
     pub fn get_vcs_adapter(&self) -> miette::Result<Arc<BoxedVcs>> {
         let item = self.vcs_adapter.get_or_try_init(|| {
             let config = &self.workspace_config.vcs;
-            let git = Git::load(
-                &self.workspace_root,
-                &config.default_branch,
-                &config.remote_candidates,
-            )?;
 
-            Ok::<_, miette::Report>(Arc::new(Box::new(git)))
+            let vcs: BoxedVcs = match config.backend {
+                VcsBackend::Cli => Box::new(Git::load(
+                    &self.workspace_root,
+                    &config.default_branch,
+                    &config.remote_candidates,
+                )?),
+                VcsBackend::Gitoxide => Box::new(Gitoxide::load(
+                    &self.workspace_root,
+                    &config.default_branch,
+                    &config.remote_candidates,
+                )?),
+            };
+
+            Ok::<_, miette::Report>(Arc::new(vcs))
         })?;
 
         Ok(Arc::clone(item))
@@ -220,8 +232,9 @@ impl CliSession {
         let builder = WorkspaceBuilder::new_with_cache(context, &cache_engine).await?;
         let result = builder.build().await?;
 
-        let _ = self.project_graph.set(result.projects);
-        let _ = self.task_graph.set(result.tasks);
+        let _ = self.project_graph.set(Arc::clone(&result.projects));
+        let _ = self.task_graph.set(Arc::clone(&result.tasks));
+        let _ = self.workspace_graph.set(result);
 
         Ok(())
     }
@@ -142,6 +142,7 @@ async fn main() -> MainResult {
                 }
                 Commands::Docker { command } => match command {
                     DockerCommands::File(args) => commands::docker::file(session, args).await,
+                    DockerCommands::Ignore => commands::docker::ignore(session).await,
                     DockerCommands::Prune => commands::docker::prune(session).await,
                     DockerCommands::Scaffold(args) => {
                         commands::docker::scaffold(session, args).await
@@ -171,10 +172,17 @@ async fn main() -> MainResult {
                     commands::graph::project::project_graph(session, args).await
                 }
                 Commands::Query { command } => match command {
+                    QueryCommands::GraphDiff(args) => {
+                        commands::query::graph_diff(session, args).await
+                    }
+                    QueryCommands::GraphStats(args) => {
+                        commands::query::graph_stats(session, args).await
+                    }
                     QueryCommands::Hash(args) => commands::query::hash(session, args).await,
                     QueryCommands::HashDiff(args) => {
                         commands::query::hash_diff(session, args).await
                     }
+                    QueryCommands::Owners(args) => commands::query::owners(session, args).await,
                     QueryCommands::Projects(args) => commands::query::projects(session, args).await,
                     QueryCommands::Tasks(args) => commands::query::tasks(session, args).await,
                     QueryCommands::TouchedFiles(args) => {
@@ -183,18 +191,25 @@ async fn main() -> MainResult {
                 },
                 Commands::Run(args) => commands::run::run(session, args).await,
                 Commands::Setup => commands::setup::setup(session).await,
-                Commands::Sync { command } => match command {
-                    Some(SyncCommands::Codeowners(args)) => {
+                // `--check` may be passed before or after the sub-command
+                Commands::Sync { command, check } => match command {
+                    Some(SyncCommands::Codeowners(mut args)) => {
+                        args.check |= check;
                         commands::syncs::codeowners::sync(session, args).await
                     }
-                    Some(SyncCommands::ConfigSchemas(args)) => {
+                    Some(SyncCommands::ConfigSchemas(mut args)) => {
+                        args.check |= check;
                         commands::syncs::config_schemas::sync(session, args).await
                     }
-                    Some(SyncCommands::Hooks(args)) => {
+                    Some(SyncCommands::Hooks(mut args)) => {
+                        args.check |= check;
                         commands::syncs::hooks::sync(session, args).await
                     }
-                    Some(SyncCommands::Projects) => commands::syncs::projects::sync(session).await,
-                    None => commands::sync::sync(session).await,
+                    Some(SyncCommands::Projects(mut args)) => {
+                        args.check |= check;
+                        commands::syncs::projects::sync(session, args).await
+                    }
+                    None => commands::sync::sync(session, check).await,
                 },
                 Commands::Task(args) => commands::task::task(session, args).await,
                 Commands::TaskGraph(args) => commands::graph::task::task_graph(session, args).await,
@@ -127,6 +127,27 @@ mod action_graph {
         assert_snapshot!(assert.output());
     }
 
+    #[test]
+    fn outputs_mermaid() {
+        let (workspace_config, toolchain_config, tasks_config) = get_tasks_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "tasks",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("action-graph")
+                .arg("--format")
+                .arg("mermaid")
+                .arg("basic:lint");
+        });
+
+        assert_snapshot!(assert.output());
+    }
+
     mod aliases {
         use super::*;
 
@@ -124,6 +124,94 @@ mod dockerfile {
         assert!(file.contains("MOON_TOOLCHAIN_FORCE_GLOBALS=1"));
     }
 
+    #[test]
+    fn can_include_multiple_projects() {
+        let sandbox = create_moon_sandbox("dockerfile");
+
+        sandbox
+            .run_bin(|cmd| {
+                cmd.args([
+                    "docker",
+                    "file",
+                    "has-tasks",
+                    "--project",
+                    "with-config",
+                    "--defaults",
+                    "--buildTask",
+                    "build",
+                    "--startTask",
+                    "start",
+                ]);
+            })
+            .success();
+
+        let file = fs::read_to_string(sandbox.path().join("has-tasks/Dockerfile")).unwrap();
+
+        assert!(file.contains("RUN moon docker scaffold has-tasks with-config"));
+        assert!(file.contains("FROM build AS build-has-tasks"));
+        assert!(file.contains("moon run has-tasks:build"));
+        assert!(file.contains("FROM base AS start-has-tasks"));
+        assert!(file.contains("FROM build AS build-with-config"));
+        assert!(file.contains("moon run with-config:compile"));
+        assert!(file.contains("FROM base AS start-with-config"));
+        assert!(file.contains("moon run with-config:serve"));
+    }
+
+    #[test]
+    fn errors_for_unknown_additional_project() {
+        let sandbox = create_moon_sandbox("dockerfile");
+
+        let assert = sandbox.run_bin(|cmd| {
+            cmd.args([
+                "docker",
+                "file",
+                "has-tasks",
+                "--project",
+                "missing",
+                "--defaults",
+            ]);
+        });
+
+        assert.inner.stderr(predicate::str::contains(
+            "No project has been configured with the identifier or alias missing.",
+        ));
+    }
+
+    #[test]
+    fn uses_workspace_file_config() {
+        let sandbox = create_moon_sandbox("dockerfile");
+
+        sandbox.create_file(
+            ".moon/workspace.yml",
+            "projects: ['*']\ndocker:\n  file:\n    cacheMounts: true\n    copyLink: true\n    toolchainStage: true",
+        );
+        sandbox.create_file(".prototools", "");
+
+        sandbox
+            .run_bin(|cmd| {
+                cmd.args([
+                    "docker",
+                    "file",
+                    "has-tasks",
+                    "--defaults",
+                    "--buildTask",
+                    "build",
+                ]);
+            })
+            .success();
+
+        let file = fs::read_to_string(sandbox.path().join("has-tasks/Dockerfile")).unwrap();
+
+        assert!(file.starts_with("# syntax=docker/dockerfile:1"));
+        assert!(file.contains("COPY --link . ."));
+        assert!(file.contains("FROM base AS toolchain"));
+        assert!(file.contains("COPY --link .moon/workspace.yml .moon/workspace.yml"));
+        assert!(file.contains("COPY --link .prototools .prototools"));
+        assert!(file.contains(
+            "--mount=type=cache,target=/app/.moon/cache/hashes moon run has-tasks:build"
+        ));
+    }
+
     #[test]
     fn uses_docker_config() {
         let sandbox = create_moon_sandbox("dockerfile");
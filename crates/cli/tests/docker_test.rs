@@ -404,6 +404,59 @@ mod scaffold_sources {
     }
 }
 
+mod ignore {
+    use super::*;
+
+    #[test]
+    fn generates_ignore_file() {
+        let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "projects",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("docker").arg("ignore");
+        });
+
+        let file = fs::read_to_string(sandbox.path().join(".dockerignore")).unwrap();
+
+        assert!(file.contains(".moon/cache\n"));
+        assert!(file.contains("**/node_modules\n"));
+        assert!(file.contains("tasks/cache\n"));
+    }
+
+    #[test]
+    fn preserves_manual_entries() {
+        let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "projects",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        sandbox.create_file(".dockerignore", ".git\n.moon/cache\n");
+
+        // Run twice to ensure the generated block is replaced
+        for _ in 0..2 {
+            sandbox.run_moon(|cmd| {
+                cmd.arg("docker").arg("ignore");
+            });
+        }
+
+        let file = fs::read_to_string(sandbox.path().join(".dockerignore")).unwrap();
+
+        assert!(file.starts_with(".git\n.moon/cache\n\n"));
+        assert_eq!(file.matches(".moon/cache").count(), 1);
+        assert_eq!(file.matches("tasks/cache").count(), 1);
+    }
+}
+
 mod prune {
     use super::*;
 
@@ -446,3 +446,649 @@ mod frontmatter {
         .unwrap());
     }
 }
+
+mod update {
+    use super::*;
+
+    fn update_sandbox() -> Sandbox {
+        let sandbox = generate_sandbox();
+
+        sandbox.create_file(
+            "templates/update/template.yml",
+            r#"
+title: 'Update'
+description: 'A template for testing updates.'
+lock: true
+variables:
+  name:
+    type: 'string'
+    default: 'default'
+"#,
+        );
+        sandbox.create_file("templates/update/file.txt", "name: {{ name }}\nb\nc\nd\n");
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("update")
+                    .arg("./test")
+                    .arg("--defaults")
+                    .arg("--")
+                    .arg("--name=custom");
+            })
+            .success();
+
+        sandbox
+    }
+
+    #[test]
+    fn records_a_lockfile() {
+        let sandbox = update_sandbox();
+
+        let lock =
+            fs::read_to_string(sandbox.path().join("test/.moon-template-update.lock.yml")).unwrap();
+
+        assert!(lock.contains("template: update"));
+        assert!(lock.contains("name: custom"));
+        assert!(lock.contains("file.txt: |"));
+    }
+
+    #[test]
+    fn errors_when_no_lockfile() {
+        let sandbox = generate_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--update");
+        });
+
+        assert.failure().stderr(predicate::str::contains(
+            "no template lockfile could be found",
+        ));
+    }
+
+    #[test]
+    fn doesnt_record_a_lockfile_if_not_enabled() {
+        let sandbox = generate_sandbox();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("standard")
+                    .arg("./test")
+                    .arg("--defaults");
+            })
+            .success();
+
+        assert!(!sandbox
+            .path()
+            .join("test/.moon-template-standard.lock.yml")
+            .exists());
+    }
+
+    #[test]
+    fn errors_when_other_template_is_locked() {
+        let sandbox = update_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--update");
+        });
+
+        assert.failure().stderr(predicate::str::contains(
+            "no template lockfile could be found",
+        ));
+
+        assert!(sandbox
+            .path()
+            .join("test/.moon-template-update.lock.yml")
+            .exists());
+    }
+
+    #[test]
+    fn resolves_destination_from_template_config() {
+        let sandbox = generate_sandbox();
+
+        sandbox.create_file(
+            "templates/update-dest/template.yml",
+            r#"
+title: 'Update'
+description: 'A template for testing updates.'
+destination: 'packages/{{ name }}'
+lock: true
+variables:
+  name:
+    type: 'string'
+    default: 'default'
+"#,
+        );
+        sandbox.create_file("templates/update-dest/file.txt", "name: {{ name }}\nb\n");
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("update-dest")
+                    .arg("--defaults")
+                    .arg("--")
+                    .arg("--name=custom");
+            })
+            .success();
+
+        sandbox.create_file("templates/update-dest/file.txt", "name: {{ name }}\nB\n");
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("update-dest")
+                    .arg("--update")
+                    .arg("--defaults")
+                    .arg("--")
+                    .arg("--name=custom");
+            })
+            .success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("packages/custom/file.txt")).unwrap(),
+            "name: custom\nB\n"
+        );
+    }
+
+    #[test]
+    fn merges_template_changes_into_modified_files() {
+        let sandbox = update_sandbox();
+
+        sandbox.create_file("test/file.txt", "name: custom\nb\nc\nd\nuser\n");
+        sandbox.create_file("templates/update/file.txt", "name: {{ name }}\nB\nc\nd\n");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("update")
+                .arg("./test")
+                .arg("--update")
+                .arg("--defaults");
+        });
+
+        assert.success().stdout(predicate::str::contains("updated"));
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "name: custom\nB\nc\nd\nuser\n"
+        );
+    }
+
+    #[test]
+    fn marks_conflicts() {
+        let sandbox = update_sandbox();
+
+        sandbox.create_file("test/file.txt", "name: custom\nmine\nc\nd\n");
+        sandbox.create_file(
+            "templates/update/file.txt",
+            "name: {{ name }}\ntheirs\nc\nd\n",
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("update")
+                .arg("./test")
+                .arg("--update")
+                .arg("--defaults");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("conflict"));
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "name: custom\n<<<<<<< current\nmine\n=======\ntheirs\n>>>>>>> template\nc\nd\n"
+        );
+    }
+
+    #[test]
+    fn records_template_content_instead_of_merged_content() {
+        let sandbox = update_sandbox();
+
+        sandbox.create_file("test/file.txt", "name: custom\nmine\nc\nd\n");
+        sandbox.create_file(
+            "templates/update/file.txt",
+            "name: {{ name }}\ntheirs\nc\nd\n",
+        );
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("update")
+                    .arg("./test")
+                    .arg("--update")
+                    .arg("--defaults");
+            })
+            .success();
+
+        let lock =
+            fs::read_to_string(sandbox.path().join("test/.moon-template-update.lock.yml")).unwrap();
+
+        assert!(lock.contains("theirs"));
+        assert!(!lock.contains("<<<<<<<"));
+    }
+
+    #[test]
+    fn doesnt_recreate_deleted_files() {
+        let sandbox = update_sandbox();
+
+        fs::remove_file(sandbox.path().join("test/file.txt")).unwrap();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("update")
+                    .arg("./test")
+                    .arg("--update")
+                    .arg("--defaults");
+            })
+            .success();
+
+        assert!(!sandbox.path().join("test/file.txt").exists());
+    }
+}
+
+mod hooks {
+    use super::*;
+
+    fn hooks_sandbox() -> Sandbox {
+        let sandbox = generate_sandbox();
+
+        sandbox.create_file(
+            "templates/hooks/template.yml",
+            r#"
+title: 'Hooks'
+description: 'A template for testing hooks.'
+variables:
+  name:
+    type: 'string'
+    default: 'default'
+hooks:
+  preGenerate: ['echo pre > pre.txt']
+  postGenerate: ['echo $MOON_VAR_NAME > post.txt']
+"#,
+        );
+        sandbox.create_file("templates/hooks/file.txt", "{{ name }}");
+
+        sandbox
+    }
+
+    #[test]
+    fn runs_hooks_in_destination() {
+        let sandbox = hooks_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("hooks")
+                .arg("./test")
+                .arg("--defaults")
+                .arg("--")
+                .arg("--name=custom");
+        });
+
+        assert.success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/pre.txt")).unwrap(),
+            "pre\n"
+        );
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/post.txt")).unwrap(),
+            "custom\n"
+        );
+    }
+
+    #[test]
+    fn prints_hooks_when_dryrun() {
+        let sandbox = hooks_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("hooks")
+                .arg("./test")
+                .arg("--defaults")
+                .arg("--dryRun");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("Would run preGenerate hooks"))
+            .stdout(predicate::str::contains("echo pre > pre.txt"))
+            .stdout(predicate::str::contains("Would run postGenerate hooks"));
+
+        assert!(!sandbox.path().join("test/pre.txt").exists());
+        assert!(!sandbox.path().join("test/post.txt").exists());
+    }
+
+    #[test]
+    fn runs_target_hooks_through_moon_run() {
+        let sandbox = hooks_sandbox();
+
+        sandbox.create_file(".moon/workspace.yml", "projects:\n  tool: 'tool'\n");
+        sandbox.create_file(
+            "tool/moon.yml",
+            "tasks:\n  mark:\n    script: 'echo target > target.txt'\n    options:\n      cache: false\n",
+        );
+        sandbox.create_file(
+            "templates/hooks/template.yml",
+            r#"
+title: 'Hooks'
+description: 'A template for testing hooks.'
+variables:
+  name:
+    type: 'string'
+    default: 'default'
+hooks:
+  postGenerate: ['tool:mark']
+"#,
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("hooks")
+                .arg("./test")
+                .arg("--defaults");
+        });
+
+        assert.success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("tool/target.txt")).unwrap(),
+            "target\n"
+        );
+    }
+
+    #[test]
+    fn runs_single_word_hooks_as_commands() {
+        let sandbox = hooks_sandbox();
+
+        sandbox.create_file(
+            "templates/hooks/template.yml",
+            r#"
+title: 'Hooks'
+description: 'A template for testing hooks.'
+variables:
+  name:
+    type: 'string'
+    default: 'default'
+hooks:
+  postGenerate: ['pwd']
+"#,
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("hooks")
+                .arg("./test")
+                .arg("--defaults");
+        });
+
+        assert.success().stdout(predicate::str::contains("test"));
+    }
+
+    #[test]
+    fn errors_when_hook_fails() {
+        let sandbox = hooks_sandbox();
+
+        sandbox.create_file(
+            "templates/hooks/template.yml",
+            r#"
+title: 'Hooks'
+description: 'A template for testing hooks.'
+hooks:
+  preGenerate: ['exit 1']
+"#,
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("hooks")
+                .arg("./test")
+                .arg("--defaults");
+        });
+
+        assert.failure();
+
+        assert!(!sandbox.path().join("test/file.txt").exists());
+    }
+}
+
+mod answers {
+    use super::*;
+
+    fn answers_sandbox() -> Sandbox {
+        let sandbox = generate_sandbox();
+
+        sandbox.create_file(
+            "templates/answers/template.yml",
+            r#"
+title: 'Answers'
+description: 'A template for testing answers.'
+variables:
+  name:
+    type: 'string'
+    default: 'default'
+    prompt: 'Name?'
+  color:
+    type: 'enum'
+    values: ['red', 'blue']
+    default: ['red']
+    multiple: true
+    prompt: 'Colors?'
+"#,
+        );
+        sandbox.create_file(
+            "templates/answers/file.txt",
+            "{{ name }} {{ color | join(sep=\",\") }}",
+        );
+
+        sandbox
+    }
+
+    #[test]
+    fn uses_values_from_file() {
+        let sandbox = answers_sandbox();
+        sandbox.create_file("answers.yml", "name: custom\ncolor: [red, blue]\n");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("answers")
+                .arg("./test")
+                .arg("--answers")
+                .arg("answers.yml");
+        });
+
+        assert.success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "custom red,blue"
+        );
+    }
+
+    #[test]
+    fn args_override_answers() {
+        let sandbox = answers_sandbox();
+        sandbox.create_file("answers.json", r#"{ "name": "custom", "color": "blue" }"#);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("answers")
+                .arg("./test")
+                .arg("--answers")
+                .arg("answers.json")
+                .arg("--")
+                .arg("--name=override");
+        });
+
+        assert.success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "override blue"
+        );
+    }
+
+    #[test]
+    fn errors_when_missing_variables() {
+        let sandbox = answers_sandbox();
+        sandbox.create_file("answers.yml", "name: custom\n");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("answers")
+                .arg("./test")
+                .arg("--answers")
+                .arg("answers.yml");
+        });
+
+        assert
+            .failure()
+            .stderr(predicate::str::contains("is missing values for variables"));
+    }
+
+    #[test]
+    fn fills_missing_with_defaults() {
+        let sandbox = answers_sandbox();
+        sandbox.create_file("answers.yml", "name: custom\n");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("answers")
+                .arg("./test")
+                .arg("--answers")
+                .arg("answers.yml")
+                .arg("--defaults");
+        });
+
+        assert.success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "custom red"
+        );
+    }
+}
+
+mod functions {
+    use super::*;
+
+    #[test]
+    fn can_reference_projects_and_files() {
+        let sandbox = generate_sandbox();
+
+        sandbox.create_file(".moon/workspace.yml", "projects:\n  foo: 'packages/foo'\n");
+        sandbox.create_file("packages/foo/moon.yml", "type: 'library'\n");
+        sandbox.create_file("packages/foo/version.txt", "1.2.3");
+        sandbox.create_file(
+            "templates/functions/template.yml",
+            r#"
+title: 'Functions'
+description: 'A template for testing functions.'
+"#,
+        );
+        sandbox.create_file(
+            "templates/functions/file.txt",
+            "{% set foo = project(id='foo') %}{{ foo.source }} {{ foo.type }} {{ read_file(path=foo.source ~ '/version.txt') }} {{ 'package' | plural }}",
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("functions")
+                .arg("./test")
+                .arg("--defaults");
+        });
+
+        assert.success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "packages/foo library 1.2.3 packages"
+        );
+    }
+}
+
+mod validation {
+    use super::*;
+
+    fn validation_sandbox(prompt: bool) -> Sandbox {
+        let sandbox = generate_sandbox();
+
+        sandbox.create_file(
+            "templates/validation/template.yml",
+            format!(
+                r#"
+title: 'Validation'
+description: 'A template for testing variable validation.'
+variables:
+  name:
+    type: 'string'
+    default: 'Invalid Name'
+    pattern: '^[a-z-]+$'
+    {}
+"#,
+                if prompt { "prompt: 'Name?'" } else { "" }
+            ),
+        );
+        sandbox.create_file("templates/validation/file.txt", "name: {{ name }}");
+
+        sandbox
+    }
+
+    #[test]
+    fn errors_for_invalid_default_with_defaults() {
+        let sandbox = validation_sandbox(true);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("validation")
+                .arg("./test")
+                .arg("--defaults");
+        });
+
+        assert
+            .failure()
+            .stderr(predicate::str::contains("Invalid value for variable"));
+    }
+
+    #[test]
+    fn errors_for_invalid_default_without_prompt() {
+        let sandbox = validation_sandbox(false);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate").arg("validation").arg("./test");
+        });
+
+        assert
+            .failure()
+            .stderr(predicate::str::contains("Invalid value for variable"));
+    }
+
+    #[test]
+    fn doesnt_validate_default_when_value_provided() {
+        let sandbox = validation_sandbox(true);
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("validation")
+                    .arg("./test")
+                    .arg("--defaults")
+                    .arg("--")
+                    .arg("--name=valid-name");
+            })
+            .success();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "name: valid-name"
+        );
+    }
+}
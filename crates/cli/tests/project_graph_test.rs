@@ -71,6 +71,48 @@ fn single_project_with_dependents() {
     assert_snapshot!(assert.output());
 }
 
+#[test]
+fn outputs_mermaid() {
+    let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+    let sandbox = create_sandbox_with_config(
+        "projects",
+        Some(workspace_config),
+        Some(toolchain_config),
+        Some(tasks_config),
+    );
+
+    let assert = sandbox.run_moon(|cmd| {
+        cmd.arg("project-graph")
+            .arg("foo")
+            .arg("--format")
+            .arg("mermaid");
+    });
+
+    assert_snapshot!(assert.output());
+}
+
+#[test]
+fn outputs_graphml() {
+    let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+    let sandbox = create_sandbox_with_config(
+        "projects",
+        Some(workspace_config),
+        Some(toolchain_config),
+        Some(tasks_config),
+    );
+
+    let assert = sandbox.run_moon(|cmd| {
+        cmd.arg("project-graph")
+            .arg("foo")
+            .arg("--format")
+            .arg("graphml");
+    });
+
+    assert_snapshot!(assert.output());
+}
+
 #[test]
 fn single_project_no_dependencies() {
     let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
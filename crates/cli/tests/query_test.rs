@@ -1,3 +1,6 @@
+use moon_app::queries::graph_diff::*;
+use moon_app::queries::graph_stats::*;
+use moon_app::queries::owners::*;
 use moon_app::queries::projects::*;
 use moon_app::queries::tasks::*;
 use moon_app::queries::touched_files::*;
@@ -34,6 +37,194 @@ fn touch_file(sandbox: &Sandbox) {
     }
 }
 
+mod graph_diff {
+    use super::*;
+
+    fn commit_changes(sandbox: &Sandbox) {
+        sandbox.run_git(|cmd| {
+            cmd.args(["add", "--all", "."]);
+        });
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["commit", "-m", "Changes"])
+                .env("GIT_AUTHOR_NAME", "Sandbox")
+                .env("GIT_AUTHOR_EMAIL", "fakeemail@somedomain.dev")
+                .env("GIT_COMMITTER_NAME", "Sandbox")
+                .env("GIT_COMMITTER_EMAIL", "fakeemail@somedomain.dev");
+        });
+    }
+
+    fn create_diff_sandbox() -> Sandbox {
+        let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "projects",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+        sandbox.enable_git();
+        sandbox
+    }
+
+    #[test]
+    fn reports_no_changes_for_same_revision() {
+        let sandbox = create_diff_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("query")
+                .arg("graph-diff")
+                .arg("--base")
+                .arg("HEAD")
+                .arg("--json");
+        });
+
+        let json: QueryGraphDiffResult = json::parse(assert.output()).unwrap();
+
+        assert!(json.added_projects.is_empty());
+        assert!(json.removed_projects.is_empty());
+        assert!(json.changed_projects.is_empty());
+        assert!(json.added_tasks.is_empty());
+        assert!(json.removed_tasks.is_empty());
+        assert!(json.changed_tasks.is_empty());
+        assert!(json.new_violations.is_empty());
+    }
+
+    #[test]
+    fn reports_changed_dependencies_and_tasks() {
+        let sandbox = create_diff_sandbox();
+
+        sandbox.create_file("deps/foo/moon.yml", "dependsOn: ['bar']\ntype: application");
+        sandbox.create_file(
+            "tasks/moon.yml",
+            r#"
+language: typescript
+tasks:
+  test:
+    command: vitest
+    inputs:
+      - 'tests/**/*'
+      - 'vitest.config.ts'
+  lint:
+    command: eslint
+    outputs:
+      - 'cache'
+  internal:
+    command: build
+    options:
+      internal: true
+  format:
+    command: prettier
+"#,
+        );
+
+        commit_changes(&sandbox);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("query")
+                .arg("graph-diff")
+                .arg("--base")
+                .arg("HEAD~1")
+                .arg("--head")
+                .arg("HEAD")
+                .arg("--json");
+        });
+
+        let json: QueryGraphDiffResult = json::parse(assert.output()).unwrap();
+
+        assert_eq!(json.changed_projects["foo"].removed_dependencies, ["baz"]);
+        assert!(json.changed_projects["foo"].added_dependencies.is_empty());
+        assert_eq!(json.added_tasks, ["tasks:format"]);
+
+        let test = &json.changed_tasks["tasks:test"];
+
+        assert_eq!(test.head_command.as_deref(), Some("vitest"));
+        assert_eq!(test.added_inputs, ["vitest.config.ts"]);
+        assert!(json.new_violations.is_empty());
+    }
+
+    #[test]
+    fn reports_new_constraint_violations() {
+        let sandbox = create_diff_sandbox();
+
+        sandbox.create_file("deps/bar/moon.yml", "type: application");
+
+        commit_changes(&sandbox);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("query")
+                .arg("graph-diff")
+                .arg("--base")
+                .arg("HEAD~1")
+                .arg("--json");
+        });
+
+        let json: QueryGraphDiffResult = json::parse(assert.output()).unwrap();
+
+        assert_eq!(json.new_violations.len(), 1);
+    }
+
+    #[test]
+    fn removes_temp_dir_when_graph_fails_to_build() {
+        let sandbox = create_diff_sandbox();
+
+        sandbox.create_file("deps/bar/moon.yml", "type: invalid");
+
+        commit_changes(&sandbox);
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("query")
+                    .arg("graph-diff")
+                    .arg("--base")
+                    .arg("HEAD~1");
+            })
+            .failure();
+
+        assert!(!sandbox.path().join(".moon/cache/temp/graph-diff").exists());
+    }
+}
+
+mod graph_stats {
+    use super::*;
+
+    #[test]
+    fn reports_project_and_task_stats() {
+        let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "projects",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("query").arg("graph-stats").arg("--json");
+        });
+
+        let json: QueryGraphStatsResult = json::parse(assert.output()).unwrap();
+
+        let bar = json
+            .projects
+            .nodes
+            .iter()
+            .find(|node| node.id == "bar")
+            .unwrap();
+
+        assert_eq!(bar.fan_in, 1);
+        assert_eq!(bar.blast_radius, 1);
+        assert_eq!(bar.fan_out, 0);
+        assert_eq!(json.projects.longest_chains[0].len(), 2);
+        assert!(json.projects.cycles.is_empty());
+        assert!(json.tasks.cycles.is_empty());
+        assert_eq!(
+            json.orphaned_projects,
+            ["advanced", "basic", "emptyConfig", "foo"]
+        );
+    }
+}
+
 mod hash {
     use super::*;
     use std::fs;
@@ -232,6 +423,123 @@ mod hash_diff {
     }
 }
 
+mod owners {
+    use super::*;
+    use moon_common::path::WorkspaceRelativePathBuf;
+    use std::fs;
+
+    fn create_owners_sandbox() -> Sandbox {
+        let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "projects",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        let config_path = sandbox.path().join(".moon/workspace.yml");
+        let mut config = fs::read_to_string(&config_path).unwrap();
+        config.push_str("\ncodeowners:\n  globalPaths:\n    '/advanced/': ['@advanced']\n    '/advanced/*.md': ['@docs']\n");
+
+        fs::write(config_path, config).unwrap();
+
+        sandbox.enable_git();
+        sandbox
+    }
+
+    #[test]
+    fn resolves_owners_of_paths() {
+        let sandbox = create_owners_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("query").arg("owners").args([
+                "advanced/file",
+                "advanced/README.md",
+                "metadata/file",
+                "--json",
+            ]);
+        });
+
+        let json: QueryOwnersResult = json::parse(assert.output()).unwrap();
+
+        assert_eq!(json.files.len(), 3);
+        assert_eq!(
+            json.files[&WorkspaceRelativePathBuf::from("advanced/file")].owners,
+            ["@advanced"]
+        );
+        assert_eq!(
+            json.files[&WorkspaceRelativePathBuf::from("advanced/README.md")].owners,
+            ["@docs"]
+        );
+        assert_eq!(
+            json.files[&WorkspaceRelativePathBuf::from("advanced/README.md")]
+                .rules
+                .len(),
+            2
+        );
+        assert!(!json.files[&WorkspaceRelativePathBuf::from("metadata/file")].is_owned());
+    }
+
+    #[test]
+    fn resolves_paths_relative_to_working_dir() {
+        let sandbox = create_owners_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.current_dir(sandbox.path().join("advanced"));
+            cmd.arg("query").arg("owners").args(["file", "--json"]);
+        });
+
+        let json: QueryOwnersResult = json::parse(assert.output()).unwrap();
+
+        assert_eq!(
+            json.files[&WorkspaceRelativePathBuf::from("advanced/file")].owners,
+            ["@advanced"]
+        );
+    }
+
+    #[test]
+    fn can_use_touched_files() {
+        let sandbox = create_owners_sandbox();
+
+        touch_file(&sandbox);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("query").arg("owners").arg("--json");
+        });
+
+        let json: QueryOwnersResult = json::parse(assert.output()).unwrap();
+
+        assert_eq!(
+            json.files[&WorkspaceRelativePathBuf::from("advanced/file")].owners,
+            ["@advanced"]
+        );
+        assert!(!json.files[&WorkspaceRelativePathBuf::from("metadata/file")].is_owned());
+    }
+
+    #[test]
+    fn reports_coverage() {
+        let sandbox = create_owners_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("query")
+                .arg("owners")
+                .args(["--coverage", "--json"]);
+        });
+
+        let json: QueryOwnersCoverageResult = json::parse(assert.output()).unwrap();
+
+        assert!(json.total_files > json.owned_files);
+        assert!(json.owned_files > 0);
+        assert!(json
+            .unowned_files
+            .iter()
+            .all(|file| !file.starts_with("advanced")));
+        assert!(json.unowned_projects.iter().any(|id| id == "metadata"));
+        assert!(!json.unowned_projects.iter().any(|id| id == "advanced"));
+    }
+}
+
 mod projects {
     use super::*;
 
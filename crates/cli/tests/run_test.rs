@@ -1796,6 +1796,118 @@ mod affected {
             .eval(&output));
         }
     }
+
+    mod staged {
+        use super::*;
+        use std::process::Command;
+
+        fn create_staged_sandbox() -> Sandbox {
+            let sandbox = cases_sandbox();
+            sandbox.create_file(
+                "files/moon.yml",
+                r#"
+tasks:
+  noop:
+    command: noop
+  cat:
+    command: cat file.txt
+    platform: system
+  format:
+    script: echo formatted > file.txt
+    platform: system
+"#,
+            );
+            sandbox.enable_git();
+            sandbox
+        }
+
+        fn read_staged_file(sandbox: &Sandbox, file: &str) -> String {
+            let output = Command::new("git")
+                .args(["show", &format!(":{file}")])
+                .current_dir(sandbox.path())
+                .output()
+                .unwrap();
+
+            String::from_utf8(output.stdout).unwrap()
+        }
+
+        #[test]
+        fn doesnt_run_if_not_staged() {
+            let sandbox = create_staged_sandbox();
+
+            sandbox.create_file("files/other.txt", "");
+
+            let assert = sandbox.run_moon(|cmd| {
+                cmd.arg("run").arg("files:noop").arg("--staged");
+            });
+
+            let output = assert.output();
+
+            assert!(predicate::str::contains(
+                "Target(s) files:noop not affected by touched files (using status staged)"
+            )
+            .eval(&output));
+        }
+
+        #[test]
+        fn runs_if_staged() {
+            let sandbox = create_staged_sandbox();
+
+            sandbox.create_file("files/other.txt", "");
+            sandbox.run_git(|cmd| {
+                cmd.args(["add", "files/other.txt"]);
+            });
+
+            let assert = sandbox.run_moon(|cmd| {
+                cmd.arg("run").arg("files:noop").arg("--staged");
+            });
+
+            let output = assert.output();
+
+            assert!(predicate::str::contains("Tasks: 1 completed").eval(&output));
+        }
+
+        #[test]
+        fn hides_unstaged_changes_while_running() {
+            let sandbox = create_staged_sandbox();
+
+            sandbox.create_file("files/file.txt", "first");
+            sandbox.run_git(|cmd| {
+                cmd.args(["add", "files/file.txt"]);
+            });
+            sandbox.create_file("files/file.txt", "second");
+
+            let assert = sandbox.run_moon(|cmd| {
+                cmd.arg("run").arg("files:cat").arg("--staged");
+            });
+
+            let output = assert.output();
+
+            assert!(predicate::str::contains("first").eval(&output));
+            assert!(!predicate::str::contains("second").eval(&output));
+            assert_eq!(
+                fs::read_to_string(sandbox.path().join("files/file.txt")).unwrap(),
+                "second"
+            );
+            assert_eq!(read_staged_file(&sandbox, "files/file.txt"), "first");
+        }
+
+        #[test]
+        fn restages_files_modified_by_tasks() {
+            let sandbox = create_staged_sandbox();
+
+            sandbox.create_file("files/file.txt", "first");
+            sandbox.run_git(|cmd| {
+                cmd.args(["add", "files/file.txt"]);
+            });
+
+            sandbox.run_moon(|cmd| {
+                cmd.arg("run").arg("files:format").arg("--staged");
+            });
+
+            assert_eq!(read_staged_file(&sandbox, "files/file.txt"), "formatted\n");
+        }
+    }
 }
 
 mod interactive {
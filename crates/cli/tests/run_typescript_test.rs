@@ -337,3 +337,28 @@ mod paths {
         .unwrap());
     }
 }
+
+mod sync_check {
+    use super::*;
+    use moon_test_utils::predicates::prelude::*;
+
+    #[test]
+    fn reports_pending_changes_without_writing() {
+        let sandbox = typescript_sandbox(|_| {});
+
+        let initial_root = read_to_string(sandbox.path().join("tsconfig.json")).unwrap();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("projects").arg("--check");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("+++ b/tsconfig.json"));
+
+        assert_eq!(
+            initial_root,
+            read_to_string(sandbox.path().join("tsconfig.json")).unwrap()
+        );
+    }
+}
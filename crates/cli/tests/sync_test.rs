@@ -1,6 +1,11 @@
 use moon_common::Id;
-use moon_config::{PartialVcsConfig, PartialWorkspaceConfig, PartialWorkspaceProjects};
-use moon_test_utils::{create_sandbox_with_config, get_cases_fixture_configs};
+use moon_config::{
+    PartialCodeownersConfig, PartialVcsConfig, PartialWorkspaceConfig, PartialWorkspaceProjects,
+    VcsProvider,
+};
+use moon_test_utils::{
+    create_sandbox_with_config, get_cases_fixture_configs, predicates::prelude::*,
+};
 use rustc_hash::FxHashMap;
 
 mod sync_codeowners {
@@ -45,6 +50,108 @@ mod sync_codeowners {
 
         assert!(!sandbox.path().join(".github/CODEOWNERS").exists());
     }
+
+    #[test]
+    fn checks_codeowners_file() {
+        let (workspace_config, _, _) = get_cases_fixture_configs();
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("codeowners").arg("--check");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("+++ b/.github/CODEOWNERS"));
+
+        assert!(!sandbox.path().join(".github/CODEOWNERS").exists());
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("codeowners");
+            })
+            .success();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("codeowners").arg("--check");
+            })
+            .success();
+    }
+
+    #[test]
+    fn checks_codeowners_file_when_check_is_before_subcommand() {
+        let (workspace_config, _, _) = get_cases_fixture_configs();
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("--check").arg("codeowners");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("+++ b/.github/CODEOWNERS"));
+
+        assert!(!sandbox.path().join(".github/CODEOWNERS").exists());
+    }
+
+    #[test]
+    fn creates_file_for_provider() {
+        let (mut workspace_config, _, _) = get_cases_fixture_configs();
+
+        workspace_config.vcs = Some(PartialVcsConfig {
+            provider: Some(VcsProvider::Gitea),
+            ..Default::default()
+        });
+
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("codeowners");
+            })
+            .success();
+
+        assert!(sandbox.path().join(".gitea/CODEOWNERS").exists());
+        assert!(!sandbox.path().join(".github/CODEOWNERS").exists());
+    }
+
+    #[test]
+    fn creates_and_removes_ownership_map() {
+        let (mut workspace_config, _, _) = get_cases_fixture_configs();
+
+        workspace_config.codeowners = Some(PartialCodeownersConfig {
+            ownership_map: Some(true),
+            ..Default::default()
+        });
+
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("codeowners").arg("--check");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("+++ b/.moon/codeowners.json"));
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("codeowners");
+            })
+            .success();
+
+        assert!(sandbox.path().join(".github/CODEOWNERS").exists());
+        assert!(sandbox.path().join(".moon/codeowners.json").exists());
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("codeowners").arg("--clean");
+            })
+            .success();
+
+        assert!(!sandbox.path().join(".moon/codeowners.json").exists());
+    }
 }
 
 mod sync_config_schemas {
@@ -65,6 +172,32 @@ mod sync_config_schemas {
 
         assert!(sandbox.path().join(".moon/cache/schemas").exists());
     }
+
+    #[test]
+    fn checks_schemas() {
+        let (workspace_config, _, _) = get_cases_fixture_configs();
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("config-schemas").arg("--check");
+            })
+            .failure();
+
+        assert!(!sandbox.path().join(".moon/cache/schemas").exists());
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("config-schemas");
+            })
+            .success();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("config-schemas").arg("--check");
+            })
+            .success();
+    }
 }
 
 mod sync_hooks {
@@ -147,6 +280,46 @@ mod sync_hooks {
 
         assert!(!hooks_dir.exists());
     }
+
+    #[test]
+    fn checks_hook_files() {
+        let (mut workspace_config, _, _) = get_cases_fixture_configs();
+
+        workspace_config.vcs = Some(PartialVcsConfig {
+            hooks: Some(FxHashMap::from_iter([(
+                "pre-commit".into(),
+                vec!["moon run :lint".into()],
+            )])),
+            ..Default::default()
+        });
+
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+        sandbox.enable_git();
+
+        let hooks_dir = sandbox.path().join(".moon/hooks");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("hooks").arg("--check");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("+moon run :lint"));
+
+        assert!(!hooks_dir.exists());
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("hooks");
+            })
+            .success();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("hooks").arg("--check");
+            })
+            .success();
+    }
 }
 
 mod sync_projects {
@@ -203,4 +376,56 @@ mod sync_projects {
 
         assert.success();
     }
+
+    #[test]
+    fn checks_all_projects_with_legacy_sync_command() {
+        let workspace_config = PartialWorkspaceConfig {
+            projects: Some(PartialWorkspaceProjects::Sources(FxHashMap::from_iter([
+                (Id::raw("a"), "a".to_owned()),
+                (Id::raw("b"), "b".to_owned()),
+                (Id::raw("c"), "c".to_owned()),
+                (Id::raw("d"), "d".to_owned()),
+            ]))),
+            ..PartialWorkspaceConfig::default()
+        };
+
+        let sandbox = create_sandbox_with_config(
+            "project-graph/dependencies",
+            Some(workspace_config),
+            None,
+            None,
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("--check");
+        });
+
+        assert.success();
+    }
+
+    #[test]
+    fn checks_all_projects() {
+        let workspace_config = PartialWorkspaceConfig {
+            projects: Some(PartialWorkspaceProjects::Sources(FxHashMap::from_iter([
+                (Id::raw("a"), "a".to_owned()),
+                (Id::raw("b"), "b".to_owned()),
+                (Id::raw("c"), "c".to_owned()),
+                (Id::raw("d"), "d".to_owned()),
+            ]))),
+            ..PartialWorkspaceConfig::default()
+        };
+
+        let sandbox = create_sandbox_with_config(
+            "project-graph/dependencies",
+            Some(workspace_config),
+            None,
+            None,
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("projects").arg("--check");
+        });
+
+        assert.success();
+    }
 }
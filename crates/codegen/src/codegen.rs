@@ -1,17 +1,25 @@
 use crate::codegen_error::CodegenError;
-use crate::template::Template;
+use crate::template::{Template, TemplateHook};
+use crate::template_lock::TemplateLock;
+use convert_case::{Case, Casing};
 use miette::IntoDiagnostic;
 use moon_common::path::RelativePathBuf;
 use moon_common::Id;
 use moon_config::{load_template_config_template, ConfigFinder, GeneratorConfig, TemplateLocator};
+use moon_console::Console;
 use moon_env::MoonEnvironment;
 use moon_process::Command;
+use moon_target::TARGET_PATTERN;
 use moon_time::now_millis;
 use rustc_hash::FxHashMap;
+use sha2::{Digest, Sha256};
 use starbase_archive::Archiver;
+use starbase_utils::json::JsonValue;
 use starbase_utils::{fs, net};
+use std::env;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use tera::Context as TemplateContext;
 use tokio::task::spawn;
 use tracing::{debug, instrument};
 
@@ -156,7 +164,7 @@ impl<'app> CodeGenerator<'app> {
     }
 
     #[instrument(skip_all)]
-    pub fn generate(&self, template: &Template) -> miette::Result<()> {
+    pub fn generate(&self, template: &Template, lock: Option<&TemplateLock>) -> miette::Result<()> {
         debug!(template = template.id.as_str(), "Generating template files");
 
         for file in template.files.values() {
@@ -169,11 +177,84 @@ impl<'app> CodeGenerator<'app> {
             template.copy_asset(asset)?;
         }
 
+        // Record what was generated, so that it can be updated later
+        if let Some(lock) = lock {
+            lock.save()?;
+        }
+
         debug!(template = template.id.as_str(), "Code generation complete!");
 
         Ok(())
     }
 
+    /// Run the commands of the provided hook from within the destination,
+    /// with template variables exposed as environment variables.
+    /// Commands that are valid targets will be ran through `moon run`.
+    #[instrument(skip(self, template, context, console))]
+    pub async fn run_hooks(
+        &self,
+        template: &Template,
+        hook: TemplateHook,
+        dest: &Path,
+        context: &TemplateContext,
+        console: Arc<Console>,
+    ) -> miette::Result<()> {
+        let commands = template.get_hook_commands(hook);
+
+        if commands.is_empty() {
+            return Ok(());
+        }
+
+        debug!(
+            template = template.id.as_str(),
+            hook = ?hook,
+            commands = ?commands,
+            "Running template hooks",
+        );
+
+        // Hooks run before generating, so the destination may not exist yet
+        fs::create_dir_all(dest)?;
+
+        let mut env = FxHashMap::default();
+
+        for name in template.config.variables.keys() {
+            if let Some(value) = context.get(name) {
+                env.insert(
+                    format!("MOON_VAR_{}", name.to_case(Case::UpperSnake)),
+                    match value {
+                        JsonValue::String(inner) => inner.to_owned(),
+                        other => other.to_string(),
+                    },
+                );
+            }
+        }
+
+        for command in commands {
+            let mut cmd = if is_target_command(&command) {
+                let mut cmd = Command::new(env::current_exe().into_diagnostic()?);
+                cmd.args(["run", &command]).without_shell();
+                cmd
+            } else {
+                let mut cmd = Command::new(&command);
+
+                // Commands should be used as-is
+                cmd.escape_args = false;
+                cmd
+            };
+
+            cmd.cwd(dest)
+                .envs(&env)
+                .env("MOON_TEMPLATE_ID", template.id.as_str())
+                .set_print_command(true)
+                .with_console(Arc::clone(&console))
+                .create_async()
+                .exec_stream_output()
+                .await?;
+        }
+
+        Ok(())
+    }
+
     #[instrument(skip_all)]
     async fn resolve_template_locations(&mut self) -> miette::Result<()> {
         let mut locations = vec![];
@@ -183,6 +264,51 @@ impl<'app> CodeGenerator<'app> {
 
         for locator in &self.config.templates {
             match locator {
+                TemplateLocator::Archive { url, checksum } => {
+                    let file_name = url.rsplit('/').next().unwrap_or_default();
+
+                    // Local archives are referenced directly, while remote
+                    // archives are downloaded to a temporary file
+                    let (archive_file, slug) = if let Some(path) = url.strip_prefix("file://") {
+                        let archive_file = RelativePathBuf::from(path)
+                            .normalize()
+                            .to_logical_path(self.workspace_root);
+
+                        if !archive_file.exists() {
+                            return Err(CodegenError::MissingArchive(archive_file).into());
+                        }
+
+                        // Without a checksum, key the cache by content,
+                        // so that changes to the archive are picked up
+                        let slug = match checksum {
+                            Some(checksum) => checksum.to_owned(),
+                            None => hash_bytes(fs::read_file_bytes(&archive_file)?),
+                        };
+
+                        (archive_file, slug)
+                    } else {
+                        let slug = match checksum {
+                            Some(checksum) => checksum.to_owned(),
+                            None => hash_bytes(url),
+                        };
+
+                        (
+                            self.moon_env.temp_dir.join(format!("{slug}-{file_name}")),
+                            slug,
+                        )
+                    };
+
+                    let template_location = self.moon_env.templates_dir.join("archive").join(&slug);
+
+                    futures.push(spawn(download_and_unpack_archive(
+                        url.to_owned(),
+                        checksum.to_owned(),
+                        template_location.clone(),
+                        archive_file,
+                    )));
+
+                    locations.push(template_location);
+                }
                 TemplateLocator::File { path } => {
                     locations.push(
                         RelativePathBuf::from(path)
@@ -363,3 +489,116 @@ async fn download_and_unpack_npm_archive(
 
     Ok(())
 }
+
+/// Only commands that are explicitly a target (`project:task`) are ran through
+/// `moon run`, as single words like `yarn` or `make` are valid IDs as well.
+fn is_target_command(command: &str) -> bool {
+    command.contains(':')
+        && !command.contains(char::is_whitespace)
+        && TARGET_PATTERN.is_match(command)
+}
+
+fn hash_bytes(data: impl AsRef<[u8]>) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+#[instrument]
+async fn download_and_unpack_archive(
+    url: String,
+    checksum: Option<String>,
+    template_location: PathBuf,
+    archive_file: PathBuf,
+) -> miette::Result<()> {
+    debug!(url, "Resolving template location for archive");
+
+    let is_remote = !url.starts_with("file://");
+
+    // Remote archives without a checksum may change between requests
+    // (`latest.tar.gz`), so they are always downloaded again
+    if template_location.exists() {
+        if is_remote && checksum.is_none() {
+            debug!(
+                location = ?template_location,
+                "Template location already exists locally, but archive is not pinned with a checksum, downloading again",
+            );
+        } else {
+            debug!(location = ?template_location, "Template location already exists locally");
+
+            return Ok(());
+        }
+    }
+
+    // Download archive
+    if is_remote {
+        debug!(url, archive_file = ?archive_file, "Downloading archive");
+
+        net::download_from_url(&url, &archive_file).await?;
+    }
+
+    // Verify checksum
+    if let Some(expected) = checksum {
+        let actual = hash_bytes(fs::read_file_bytes(&archive_file)?);
+
+        if actual != expected {
+            if is_remote {
+                fs::remove_file(&archive_file)?;
+            }
+
+            return Err(CodegenError::ArchiveChecksumMismatch {
+                url,
+                expected,
+                actual,
+            }
+            .into());
+        }
+
+        debug!(checksum = expected, "Archive checksum verified");
+    }
+
+    // Unpack archive into a staging directory
+    let staging_location = template_location.with_extension("unpacking");
+
+    debug!(
+        archive_file = ?archive_file,
+        location = ?template_location,
+        "Unpacking archive into template location",
+    );
+
+    if staging_location.exists() {
+        fs::remove_dir_all(&staging_location)?;
+    }
+
+    Archiver::new(&staging_location, &archive_file).unpack_from_ext()?;
+
+    if is_remote {
+        fs::remove_file(&archive_file)?;
+    }
+
+    if template_location.exists() {
+        fs::remove_dir_all(&template_location)?;
+    }
+
+    // Archives commonly wrap their contents in a single top-level directory,
+    // so use that directory as the template location instead, unless it's a template
+    let entries = fs::read_dir(&staging_location)?;
+
+    if entries.len() == 1
+        && entries[0].path().is_dir()
+        && ConfigFinder::default()
+            .get_template_files(&entries[0].path())
+            .iter()
+            .all(|file| !file.exists())
+    {
+        fs::rename(entries[0].path(), &template_location)?;
+        fs::remove_dir_all(&staging_location)?;
+    } else {
+        fs::rename(&staging_location, &template_location)?;
+    }
+
+    fs::write_file(
+        template_location.join(".installed-at"),
+        now_millis().to_string(),
+    )?;
+
+    Ok(())
+}
@@ -5,6 +5,22 @@ use thiserror::Error;
 
 #[derive(Error, Debug, Diagnostic)]
 pub enum CodegenError {
+    #[diagnostic(
+        code(codegen::template::archive_checksum),
+        help = "The archive may have been modified or corrupted, or the configured checksum is outdated."
+    )]
+    #[error(
+        "Checksum mismatch for template archive {}.\nExpected {} but received {}.",
+        .url.style(Style::Url),
+        .expected.style(Style::Hash),
+        .actual.style(Style::Hash),
+    )]
+    ArchiveChecksumMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+
     #[diagnostic(code(codegen::template::fs_only))]
     #[error(
         "Unable to create a new template, as the destination must be a local file system path.\nPlease add a file path to the {} setting.",
@@ -27,6 +43,47 @@ pub enum CodegenError {
         error: miette::Report,
     },
 
+    #[diagnostic(code(codegen::args::invalid_value))]
+    #[error(
+        "Invalid value for variable {}: {message}",
+        .name.style(Style::Property),
+    )]
+    InvalidVariableValue { name: String, message: String },
+
+    #[diagnostic(
+        code(codegen::answers::internal_variable),
+        help = "Internal variables cannot be provided externally."
+    )]
+    #[error(
+        "Variable {} in answers file {} is internal.",
+        .name.style(Style::Property),
+        .path.style(Style::Path),
+    )]
+    InternalAnswersVariable { name: String, path: PathBuf },
+
+    #[diagnostic(
+        code(codegen::answers::missing_variables),
+        help = "Provide a value for each variable, or pass --defaults to use their default values."
+    )]
+    #[error(
+        "Answers file {} is missing values for variables: {}",
+        .path.style(Style::Path),
+        .names.style(Style::Property),
+    )]
+    MissingAnswersVariables { names: String, path: PathBuf },
+
+    #[diagnostic(code(codegen::answers::unknown_variable))]
+    #[error(
+        "Unknown variable {} in answers file {}.",
+        .name.style(Style::Property),
+        .path.style(Style::Path),
+    )]
+    UnknownAnswersVariable { name: String, path: PathBuf },
+
+    #[diagnostic(code(codegen::template::missing_archive))]
+    #[error("Template archive {} does not exist.", .0.style(Style::Path))]
+    MissingArchive(PathBuf),
+
     #[diagnostic(code(codegen::template::missing))]
     #[error(
         "No template with the name {} could be found at any of the configured template locations.",
@@ -34,6 +91,24 @@ pub enum CodegenError {
     )]
     MissingTemplate(Id),
 
+    #[diagnostic(
+        code(codegen::template::missing_lock),
+        help = "Only destinations that were generated with a template can be updated."
+    )]
+    #[error(
+        "Unable to update, as no template lockfile could be found in {}.",
+        .0.style(Style::Path),
+    )]
+    MissingTemplateLock(PathBuf),
+
+    #[diagnostic(code(codegen::template::lock_mismatch))]
+    #[error(
+        "Unable to update, as the destination was generated with template {}, not {}.",
+        .locked.style(Style::Id),
+        .id.style(Style::Id),
+    )]
+    TemplateLockMismatch { id: Id, locked: Id },
+
     #[diagnostic(code(codegen::template::duplicate))]
     #[error(
         "Found multiple templates with the same name {}.\nOriginal template at {}.\nCurrent template at {}.",
@@ -69,6 +144,17 @@ pub enum CodegenError {
         error: Box<tera::Error>,
     },
 
+    #[diagnostic(code(codegen::template::file_condition_failed))]
+    #[error(
+        "Failed to render the condition for files matching {}.",
+        .glob.style(Style::File),
+    )]
+    RenderFileConditionFailed {
+        glob: String,
+        #[source]
+        error: Box<tera::Error>,
+    },
+
     #[diagnostic(code(codegen::template_file::interpolate_path))]
     #[error(
         "Failed to interpolate variables into template file path {}.",
@@ -79,4 +165,15 @@ pub enum CodegenError {
         #[source]
         error: Box<tera::Error>,
     },
+
+    #[diagnostic(code(codegen::template_file::merge_toml_failed))]
+    #[error(
+        "Failed to merge TOML template file into {}.",
+        .path.style(Style::Path),
+    )]
+    MergeTomlFailed {
+        path: PathBuf,
+        #[source]
+        error: Box<toml_edit::TomlError>,
+    },
 }
@@ -52,6 +52,168 @@ pub fn upper_case(value: &Value, _: &HashMap<String, Value>) -> Result<Value> {
     Ok(to_value(s.to_uppercase()).unwrap())
 }
 
+pub fn plural(value: &Value, _: &HashMap<String, Value>) -> Result<Value> {
+    let s = try_get_value!("plural", "value", String, value);
+
+    Ok(to_value(pluralize(&s)).unwrap())
+}
+
+pub fn singular(value: &Value, _: &HashMap<String, Value>) -> Result<Value> {
+    let s = try_get_value!("singular", "value", String, value);
+
+    Ok(to_value(singularize(&s)).unwrap())
+}
+
+// INFLECTION
+
+const IRREGULAR_WORDS: [(&str, &str); 19] = [
+    ("cache", "caches"),
+    ("child", "children"),
+    ("criterion", "criteria"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("knife", "knives"),
+    ("life", "lives"),
+    ("man", "men"),
+    ("matrix", "matrices"),
+    ("mouse", "mice"),
+    ("movie", "movies"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("quiz", "quizzes"),
+    ("tooth", "teeth"),
+    ("vertex", "vertices"),
+    ("wife", "wives"),
+    ("woman", "women"),
+    ("zombie", "zombies"),
+];
+
+const UNCOUNTABLE_WORDS: [&str; 10] = [
+    "data",
+    "equipment",
+    "fish",
+    "information",
+    "metadata",
+    "news",
+    "series",
+    "sheep",
+    "software",
+    "species",
+];
+
+// Suffix replacements, matched in order, so more specific suffixes must come first
+const PLURAL_RULES: [(&str, &str); 7] = [
+    ("sis", "ses"),
+    ("lf", "lves"),
+    ("ch", "ches"),
+    ("sh", "shes"),
+    ("s", "ses"),
+    ("x", "xes"),
+    ("z", "zes"),
+];
+
+const SINGULAR_RULES: [(&str, &str); 15] = [
+    ("aliases", "alias"),
+    ("buses", "bus"),
+    ("statuses", "status"),
+    ("viruses", "virus"),
+    ("gnoses", "gnosis"),
+    ("lyses", "lysis"),
+    ("theses", "thesis"),
+    ("sses", "ss"),
+    ("ches", "ch"),
+    ("shes", "sh"),
+    ("xes", "x"),
+    ("lves", "lf"),
+    // Already singular
+    ("is", "is"),
+    ("ss", "ss"),
+    ("us", "us"),
+];
+
+fn match_case(original: &str, replacement: &str) -> String {
+    if original.chars().next().is_some_and(|ch| ch.is_uppercase()) {
+        let mut chars = replacement.chars();
+
+        if let Some(first) = chars.next() {
+            return first.to_uppercase().chain(chars).collect();
+        }
+    }
+
+    replacement.to_owned()
+}
+
+fn is_vowel(ch: char) -> bool {
+    matches!(ch.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+fn replace_suffix(word: &str, lower: &str, rules: &[(&str, &str)]) -> Option<String> {
+    // Slicing the original word requires the lowercased word to have the same length
+    if word.len() != lower.len() {
+        return None;
+    }
+
+    rules.iter().find_map(|(suffix, replacement)| {
+        lower
+            .strip_suffix(suffix)
+            .map(|stem| format!("{}{replacement}", &word[0..stem.len()]))
+    })
+}
+
+fn pluralize(word: &str) -> String {
+    let lower = word.to_lowercase();
+
+    if word.is_empty() || UNCOUNTABLE_WORDS.contains(&lower.as_str()) {
+        return word.to_owned();
+    }
+
+    for (singular, plural) in IRREGULAR_WORDS {
+        if lower == singular {
+            return match_case(word, plural);
+        }
+    }
+
+    if let Some(plural) = replace_suffix(word, &lower, &PLURAL_RULES) {
+        return plural;
+    }
+
+    if let Some(stem) = lower.strip_suffix('y') {
+        if stem.chars().last().is_some_and(|ch| !is_vowel(ch)) {
+            return format!("{}ies", &word[0..word.len() - 1]);
+        }
+    }
+
+    format!("{word}s")
+}
+
+fn singularize(word: &str) -> String {
+    let lower = word.to_lowercase();
+
+    if word.is_empty() || UNCOUNTABLE_WORDS.contains(&lower.as_str()) {
+        return word.to_owned();
+    }
+
+    for (singular, plural) in IRREGULAR_WORDS {
+        if lower == plural {
+            return match_case(word, singular);
+        }
+    }
+
+    if let Some(singular) = replace_suffix(word, &lower, &SINGULAR_RULES) {
+        return singular;
+    }
+
+    if lower.len() > 3 && lower.ends_with("ies") {
+        return format!("{}y", &word[0..word.len() - 3]);
+    }
+
+    if lower.ends_with('s') {
+        return word[0..word.len() - 1].to_owned();
+    }
+
+    word.to_owned()
+}
+
 // PATHS
 
 pub fn path_join(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
@@ -1,9 +1,15 @@
 // HashMap is required for Tera
 #![allow(clippy::disallowed_types)]
 
-use starbase_utils::json::{JsonMap, JsonValue as Value};
+use moon_common::path::RelativePathBuf;
+use rustc_hash::FxHashMap;
+use starbase_utils::json::{serde_json::to_value, JsonMap, JsonValue as Value};
 use std::collections::HashMap;
-use tera::Result;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tera::{from_value, Error, Function, Result};
 
 pub fn variables(args: &HashMap<String, Value>) -> Result<Value> {
     let mut map = JsonMap::with_capacity(args.len());
@@ -11,3 +17,73 @@ pub fn variables(args: &HashMap<String, Value>) -> Result<Value> {
 
     Ok(Value::Object(map))
 }
+
+pub fn uuid(_: &HashMap<String, Value>) -> Result<Value> {
+    Ok(to_value(uuid::Uuid::new_v4().to_string()).unwrap())
+}
+
+pub fn env(args: &HashMap<String, Value>) -> Result<Value> {
+    let name = match args.get("name") {
+        Some(val) => from_value::<String>(val.clone())
+            .map_err(|_| Error::msg("Argument `name` for `env` must be a string."))?,
+        None => return Err(Error::msg("Expected a `name` for `env`.")),
+    };
+
+    match env::var(name) {
+        Ok(value) => Ok(to_value(value).unwrap()),
+        Err(_) => Ok(args.get("default").cloned().unwrap_or(Value::Null)),
+    }
+}
+
+// WORKSPACE
+
+pub fn read_file(workspace_root: PathBuf) -> impl Function {
+    move |args: &HashMap<String, Value>| -> Result<Value> {
+        let path = match args.get("path") {
+            Some(val) => from_value::<RelativePathBuf>(val.clone())
+                .map_err(|_| Error::msg("Argument `path` for `read_file` must be a string."))?,
+            None => return Err(Error::msg("Expected a `path` for `read_file`.")),
+        };
+
+        let file = path.normalize().to_logical_path(&workspace_root);
+
+        if !file.starts_with(&workspace_root) {
+            return Err(Error::msg(format!(
+                "File `{path}` for `read_file` must be within the workspace."
+            )));
+        }
+
+        match fs::read_to_string(&file) {
+            Ok(content) => Ok(to_value(content).unwrap()),
+            Err(error) => Err(Error::msg(format!(
+                "Failed to read file `{path}` for `read_file`: {error}"
+            ))),
+        }
+    }
+}
+
+/// Loads information about each project, keyed by ID and alias.
+pub type TemplateProjectsLoader =
+    Arc<dyn Fn() -> miette::Result<Arc<FxHashMap<String, Value>>> + Send + Sync>;
+
+pub fn project(load_projects: TemplateProjectsLoader) -> impl Function {
+    move |args: &HashMap<String, Value>| -> Result<Value> {
+        let id = match args.get("id") {
+            Some(val) => from_value::<String>(val.clone())
+                .map_err(|_| Error::msg("Argument `id` for `project` must be a string."))?,
+            None => return Err(Error::msg("Expected an `id` for `project`.")),
+        };
+
+        // Projects are only loaded when a template calls this function
+        let projects = load_projects().map_err(|error| {
+            Error::msg(format!("Failed to load projects for `project`: {error}"))
+        })?;
+
+        match projects.get(&id) {
+            Some(project) => Ok(project.clone()),
+            None => Err(Error::msg(format!(
+                "No project has been configured with the name or alias `{id}`."
+            ))),
+        }
+    }
+}
@@ -5,13 +5,17 @@ use clap::builder::{
 };
 use clap::parser::ValueSource;
 use clap::{Arg, ArgAction, Args, Command};
+use convert_case::{Case, Casing};
 use moon_config::{TemplateVariable, TemplateVariableEnumDefault};
 use moon_console::prompts::list_option::ListOption;
 use moon_console::prompts::validator::Validation;
 use moon_console::prompts::{Confirm, CustomType, MultiSelect, Select, Text};
 use moon_console::Console;
 use rustc_hash::FxHashMap;
+use starbase_utils::json::{JsonMap, JsonValue};
+use starbase_utils::{json, yaml};
 use std::io::{stdout, IsTerminal};
+use std::path::{Path, PathBuf};
 use tera::Context as TemplateContext;
 use tracing::{debug, instrument};
 
@@ -23,6 +27,12 @@ pub struct GenerateArgs {
     #[arg(help = "Destination path, relative from workspace root or working directory")]
     pub dest: Option<String>,
 
+    #[arg(
+        long,
+        help = "Path to a YAML or JSON file of variable values to use instead of prompting"
+    )]
+    pub answers: Option<PathBuf>,
+
     #[arg(
         long,
         help = "Use the default value of all variables instead of prompting"
@@ -41,6 +51,13 @@ pub struct GenerateArgs {
     #[arg(long, help = "Create a new template")]
     pub template: bool,
 
+    #[arg(
+        long,
+        conflicts_with_all = ["force", "template"],
+        help = "Re-apply the template to a previously generated destination, merging changes"
+    )]
+    pub update: bool,
+
     // Variable args (after --)
     #[arg(last = true, help = "Arguments to define as variable values")]
     pub vars: Vec<String>,
@@ -169,15 +186,31 @@ pub fn parse_args_into_variables(
                             vars.insert(name, value);
                         }
                     }
-                    TemplateVariable::Number(_) => {
+                    TemplateVariable::Number(inner) => {
                         if let Some(value) = matches.get_one::<isize>(arg_name) {
+                            if let Err(message) = inner.validate_value(*value) {
+                                return Err(CodegenError::InvalidVariableValue {
+                                    name: name.to_owned(),
+                                    message,
+                                }
+                                .into());
+                            }
+
                             debug!(name, value, "Setting number variable");
 
                             vars.insert(name, value);
                         }
                     }
-                    TemplateVariable::String(_) => {
+                    TemplateVariable::String(inner) => {
                         if let Some(value) = matches.get_one::<String>(arg_name) {
+                            if let Err(message) = inner.validate_value(value) {
+                                return Err(CodegenError::InvalidVariableValue {
+                                    name: name.to_owned(),
+                                    message,
+                                }
+                                .into());
+                            }
+
                             debug!(name, value, "Setting string variable");
 
                             vars.insert(name, value);
@@ -215,13 +248,221 @@ pub fn parse_args_into_variables(
     Ok(vars)
 }
 
+fn flatten_answers(
+    answers: JsonMap<String, JsonValue>,
+    parent: Option<&str>,
+    config: &FxHashMap<String, TemplateVariable>,
+    path: &Path,
+    flattened: &mut Vec<(String, JsonValue)>,
+) -> miette::Result<()> {
+    for (key, value) in answers {
+        let (name, dotted_name) = match parent {
+            Some(parent) => {
+                let camel_name = format!("{parent}{}", key.to_case(Case::Pascal));
+                let snake_name = format!("{parent}_{key}");
+
+                (
+                    if config.contains_key(&snake_name) {
+                        snake_name
+                    } else {
+                        camel_name
+                    },
+                    format!("{parent}.{key}"),
+                )
+            }
+            None => (key.clone(), key),
+        };
+
+        if config.contains_key(&name) {
+            flattened.push((name, value));
+            continue;
+        }
+
+        // Nested values are flattened into a single variable name
+        if let JsonValue::Object(nested) = value {
+            flatten_answers(nested, Some(&name), config, path, flattened)?;
+            continue;
+        }
+
+        return Err(CodegenError::UnknownAnswersVariable {
+            name: dotted_name,
+            path: path.to_path_buf(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+fn validate_answer(config: &TemplateVariable, value: &JsonValue) -> Result<(), String> {
+    match (config, value) {
+        (TemplateVariable::Boolean(_), JsonValue::Bool(_)) => Ok(()),
+        (TemplateVariable::Boolean(_), _) => Err("Expected a boolean".into()),
+        (TemplateVariable::Number(cfg), JsonValue::Number(number)) => match number.as_i64() {
+            Some(number) => cfg.validate_value(number as isize),
+            None => Err("Expected an integer".into()),
+        },
+        (TemplateVariable::Number(_), _) => Err("Expected a number".into()),
+        (TemplateVariable::String(cfg), JsonValue::String(string)) => cfg.validate_value(string),
+        (TemplateVariable::String(_), _) => Err("Expected a string".into()),
+        (TemplateVariable::Enum(cfg), value) => {
+            let allowed = cfg.get_values();
+            let values = match value {
+                JsonValue::String(string) => vec![string],
+                JsonValue::Array(list) if cfg.is_multiple() => list
+                    .iter()
+                    .map(|item| match item {
+                        JsonValue::String(string) => Ok(string),
+                        _ => Err("Expected a list of strings".to_owned()),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+                _ if cfg.is_multiple() => return Err("Expected a string or list of strings".into()),
+                _ => return Err("Expected a string".into()),
+            };
+
+            for value in values {
+                if !allowed.contains(&value) {
+                    return Err(format!(
+                        "Unknown value {value}, expected one of {}",
+                        allowed
+                            .iter()
+                            .map(|value| value.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+#[instrument(skip(config))]
+pub fn load_answers_into_variables(
+    path: &Path,
+    config: &FxHashMap<String, TemplateVariable>,
+) -> miette::Result<TemplateContext> {
+    let mut vars = TemplateContext::default();
+
+    debug!(path = ?path, "Loading variable values from answers file");
+
+    let answers: JsonMap<String, JsonValue> = if path.extension().is_some_and(|ext| ext == "json") {
+        json::read_file(path)?
+    } else {
+        yaml::read_file(path)?
+    };
+
+    let mut flattened = vec![];
+
+    flatten_answers(answers, None, config, path, &mut flattened)?;
+
+    for (name, value) in flattened {
+        let cfg = config.get(&name).unwrap();
+
+        if cfg.is_internal() {
+            return Err(CodegenError::InternalAnswersVariable {
+                name,
+                path: path.to_path_buf(),
+            }
+            .into());
+        }
+
+        if let Err(message) = validate_answer(cfg, &value) {
+            return Err(CodegenError::InvalidVariableValue { name, message }.into());
+        }
+
+        // Single enums may be provided as a list for convenience
+        let value = match value {
+            JsonValue::String(string) if cfg.is_multiple() => {
+                JsonValue::Array(vec![JsonValue::String(string)])
+            }
+            value => value,
+        };
+
+        debug!(name, value = ?value, "Setting variable from answers");
+
+        vars.insert(name, &value);
+    }
+
+    Ok(vars)
+}
+
+// Defaults are not passed through prompt validators when prompts are skipped
+fn validate_default(name: &str, result: Result<(), String>) -> miette::Result<()> {
+    result.map_err(|message| {
+        CodegenError::InvalidVariableValue {
+            name: name.to_owned(),
+            message,
+        }
+        .into()
+    })
+}
+
 #[instrument(skip_all)]
 pub fn gather_variables(
     args: &GenerateArgs,
     template: &Template,
     console: &Console,
+    context: TemplateContext,
 ) -> miette::Result<TemplateContext> {
-    let mut context = parse_args_into_variables(&args.vars, &template.config.variables)?;
+    gather_variables_with_options(args, template, console, context, true)
+}
+
+/// Gather variables without validating defaults, for when the values are only
+/// used to resolve the destination before the actual variables are gathered.
+#[instrument(skip_all)]
+pub fn gather_variables_for_destination(
+    args: &GenerateArgs,
+    template: &Template,
+    console: &Console,
+    context: TemplateContext,
+) -> miette::Result<TemplateContext> {
+    gather_variables_with_options(args, template, console, context, false)
+}
+
+fn gather_variables_with_options(
+    args: &GenerateArgs,
+    template: &Template,
+    console: &Console,
+    mut context: TemplateContext,
+    validate_defaults: bool,
+) -> miette::Result<TemplateContext> {
+    if let Some(answers) = &args.answers {
+        context.extend(load_answers_into_variables(
+            answers,
+            &template.config.variables,
+        )?);
+    }
+
+    context.extend(parse_args_into_variables(
+        &args.vars,
+        &template.config.variables,
+    )?);
+
+    // When answering non-interactively, all variables must be provided,
+    // unless defaults have been requested for the rest
+    if let Some(answers) = &args.answers {
+        if !args.defaults {
+            let mut missing = template
+                .config
+                .variables
+                .iter()
+                .filter(|(name, cfg)| !cfg.is_internal() && !context.contains_key(name.as_str()))
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+
+            if !missing.is_empty() {
+                missing.sort();
+
+                return Err(CodegenError::MissingAnswersVariables {
+                    names: missing.join(", "),
+                    path: answers.to_path_buf(),
+                }
+                .into());
+            }
+        }
+    }
 
     debug!("Gathering variable values from defaults and user prompts");
 
@@ -254,14 +495,22 @@ pub fn gather_variables(
             }
             TemplateVariable::Number(cfg) => {
                 let value = if skip_prompts || cfg.prompt.is_none() {
+                    if validate_defaults {
+                        validate_default(name, cfg.validate_value(cfg.default))?;
+                    }
+
                     cfg.default
                 } else {
+                    let validator_cfg = cfg.clone();
+
                     console.prompt_custom(
                         CustomType::<isize>::new(cfg.prompt.as_ref().unwrap())
                             .with_default(cfg.default)
                             .with_validator(move |input: &isize| {
                                 if required && *input == 0 {
                                     Ok(Validation::Invalid("A non-zero value is required".into()))
+                                } else if let Err(message) = validator_cfg.validate_value(*input) {
+                                    Ok(Validation::Invalid(message.into()))
                                 } else {
                                     Ok(Validation::Valid)
                                 }
@@ -275,14 +524,22 @@ pub fn gather_variables(
             }
             TemplateVariable::String(cfg) => {
                 let value = if skip_prompts || cfg.prompt.is_none() {
+                    if validate_defaults {
+                        validate_default(name, cfg.validate_value(&cfg.default))?;
+                    }
+
                     cfg.default.clone()
                 } else {
+                    let validator_cfg = cfg.clone();
+
                     console.prompt_text(
                         Text::new(cfg.prompt.as_ref().unwrap())
                             .with_default(&cfg.default)
                             .with_validator(move |input: &str| {
                                 if required && input.is_empty() {
                                     Ok(Validation::Invalid("A value is required".into()))
+                                } else if let Err(message) = validator_cfg.validate_value(input) {
+                                    Ok(Validation::Invalid(message.into()))
                                 } else {
                                     Ok(Validation::Valid)
                                 }
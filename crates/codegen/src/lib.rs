@@ -4,15 +4,20 @@ mod codegen_error;
 mod filters;
 mod funcs;
 mod generate_command;
+mod merge;
 mod template;
 mod template_file;
+mod template_lock;
 mod templates_command;
 
 pub use asset_file::*;
 pub use codegen::*;
 pub use codegen_error::*;
+pub use funcs::TemplateProjectsLoader;
 pub use generate_command::*;
+pub use merge::*;
 pub use template::*;
 pub use template_file::*;
+pub use template_lock::*;
 pub use templates_command::*;
 pub use tera::Context as TemplateContext;
@@ -0,0 +1,330 @@
+use crate::codegen_error::CodegenError;
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+use std::path::Path;
+use toml_edit::{DocumentMut, Item, TableLike};
+
+/// Deep merge the next TOML content into the previous TOML content,
+/// while preserving the formatting, comments, and key order of the previous.
+pub fn merge_toml(path: &Path, prev: &str, next: &str) -> miette::Result<String> {
+    let parse = |content: &str| {
+        content
+            .parse::<DocumentMut>()
+            .map_err(|error| CodegenError::MergeTomlFailed {
+                path: path.to_path_buf(),
+                error: Box::new(error),
+            })
+    };
+
+    let mut prev_doc = parse(prev)?;
+    let next_doc = parse(next)?;
+
+    merge_toml_tables(prev_doc.as_table_mut(), next_doc.as_table());
+
+    Ok(prev_doc.to_string())
+}
+
+fn merge_toml_tables(prev: &mut dyn TableLike, next: &dyn TableLike) {
+    for (key, next_item) in next.iter() {
+        let Some(prev_item) = prev.get_mut(key) else {
+            prev.insert(key, next_item.clone());
+            continue;
+        };
+
+        if let (Some(prev_table), Some(next_table)) =
+            (prev_item.as_table_like_mut(), next_item.as_table_like())
+        {
+            merge_toml_tables(prev_table, next_table);
+            continue;
+        }
+
+        match (prev_item, next_item) {
+            // Keep the surrounding comments and whitespace of the existing value
+            (Item::Value(prev_value), Item::Value(next_value)) => {
+                let decor = prev_value.decor().clone();
+
+                *prev_value = next_value.clone();
+                *prev_value.decor_mut() = decor;
+            }
+            (prev_item, next_item) => {
+                *prev_item = next_item.clone();
+            }
+        };
+    }
+}
+
+#[derive(Debug)]
+enum IniLine {
+    Entry { key: String, line: String },
+    Other(String),
+}
+
+impl IniLine {
+    fn is_blank(&self) -> bool {
+        matches!(self, IniLine::Other(line) if line.trim().is_empty())
+    }
+}
+
+#[derive(Debug)]
+struct IniSection {
+    name: Option<String>,
+    lines: Vec<IniLine>,
+}
+
+fn parse_ini(content: &str) -> Vec<IniSection> {
+    let mut sections = vec![IniSection {
+        name: None,
+        lines: vec![],
+    }];
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            sections.push(IniSection {
+                name: Some(trimmed[1..trimmed.len() - 1].trim().to_owned()),
+                lines: vec![IniLine::Other(line.to_owned())],
+            });
+
+            continue;
+        }
+
+        let entry = if trimmed.starts_with(';') || trimmed.starts_with('#') {
+            None
+        } else {
+            trimmed
+                .split_once('=')
+                .or_else(|| trimmed.split_once(':'))
+                .map(|(key, _)| key.trim().to_owned())
+        };
+
+        sections.last_mut().unwrap().lines.push(match entry {
+            Some(key) => IniLine::Entry {
+                key,
+                line: line.to_owned(),
+            },
+            None => IniLine::Other(line.to_owned()),
+        });
+    }
+
+    sections
+}
+
+/// Deep merge the next INI content into the previous INI content. Existing
+/// keys are updated in place, new keys are appended to their section, and
+/// new sections are appended to the end. Comments in the previous are preserved.
+pub fn merge_ini(prev: &str, next: &str) -> String {
+    let mut prev_sections = parse_ini(prev);
+
+    for next_section in parse_ini(next) {
+        let Some(prev_section) = prev_sections
+            .iter_mut()
+            .find(|section| section.name == next_section.name)
+        else {
+            if next_section.lines.iter().any(|line| !line.is_blank()) {
+                if let Some(last) = prev_sections.last_mut() {
+                    if last.lines.last().is_some_and(|line| !line.is_blank()) {
+                        last.lines.push(IniLine::Other(String::new()));
+                    }
+                }
+
+                prev_sections.push(next_section);
+            }
+
+            continue;
+        };
+
+        for next_line in next_section.lines {
+            let IniLine::Entry { key, line } = next_line else {
+                continue;
+            };
+
+            let existing = prev_section
+                .lines
+                .iter_mut()
+                .find_map(|prev_line| match prev_line {
+                    IniLine::Entry {
+                        key: prev_key,
+                        line: prev_line,
+                    } if *prev_key == key => Some(prev_line),
+                    _ => None,
+                });
+
+            if let Some(prev_line) = existing {
+                *prev_line = line;
+                continue;
+            }
+
+            // Insert after the last non-blank line of the section
+            let index = prev_section
+                .lines
+                .iter()
+                .rposition(|line| !line.is_blank())
+                .map(|index| index + 1)
+                .unwrap_or(prev_section.lines.len());
+
+            prev_section
+                .lines
+                .insert(index, IniLine::Entry { key, line });
+        }
+    }
+
+    let mut output = prev_sections
+        .into_iter()
+        .flat_map(|section| section.lines)
+        .map(|line| match line {
+            IniLine::Entry { line, .. } | IniLine::Other(line) => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    output = output.trim_start_matches('\n').to_owned();
+
+    if !output.ends_with('\n') {
+        output.push('\n');
+    }
+
+    output
+}
+
+#[derive(Debug)]
+struct Chunk {
+    base_start: usize,
+    base_end: usize,
+    other_start: usize,
+    other_end: usize,
+}
+
+fn diff_chunks(base: &[&str], other: &[&str]) -> Vec<Chunk> {
+    let mut chunks: Vec<Chunk> = vec![];
+
+    for op in capture_diff_slices(Algorithm::Myers, base, other) {
+        let (tag, base_range, other_range) = op.as_tag_tuple();
+
+        if tag == DiffTag::Equal {
+            continue;
+        }
+
+        // Join adjacent operations into a single change
+        if let Some(last) = chunks.last_mut() {
+            if last.base_end == base_range.start && last.other_end == other_range.start {
+                last.base_end = base_range.end;
+                last.other_end = other_range.end;
+                continue;
+            }
+        }
+
+        chunks.push(Chunk {
+            base_start: base_range.start,
+            base_end: base_range.end,
+            other_start: other_range.start,
+            other_end: other_range.end,
+        });
+    }
+
+    chunks
+}
+
+fn apply_chunks(
+    base: &[&str],
+    other: &[&str],
+    chunks: &[&Chunk],
+    start: usize,
+    end: usize,
+) -> String {
+    let mut output = String::new();
+    let mut index = start;
+
+    for chunk in chunks {
+        output.push_str(&base[index..chunk.base_start].concat());
+        output.push_str(&other[chunk.other_start..chunk.other_end].concat());
+        index = chunk.base_end;
+    }
+
+    output.push_str(&base[index..end].concat());
+    output
+}
+
+fn push_conflict_side(output: &mut String, content: &str) {
+    output.push_str(content);
+
+    if !content.is_empty() && !content.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+/// Merge the changes between the base (previously generated) content and
+/// the next (newly generated) content, into the current (possibly modified)
+/// content, using a line-based three-way merge. Overlapping changes that
+/// differ are marked with Git-style conflict markers. Returns the merged
+/// content, and whether a conflict occurred.
+pub fn merge_three_way(base: &str, current: &str, next: &str) -> (String, bool) {
+    let base_lines = base.split_inclusive('\n').collect::<Vec<_>>();
+    let current_lines = current.split_inclusive('\n').collect::<Vec<_>>();
+    let next_lines = next.split_inclusive('\n').collect::<Vec<_>>();
+
+    let current_chunks = diff_chunks(&base_lines, &current_lines);
+    let next_chunks = diff_chunks(&base_lines, &next_lines);
+
+    let mut output = String::new();
+    let mut conflicted = false;
+    let mut index = 0;
+    let mut c = 0;
+    let mut n = 0;
+
+    loop {
+        let start = match (current_chunks.get(c), next_chunks.get(n)) {
+            (Some(cc), Some(nc)) => cc.base_start.min(nc.base_start),
+            (Some(cc), None) => cc.base_start,
+            (None, Some(nc)) => nc.base_start,
+            (None, None) => break,
+        };
+
+        output.push_str(&base_lines[index..start].concat());
+
+        // Gather all changes from both sides that overlap or touch
+        let mut end = start;
+        let mut region_current = vec![];
+        let mut region_next = vec![];
+
+        loop {
+            if let Some(chunk) = current_chunks.get(c).filter(|ch| ch.base_start <= end) {
+                end = end.max(chunk.base_end);
+                region_current.push(chunk);
+                c += 1;
+            } else if let Some(chunk) = next_chunks.get(n).filter(|ch| ch.base_start <= end) {
+                end = end.max(chunk.base_end);
+                region_next.push(chunk);
+                n += 1;
+            } else {
+                break;
+            }
+        }
+
+        let current_region = apply_chunks(&base_lines, &current_lines, &region_current, start, end);
+        let next_region = apply_chunks(&base_lines, &next_lines, &region_next, start, end);
+
+        if region_next.is_empty() || current_region == next_region {
+            output.push_str(&current_region);
+        } else if region_current.is_empty() {
+            output.push_str(&next_region);
+        } else {
+            conflicted = true;
+
+            if !output.is_empty() && !output.ends_with('\n') {
+                output.push('\n');
+            }
+
+            output.push_str("<<<<<<< current\n");
+            push_conflict_side(&mut output, &current_region);
+            output.push_str("=======\n");
+            push_conflict_side(&mut output, &next_region);
+            output.push_str(">>>>>>> template\n");
+        }
+
+        index = end;
+    }
+
+    output.push_str(&base_lines[index..].concat());
+
+    (output, conflicted)
+}
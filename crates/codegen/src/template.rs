@@ -1,5 +1,7 @@
 use crate::asset_file::AssetFile;
+use crate::merge::{merge_ini, merge_three_way, merge_toml};
 use crate::template_file::{FileState, MergeType, TemplateFile};
+use crate::template_lock::TemplateLock;
 use crate::{filters, funcs, CodegenError};
 use miette::IntoDiagnostic;
 use moon_common::path::{to_virtual_string, RelativePathBuf};
@@ -7,16 +9,23 @@ use moon_common::Id;
 use moon_config::{ConfigLoader, TemplateConfig};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use starbase_utils::{fs, json, yaml};
+use starbase_utils::{fs, glob, json, yaml};
 use std::collections::BTreeMap;
 use std::mem;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tera::{Context, Tera};
 use tracing::{debug, instrument};
 
 static PATH_VAR: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"\[([A-Za-z0-9_]+)(?:\s*\|\s*([^\]]+))?\]").unwrap());
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TemplateHook {
+    PreGenerate,
+    PostGenerate,
+}
+
 #[derive(Clone, Debug)]
 pub struct Template {
     pub assets: BTreeMap<RelativePathBuf, AssetFile>,
@@ -43,6 +52,10 @@ impl Template {
         engine.register_filter("upper_snake_case", filters::upper_snake_case);
         engine.register_filter("path_join", filters::path_join);
         engine.register_filter("path_relative", filters::path_relative);
+        engine.register_filter("plural", filters::plural);
+        engine.register_filter("singular", filters::singular);
+        engine.register_function("env", funcs::env);
+        engine.register_function("uuid", funcs::uuid);
         engine.register_function("variables", funcs::variables);
 
         let config = ConfigLoader::default().load_template_config(&root)?;
@@ -58,6 +71,23 @@ impl Template {
         })
     }
 
+    /// Register functions that require information about the workspace,
+    /// for this template and all extended templates.
+    pub fn register_workspace_functions(
+        &mut self,
+        workspace_root: &Path,
+        load_projects: funcs::TemplateProjectsLoader,
+    ) {
+        for template in &mut self.templates {
+            template.register_workspace_functions(workspace_root, Arc::clone(&load_projects));
+        }
+
+        self.engine
+            .register_function("project", funcs::project(load_projects));
+        self.engine
+            .register_function("read_file", funcs::read_file(workspace_root.to_path_buf()));
+    }
+
     /// Extend another template and include its files when generating.
     /// Furthermore, we'll also merge variables so that they can be handled
     /// in the command correctly.
@@ -70,6 +100,50 @@ impl Template {
         self.templates.push(template);
     }
 
+    /// Return the commands for the provided hook, starting with extended
+    /// templates, in the order they should be ran.
+    pub fn get_hook_commands(&self, hook: TemplateHook) -> Vec<String> {
+        let mut commands = vec![];
+
+        for template in &self.templates {
+            commands.extend(template.get_hook_commands(hook));
+        }
+
+        commands.extend(match hook {
+            TemplateHook::PreGenerate => self.config.hooks.pre_generate.clone(),
+            TemplateHook::PostGenerate => self.config.hooks.post_generate.clone(),
+        });
+
+        commands
+    }
+
+    /// Render the conditions of configured file globs, and return a list
+    /// of globs whose conditions are falsy, and should be excluded.
+    pub fn get_excluded_file_globs(&mut self, context: &Context) -> miette::Result<Vec<String>> {
+        let mut globs = vec![];
+
+        for (glob, condition) in &self.config.files {
+            let result = self
+                .engine
+                .render_str(condition, context)
+                .map_err(|error| CodegenError::RenderFileConditionFailed {
+                    glob: glob.to_owned(),
+                    error: Box::new(error),
+                })?;
+
+            if matches!(result.trim(), "" | "false" | "0" | "null" | "[]") {
+                debug!(
+                    template = self.id.as_str(),
+                    glob, condition, "File condition is falsy, excluding matching files",
+                );
+
+                globs.push(glob.to_owned());
+            }
+        }
+
+        Ok(globs)
+    }
+
     /// Once files have been loaded by all templates in the extends chain,
     /// we must flatten all nested files map into a single top-level map.
     #[instrument(skip_all)]
@@ -107,6 +181,8 @@ impl Template {
 
         let mut files = vec![];
         let filenames = ConfigLoader::default().get_template_file_names();
+        let excluded_globs = self.get_excluded_file_globs(context)?;
+        let excluded = glob::GlobSet::new(&excluded_globs)?;
 
         debug!(
             template = self.id.as_str(),
@@ -124,9 +200,20 @@ impl Template {
             }
 
             let source_path = entry.path();
+            let rel_source_path = source_path.strip_prefix(&self.root).unwrap();
+
+            if !excluded_globs.is_empty() && excluded.matches(to_virtual_string(rel_source_path)?) {
+                debug!(
+                    template = self.id.as_str(),
+                    source = ?source_path,
+                    "Excluding file based on a file condition",
+                );
+
+                continue;
+            }
+
             let source_content = fs::read_file_bytes(&source_path)?;
-            let name =
-                self.interpolate_path(source_path.strip_prefix(&self.root).unwrap(), context)?;
+            let name = self.interpolate_path(rel_source_path, context)?;
 
             // Images, etc
             if content_inspector::inspect(&source_content).is_binary() {
@@ -216,6 +303,77 @@ impl Template {
         Ok(())
     }
 
+    /// When re-applying a template onto a previous destination, determine
+    /// the state of each file by comparing the previously generated content
+    /// (from the lockfile), the current content, and the newly generated content.
+    /// Files that have been modified on both sides will be three-way merged.
+    #[instrument(skip_all)]
+    pub fn prepare_update(&mut self, dest: &Path, lock: &TemplateLock) -> miette::Result<()> {
+        debug!(
+            template = self.id.as_str(),
+            revision = &lock.revision,
+            "Preparing template files for update",
+        );
+
+        for file in self.files.values_mut() {
+            if file.is_skipped() {
+                file.state = FileState::Skip;
+                continue;
+            }
+
+            let base = match file.dest_path.strip_prefix(dest) {
+                Ok(rel_path) => lock
+                    .files
+                    .get(&RelativePathBuf::from(to_virtual_string(rel_path)?)),
+                Err(_) => None,
+            };
+
+            if !file.dest_path.exists() {
+                // Previously generated but removed by the user, so don't recreate
+                file.state = if base.is_some() {
+                    FileState::Skip
+                } else {
+                    FileState::Create
+                };
+
+                continue;
+            }
+
+            let current = fs::read_file(&file.dest_path)?;
+
+            if current == file.content || base.is_some_and(|base| *base == file.content) {
+                file.state = FileState::Skip;
+                continue;
+            }
+
+            if base.is_some_and(|base| *base == current) {
+                file.state = FileState::Update;
+                continue;
+            }
+
+            debug!(
+                file = file.name.as_str(),
+                to = ?file.dest_path,
+                "File has been modified, applying a three-way merge",
+            );
+
+            let (content, conflicted) = merge_three_way(
+                base.map(|base| base.as_str()).unwrap_or_default(),
+                &current,
+                &file.content,
+            );
+
+            file.merged_content = Some(content);
+            file.state = if conflicted {
+                FileState::Conflict
+            } else {
+                FileState::Update
+            };
+        }
+
+        Ok(())
+    }
+
     /// Tera *does not* support iterating over the context, so we're unable
     /// to interpolate a path ourselves. Instead, let's use Tera and its
     /// template rendering to handle this.
@@ -306,17 +464,33 @@ impl Template {
 
                         yaml::write_file_with_config(&file.dest_path, &yaml::merge(&prev, &next))?;
                     }
+                    Some(MergeType::Toml) => {
+                        let prev = fs::read_file(&file.dest_path)?;
+
+                        fs::write_file(
+                            &file.dest_path,
+                            merge_toml(&file.dest_path, &prev, &file.content)?,
+                        )?;
+                    }
+                    Some(MergeType::Ini) => {
+                        let prev = fs::read_file(&file.dest_path)?;
+
+                        fs::write_file(&file.dest_path, merge_ini(&prev, &file.content))?;
+                    }
                     _ => {}
                 };
             }
-            FileState::Replace => {
+            FileState::Replace | FileState::Update | FileState::Conflict => {
                 debug!(
                     file = file.name.as_str(),
                     to = ?file.dest_path,
                     "Overwriting with template file",
                 );
 
-                fs::write_file(&file.dest_path, &file.content)?;
+                fs::write_file(
+                    &file.dest_path,
+                    file.merged_content.as_ref().unwrap_or(&file.content),
+                )?;
             }
             _ => {
                 debug!(
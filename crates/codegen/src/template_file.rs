@@ -5,16 +5,20 @@ use tracing::debug;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum MergeType {
+    Ini,
     Json,
+    Toml,
     Yaml,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FileState {
+    Conflict,
     Create,
     Merge,
     Replace,
     Skip,
+    Update,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -28,6 +32,10 @@ pub struct TemplateFile {
     /// Absolute path to destination.
     pub dest_path: PathBuf,
 
+    /// Content to write instead of the rendered content, when it has been
+    /// merged with the destination's content during an update.
+    pub merged_content: Option<String>,
+
     /// Relative path from templates dir. Also acts as the Tera engine name.
     pub name: RelativePathBuf,
 
@@ -48,6 +56,7 @@ impl TemplateFile {
             config: None,
             content: String::new(),
             dest_path: PathBuf::new(),
+            merged_content: None,
             name,
             source_path,
             state: FileState::Create,
@@ -67,6 +76,10 @@ impl TemplateFile {
             return Some(MergeType::Json);
         } else if ext.ends_with(".yaml") || ext.ends_with(".yml") {
             return Some(MergeType::Yaml);
+        } else if ext.ends_with(".toml") {
+            return Some(MergeType::Toml);
+        } else if ext.ends_with(".ini") || ext.ends_with(".cfg") || ext.ends_with(".editorconfig") {
+            return Some(MergeType::Ini);
         }
 
         None
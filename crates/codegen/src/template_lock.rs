@@ -0,0 +1,149 @@
+use crate::template::Template;
+use crate::template_file::FileState;
+use moon_common::path::{encode_component, to_virtual_string, RelativePathBuf};
+use moon_common::Id;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use starbase_utils::json::JsonValue;
+use starbase_utils::{fs, yaml};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tera::Context;
+use tracing::debug;
+
+/// Records the template, revision, and variables that were used to generate
+/// a destination, and the rendered content of each file, which acts as the
+/// base when re-applying the template with a three-way merge. Each template
+/// has its own lockfile, so that multiple templates can share a destination.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLock {
+    /// Absolute path to the destination.
+    #[serde(skip)]
+    pub dest: PathBuf,
+
+    /// Rendered content of each file, relative from the destination.
+    pub files: BTreeMap<RelativePathBuf, String>,
+
+    /// Hash of the template's source files.
+    pub revision: String,
+
+    /// ID of the template that was generated.
+    pub template: Id,
+
+    /// Values of variables that were used for rendering.
+    pub variables: BTreeMap<String, JsonValue>,
+}
+
+impl TemplateLock {
+    /// Create a lock from the template's files, once their states have been
+    /// determined. Files that were not written (kept or skipped) or merged with
+    /// existing content are not recorded, as their rendered content is not what
+    /// exists in the destination, unless they were recorded by the `previous` lock.
+    pub fn new(
+        template: &Template,
+        dest: &Path,
+        context: &Context,
+        previous: Option<&TemplateLock>,
+    ) -> miette::Result<Self> {
+        let mut lock = TemplateLock {
+            dest: dest.to_path_buf(),
+            template: template.id.clone(),
+            ..TemplateLock::default()
+        };
+
+        let mut hasher = Sha256::default();
+
+        for file in template.files.values() {
+            hasher.update(file.name.as_str().as_bytes());
+            hasher.update(fs::read_file_bytes(&file.source_path)?);
+
+            if file.is_skipped() {
+                continue;
+            }
+
+            let Ok(rel_path) = file.dest_path.strip_prefix(dest) else {
+                continue;
+            };
+
+            let rel_path = RelativePathBuf::from(to_virtual_string(rel_path)?);
+
+            let content = match file.state {
+                FileState::Merge => None,
+                FileState::Skip => match previous.and_then(|prev| prev.files.get(&rel_path)) {
+                    // Already matches the template, so use the latest as the base
+                    Some(_)
+                        if file.dest_path.exists()
+                            && fs::read_file(&file.dest_path)? == file.content =>
+                    {
+                        Some(file.content.clone())
+                    }
+                    base => base.cloned(),
+                },
+                _ => Some(file.content.clone()),
+            };
+
+            if let Some(content) = content {
+                lock.files.insert(rel_path, content);
+            }
+        }
+
+        for asset in template.assets.values() {
+            hasher.update(asset.name.as_str().as_bytes());
+            hasher.update(&asset.content);
+        }
+
+        lock.revision = format!("{:x}", hasher.finalize());
+
+        for name in template.config.variables.keys() {
+            if let Some(value) = context.get(name) {
+                lock.variables.insert(name.to_owned(), value.to_owned());
+            }
+        }
+
+        Ok(lock)
+    }
+
+    pub fn get_path(dest: &Path, template: &Id) -> PathBuf {
+        dest.join(format!(
+            ".moon-template-{}.lock.yml",
+            encode_component(template)
+        ))
+    }
+
+    pub fn load(dest: &Path, template: &Id) -> miette::Result<Option<Self>> {
+        let path = Self::get_path(dest, template);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        debug!(path = ?path, "Loading template lockfile");
+
+        let mut lock: TemplateLock = yaml::read_file(&path)?;
+        lock.dest = dest.to_path_buf();
+
+        Ok(Some(lock))
+    }
+
+    pub fn save(&self) -> miette::Result<()> {
+        let path = Self::get_path(&self.dest, &self.template);
+
+        debug!(path = ?path, "Saving template lockfile");
+
+        yaml::write_file(&path, self)?;
+
+        Ok(())
+    }
+
+    /// Convert the recorded variables into a context for rendering.
+    pub fn to_context(&self) -> Context {
+        let mut context = Context::new();
+
+        for (name, value) in &self.variables {
+            context.insert(name, value);
+        }
+
+        context
+    }
+}
@@ -2,8 +2,14 @@ use moon_codegen::CodeGenerator;
 use moon_common::Id;
 use moon_config::{FilePath, GeneratorConfig, TemplateLocator, TemplateVariable, Version};
 use moon_env::MoonEnvironment;
-use starbase_sandbox::{create_empty_sandbox, create_sandbox};
+use sha2::{Digest, Sha256};
+use starbase_archive::Archiver;
+use starbase_sandbox::{create_empty_sandbox, create_sandbox, Sandbox};
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
 use std::sync::Arc;
+use std::thread;
 
 mod codegen {
     use super::*;
@@ -158,6 +164,178 @@ mod codegen {
                 .exists());
         }
 
+        fn create_archive(sandbox: &Sandbox, source: &str, file: &str) {
+            sandbox.create_file(
+                format!("{source}/standard/template.yml"),
+                "title: Standard\ndescription: From an archive",
+            );
+            sandbox.create_file(format!("{source}/standard/file.txt"), "{{ name }}");
+
+            let source_root = sandbox.path().join("src");
+            let archive_file = sandbox.path().join(file);
+
+            Archiver::new(&source_root, &archive_file)
+                .add_source_glob("**/*")
+                .pack_from_ext()
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn unpacks_a_local_zip_archive() {
+            let sandbox = create_empty_sandbox();
+            create_archive(&sandbox, "src/templates-v1", "archives/templates.zip");
+
+            let env = Arc::new(MoonEnvironment::new_testing(sandbox.path()));
+            let config = GeneratorConfig {
+                templates: vec![TemplateLocator::Archive {
+                    url: "file://./archives/templates.zip".into(),
+                    checksum: None,
+                }],
+            };
+
+            let mut codegen = CodeGenerator::new(sandbox.path(), &config, Arc::clone(&env));
+            codegen.load_templates().await.unwrap();
+
+            // Single top-level directory is flattened
+            assert!(codegen.template_locations[0].starts_with(env.templates_dir.join("archive")));
+            assert!(codegen.template_locations[0]
+                .join("standard/template.yml")
+                .exists());
+            assert_eq!(codegen.get_template("standard").unwrap().id, "standard");
+        }
+
+        #[tokio::test]
+        async fn unpacks_a_local_tar_archive_with_checksum() {
+            let sandbox = create_empty_sandbox();
+            create_archive(&sandbox, "src", "templates.tar.gz");
+
+            let checksum = format!(
+                "{:x}",
+                Sha256::digest(fs::read(sandbox.path().join("templates.tar.gz")).unwrap())
+            );
+
+            let env = Arc::new(MoonEnvironment::new_testing(sandbox.path()));
+            let config = GeneratorConfig {
+                templates: vec![TemplateLocator::Archive {
+                    url: "file://templates.tar.gz".into(),
+                    checksum: Some(checksum.clone()),
+                }],
+            };
+
+            let mut codegen = CodeGenerator::new(sandbox.path(), &config, Arc::clone(&env));
+            codegen.load_templates().await.unwrap();
+
+            assert_eq!(
+                codegen.template_locations[0],
+                env.templates_dir.join("archive").join(checksum)
+            );
+            assert_eq!(codegen.get_template("standard").unwrap().id, "standard");
+        }
+
+        /// Serve the archive file over HTTP, reading it from disk on every request.
+        fn serve_archive(sandbox: &Sandbox, file: &str) -> String {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/{file}", listener.local_addr().unwrap());
+            let archive_file = sandbox.path().join(file);
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut request = [0; 1024];
+                    let _ = stream.read(&mut request).unwrap();
+
+                    let body = fs::read(&archive_file).unwrap();
+
+                    stream
+                        .write_all(
+                            format!(
+                                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                                body.len()
+                            )
+                            .as_bytes(),
+                        )
+                        .unwrap();
+                    stream.write_all(&body).unwrap();
+                }
+            });
+
+            url
+        }
+
+        #[tokio::test]
+        async fn downloads_unpinned_remote_archive_again() {
+            let sandbox = create_empty_sandbox();
+            create_archive(&sandbox, "src", "templates.zip");
+
+            let env = Arc::new(MoonEnvironment::new_testing(sandbox.path()));
+            let config = GeneratorConfig {
+                templates: vec![TemplateLocator::Archive {
+                    url: serve_archive(&sandbox, "templates.zip"),
+                    checksum: None,
+                }],
+            };
+
+            let mut codegen = CodeGenerator::new(sandbox.path(), &config, Arc::clone(&env));
+            codegen.load_templates().await.unwrap();
+
+            let location = codegen.template_locations[0].clone();
+
+            assert!(location.join("standard/file.txt").exists());
+            assert!(!location.join("standard/other.txt").exists());
+
+            // Publish a new archive at the same URL
+            sandbox.create_file("src/standard/other.txt", "{{ name }}");
+            fs::remove_file(sandbox.path().join("templates.zip")).unwrap();
+            create_archive(&sandbox, "src", "templates.zip");
+
+            let mut codegen = CodeGenerator::new(sandbox.path(), &config, Arc::clone(&env));
+            codegen.load_templates().await.unwrap();
+
+            assert_eq!(codegen.template_locations[0], location);
+            assert!(location.join("standard/other.txt").exists());
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Checksum mismatch for template archive")]
+        async fn errors_for_archive_checksum_mismatch() {
+            let sandbox = create_empty_sandbox();
+            create_archive(&sandbox, "src", "templates.zip");
+
+            let config = GeneratorConfig {
+                templates: vec![TemplateLocator::Archive {
+                    url: "file://templates.zip".into(),
+                    checksum: Some("0".repeat(64)),
+                }],
+            };
+
+            let mut codegen = CodeGenerator::new(
+                sandbox.path(),
+                &config,
+                MoonEnvironment::new_testing(sandbox.path()).into(),
+            );
+            codegen.load_templates().await.unwrap();
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "does not exist")]
+        async fn errors_for_missing_archive() {
+            let sandbox = create_empty_sandbox();
+
+            let config = GeneratorConfig {
+                templates: vec![TemplateLocator::Archive {
+                    url: "file://missing.zip".into(),
+                    checksum: None,
+                }],
+            };
+
+            let mut codegen = CodeGenerator::new(
+                sandbox.path(),
+                &config,
+                MoonEnvironment::new_testing(sandbox.path()).into(),
+            );
+            codegen.load_templates().await.unwrap();
+        }
+
         #[tokio::test]
         #[should_panic(expected = "Found multiple templates with the same name folder-name")]
         async fn errors_for_dupe_ids() {
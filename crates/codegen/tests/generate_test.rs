@@ -31,6 +31,23 @@ mod cli_args {
             "string".into(),
             TemplateVariable::String(TemplateVariableStringSetting::default()),
         );
+        vars.insert(
+            "validNumber".into(),
+            TemplateVariable::Number(TemplateVariableNumberSetting {
+                min: Some(1),
+                max: Some(10),
+                ..Default::default()
+            }),
+        );
+        vars.insert(
+            "validString".into(),
+            TemplateVariable::String(TemplateVariableStringSetting {
+                max_length: Some(10),
+                pattern: Some("^[a-z-]+$".into()),
+                message: Some("Must be a valid package name".into()),
+                ..Default::default()
+            }),
+        );
         vars.insert(
             "enum".into(),
             TemplateVariable::Enum(TemplateVariableEnumSetting {
@@ -175,6 +192,54 @@ mod cli_args {
         }
     }
 
+    mod validation {
+        use super::*;
+
+        #[test]
+        fn sets_valid_values() {
+            let context = parse_args_into_variables(
+                &[
+                    "--validNumber".into(),
+                    "5".into(),
+                    "--validString".into(),
+                    "my-pkg".into(),
+                ],
+                &create_vars(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                context.get("validNumber").unwrap(),
+                &Value::Number(Number::from(5))
+            );
+            assert_eq!(
+                context.get("validString").unwrap(),
+                &Value::String("my-pkg".into())
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Must be at least 1")]
+        fn errors_when_number_below_min() {
+            parse_args_into_variables(&["--validNumber".into(), "0".into()], &create_vars())
+                .unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "Must be at most 10")]
+        fn errors_when_number_above_max() {
+            parse_args_into_variables(&["--validNumber".into(), "11".into()], &create_vars())
+                .unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "Must be a valid package name")]
+        fn errors_with_custom_message() {
+            parse_args_into_variables(&["--validString".into(), "my pkg".into()], &create_vars())
+                .unwrap();
+        }
+    }
+
     mod enum_single {
         use super::*;
 
@@ -260,3 +325,161 @@ mod cli_args {
         }
     }
 }
+
+mod answers {
+    use super::*;
+    use moon_codegen::load_answers_into_variables;
+    use starbase_sandbox::create_empty_sandbox;
+
+    fn create_vars() -> FxHashMap<String, TemplateVariable> {
+        let mut vars = FxHashMap::default();
+        vars.insert(
+            "internal".into(),
+            TemplateVariable::Boolean(TemplateVariableBoolSetting {
+                internal: true,
+                ..Default::default()
+            }),
+        );
+        vars.insert(
+            "bool".into(),
+            TemplateVariable::Boolean(TemplateVariableBoolSetting::default()),
+        );
+        vars.insert(
+            "number".into(),
+            TemplateVariable::Number(TemplateVariableNumberSetting {
+                max: Some(100),
+                ..Default::default()
+            }),
+        );
+        vars.insert(
+            "string".into(),
+            TemplateVariable::String(TemplateVariableStringSetting::default()),
+        );
+        vars.insert(
+            "dockerImage".into(),
+            TemplateVariable::String(TemplateVariableStringSetting::default()),
+        );
+        vars.insert(
+            "multienum".into(),
+            TemplateVariable::Enum(TemplateVariableEnumSetting {
+                multiple: Some(true),
+                values: vec![
+                    TemplateVariableEnumValue::String("a".into()),
+                    TemplateVariableEnumValue::String("b".into()),
+                    TemplateVariableEnumValue::String("c".into()),
+                ],
+                ..Default::default()
+            }),
+        );
+        vars
+    }
+
+    #[test]
+    fn loads_yaml() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "answers.yml",
+            "bool: true\nnumber: 10\nstring: abc\nmultienum: [a, c]\n",
+        );
+
+        let context =
+            load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars())
+                .unwrap();
+
+        assert_eq!(context.get("bool").unwrap(), &Value::Bool(true));
+        assert_eq!(
+            context.get("number").unwrap(),
+            &Value::Number(Number::from(10))
+        );
+        assert_eq!(context.get("string").unwrap(), &Value::String("abc".into()));
+        assert_eq!(
+            context.get("multienum").unwrap(),
+            &Value::Array(vec![Value::String("a".into()), Value::String("c".into())])
+        );
+    }
+
+    #[test]
+    fn loads_json() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.json", r#"{ "string": "abc", "multienum": "b" }"#);
+
+        let context =
+            load_answers_into_variables(&sandbox.path().join("answers.json"), &create_vars())
+                .unwrap();
+
+        assert_eq!(context.get("string").unwrap(), &Value::String("abc".into()));
+        assert_eq!(
+            context.get("multienum").unwrap(),
+            &Value::Array(vec![Value::String("b".into())])
+        );
+    }
+
+    #[test]
+    fn flattens_nested_values() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.yml", "docker:\n  image: node\n");
+
+        let context =
+            load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars())
+                .unwrap();
+
+        assert_eq!(
+            context.get("dockerImage").unwrap(),
+            &Value::String("node".into())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown variable docker.tag in answers file")]
+    fn errors_for_unknown_nested_variable() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.yml", "docker:\n  tag: latest\n");
+
+        load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown variable unknown in answers file")]
+    fn errors_for_unknown_variable() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.yml", "unknown: true\n");
+
+        load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Variable internal in answers file")]
+    fn errors_for_internal_variable() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.yml", "internal: true\n");
+
+        load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a boolean")]
+    fn errors_for_invalid_type() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.yml", "bool: 'yes'\n");
+
+        load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Must be at most 100")]
+    fn errors_for_failed_validation() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.yml", "number: 200\n");
+
+        load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown value d, expected one of")]
+    fn errors_for_unknown_enum_value() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("answers.yml", "multienum: [a, d]\n");
+
+        load_answers_into_variables(&sandbox.path().join("answers.yml"), &create_vars()).unwrap();
+    }
+}
@@ -0,0 +1,180 @@
+use moon_codegen::{merge_ini, merge_three_way, merge_toml};
+use std::path::PathBuf;
+
+mod merge_toml {
+    use super::*;
+
+    #[test]
+    fn preserves_comments_and_order() {
+        let prev = r#"# Package info
+[package]
+name = "app" # The name
+version = "1.0.0"
+
+[dependencies]
+serde = "1.0"
+"#;
+        let next = r#"[package]
+version = "2.0.0"
+edition = "2021"
+
+[dependencies]
+tokio = "1.0"
+"#;
+
+        assert_eq!(
+            merge_toml(&PathBuf::from("Cargo.toml"), prev, next).unwrap(),
+            r#"# Package info
+[package]
+name = "app" # The name
+version = "2.0.0"
+edition = "2021"
+
+[dependencies]
+serde = "1.0"
+tokio = "1.0"
+"#
+        );
+    }
+
+    #[test]
+    fn merges_nested_and_inline_tables() {
+        let prev = r#"[tool.lint]
+rules = { a = true, b = false }
+"#;
+        let next = r#"[tool.lint]
+rules = { b = true }
+ignore = ["x"]
+
+[tool.fmt]
+width = 80
+"#;
+
+        assert_eq!(
+            merge_toml(&PathBuf::from("file.toml"), prev, next).unwrap(),
+            r#"[tool.lint]
+rules = { a = true, b = true }
+ignore = ["x"]
+
+[tool.fmt]
+width = 80
+"#
+        );
+    }
+
+    #[test]
+    fn replaces_arrays() {
+        let prev = "list = [1, 2]\n";
+        let next = "list = [3]\n";
+
+        assert_eq!(
+            merge_toml(&PathBuf::from("file.toml"), prev, next).unwrap(),
+            "list = [3]\n"
+        );
+    }
+
+    #[test]
+    fn errors_for_invalid_toml() {
+        assert!(merge_toml(&PathBuf::from("file.toml"), "a = ", "b = 1").is_err());
+    }
+}
+
+mod merge_ini {
+    use super::*;
+
+    #[test]
+    fn updates_existing_keys_in_place() {
+        let prev = "; Global\nroot = true\n\n[*]\n# Indent\nindent_style = tab\nindent_size = 2\n";
+        let next = "[*]\nindent_style = space\n";
+
+        assert_eq!(
+            merge_ini(prev, next),
+            "; Global\nroot = true\n\n[*]\n# Indent\nindent_style = space\nindent_size = 2\n"
+        );
+    }
+
+    #[test]
+    fn appends_new_keys_to_section() {
+        let prev = "[a]\nfoo = 1\n\n[b]\nbar = 2\n";
+        let next = "[a]\nbaz = 3\n";
+
+        assert_eq!(
+            merge_ini(prev, next),
+            "[a]\nfoo = 1\nbaz = 3\n\n[b]\nbar = 2\n"
+        );
+    }
+
+    #[test]
+    fn appends_new_sections() {
+        let prev = "[a]\nfoo = 1\n";
+        let next = "top = yes\n\n[b]\nbar = 2\n";
+
+        assert_eq!(
+            merge_ini(prev, next),
+            "top = yes\n[a]\nfoo = 1\n\n[b]\nbar = 2\n"
+        );
+    }
+}
+
+mod merge_three_way {
+    use super::*;
+
+    #[test]
+    fn applies_non_overlapping_changes() {
+        let base = "a\nb\nc\nd\ne\n";
+        let current = "a\nB\nc\nd\ne\n";
+        let next = "a\nb\nc\nd\nE\nf\n";
+
+        assert_eq!(
+            merge_three_way(base, current, next),
+            ("a\nB\nc\nd\nE\nf\n".into(), false)
+        );
+    }
+
+    #[test]
+    fn keeps_identical_changes() {
+        let base = "a\nb\n";
+        let current = "a\nc\n";
+        let next = "a\nc\n";
+
+        assert_eq!(
+            merge_three_way(base, current, next),
+            ("a\nc\n".into(), false)
+        );
+    }
+
+    #[test]
+    fn marks_conflicts() {
+        let base = "a\nb\nc\n";
+        let current = "a\nmine\nc\n";
+        let next = "a\ntheirs\nc\n";
+
+        assert_eq!(
+            merge_three_way(base, current, next),
+            (
+                "a\n<<<<<<< current\nmine\n=======\ntheirs\n>>>>>>> template\nc\n".into(),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn handles_missing_trailing_newline() {
+        let base = "a";
+        let current = "b";
+        let next = "c";
+
+        assert_eq!(
+            merge_three_way(base, current, next),
+            (
+                "<<<<<<< current\nb\n=======\nc\n>>>>>>> template\n".into(),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn merges_against_empty_base() {
+        assert_eq!(merge_three_way("", "a\n", "a\n"), ("a\n".into(), false));
+    }
+}
@@ -46,6 +46,22 @@ mod template_file {
             assert_eq!(template.is_mergeable(), Some(MergeType::Yaml));
         }
 
+        #[test]
+        fn is_with_toml() {
+            let template = TemplateFile::new(RelativePathBuf::from("file.toml"), PathBuf::new());
+
+            assert_eq!(template.is_mergeable(), Some(MergeType::Toml));
+        }
+
+        #[test]
+        fn is_with_ini() {
+            for name in ["file.ini", "setup.cfg", ".editorconfig"] {
+                let template = TemplateFile::new(RelativePathBuf::from(name), PathBuf::new());
+
+                assert_eq!(template.is_mergeable(), Some(MergeType::Ini));
+            }
+        }
+
         #[test]
         fn uses_to_config() {
             let mut template = TemplateFile::new(RelativePathBuf::from("file.txt"), PathBuf::new());
@@ -1,8 +1,11 @@
-use moon_codegen::{CodeGenerator, Template, TemplateContext, TemplateFile};
+use moon_codegen::{
+    CodeGenerator, FileState, Template, TemplateContext, TemplateFile, TemplateLock,
+};
+use moon_common::path::RelativePathBuf;
 use moon_common::Id;
 use moon_config::{GeneratorConfig, TemplateFrontmatterConfig};
 use moon_env::MoonEnvironment;
-use starbase_sandbox::{create_sandbox, locate_fixture};
+use starbase_sandbox::{create_empty_sandbox, create_sandbox, locate_fixture};
 use std::path::PathBuf;
 
 fn create_template_file() -> TemplateFile {
@@ -59,6 +62,50 @@ mod template {
             );
         }
 
+        #[test]
+        fn excludes_files_with_falsy_conditions() {
+            let mut template = create_template();
+            let fixture = locate_fixture("template");
+
+            template
+                .config
+                .files
+                .insert("folder/**".into(), "{{ not bool }}".into());
+            template
+                .config
+                .files
+                .insert("*.txt".into(), "{{ string == 'unknown' }}".into());
+            template
+                .config
+                .files
+                .insert("file.ts".into(), "{{ bool }}".into());
+
+            template.load_files(&fixture, &create_context()).unwrap();
+
+            assert_eq!(
+                template
+                    .files
+                    .into_values()
+                    .map(|f| f.source_path)
+                    .collect::<Vec<_>>(),
+                vec![fixture.join("file.ts")]
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Failed to render the condition for files matching")]
+        fn errors_for_invalid_condition() {
+            let mut template = create_template();
+            let fixture = locate_fixture("template");
+
+            template
+                .config
+                .files
+                .insert("folder/**".into(), "{{ unknown_var }}".into());
+
+            template.load_files(&fixture, &create_context()).unwrap();
+        }
+
         #[test]
         fn adds_all_to_tera_engine() {
             let mut template = create_template();
@@ -425,4 +472,302 @@ mod template {
             assert_snapshot!(file.content);
         }
     }
+
+    mod functions_and_filters {
+        use super::*;
+        use rustc_hash::FxHashMap;
+        use starbase_utils::json::serde_json::json;
+        use std::error::Error;
+        use std::sync::Arc;
+
+        fn render(template: &mut Template, content: &str) -> String {
+            template
+                .engine
+                .render_str(content, &create_context())
+                .unwrap()
+        }
+
+        #[test]
+        fn pluralizes_and_singularizes_words() {
+            let mut template = create_template();
+
+            assert_eq!(
+                render(
+                    &mut template,
+                    "{{ 'package' | plural }} {{ 'box' | plural }} {{ 'library' | plural }} {{ 'key' | plural }} {{ 'Person' | plural }} {{ 'data' | plural }}"
+                ),
+                "packages boxes libraries keys People data"
+            );
+            assert_eq!(
+                render(
+                    &mut template,
+                    "{{ 'packages' | singular }} {{ 'boxes' | singular }} {{ 'libraries' | singular }} {{ 'class' | singular }} {{ 'children' | singular }}"
+                ),
+                "package box library class child"
+            );
+        }
+
+        #[test]
+        fn pluralizes_words_with_special_suffixes() {
+            let mut template = create_template();
+
+            assert_eq!(
+                render(
+                    &mut template,
+                    "{{ 'status' | plural }} {{ 'bus' | plural }} {{ 'analysis' | plural }} {{ 'shelf' | plural }} {{ 'branch' | plural }} {{ 'quiz' | plural }} {{ 'day' | plural }}"
+                ),
+                "statuses buses analyses shelves branches quizzes days"
+            );
+        }
+
+        #[test]
+        fn singularizes_words_with_special_suffixes() {
+            let mut template = create_template();
+
+            assert_eq!(
+                render(
+                    &mut template,
+                    "{{ 'statuses' | singular }} {{ 'buses' | singular }} {{ 'analyses' | singular }} {{ 'shelves' | singular }} {{ 'branches' | singular }} {{ 'quizzes' | singular }} {{ 'caches' | singular }} {{ 'databases' | singular }} {{ 'sizes' | singular }}"
+                ),
+                "status bus analysis shelf branch quiz cache database size"
+            );
+        }
+
+        #[test]
+        fn doesnt_singularize_singular_words() {
+            let mut template = create_template();
+
+            assert_eq!(
+                render(
+                    &mut template,
+                    "{{ 'status' | singular }} {{ 'bus' | singular }} {{ 'analysis' | singular }} {{ 'class' | singular }}"
+                ),
+                "status bus analysis class"
+            );
+        }
+
+        #[test]
+        fn supports_builtin_json_and_indent_filters() {
+            let mut template = create_template();
+
+            assert_eq!(
+                render(&mut template, "{{ variables(a=1) | json_encode() | safe }}"),
+                "{\"a\":1}"
+            );
+            assert_eq!(
+                render(&mut template, "{{ 'a\nb' | indent(prefix='  ') }}"),
+                "a\n  b"
+            );
+        }
+
+        #[test]
+        fn generates_uuids() {
+            let mut template = create_template();
+            let first = render(&mut template, "{{ uuid() }}");
+            let second = render(&mut template, "{{ uuid() }}");
+
+            assert_eq!(first.len(), 36);
+            assert_ne!(first, second);
+        }
+
+        #[test]
+        fn reads_env_vars() {
+            let mut template = create_template();
+
+            // Set by Cargo for the test process
+            assert_eq!(
+                render(
+                    &mut template,
+                    "{{ env(name='CARGO_PKG_NAME') }}|{{ env(name='MOON_CODEGEN_UNKNOWN_ENV') }}|{{ env(name='MOON_CODEGEN_UNKNOWN_ENV', default='fallback') }}"
+                ),
+                "moon_codegen||fallback"
+            );
+        }
+
+        #[test]
+        fn reads_files_and_projects_from_workspace() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("packages/foo/version.txt", "1.2.3");
+
+            let mut projects = FxHashMap::default();
+            projects.insert(
+                "foo".to_owned(),
+                json!({ "id": "foo", "alias": "@scope/foo", "source": "packages/foo", "type": "library" }),
+            );
+
+            let mut template = create_template();
+            let projects = Arc::new(projects);
+            template.register_workspace_functions(
+                sandbox.path(),
+                Arc::new(move || Ok(Arc::clone(&projects))),
+            );
+
+            assert_eq!(
+                render(
+                    &mut template,
+                    "{% set foo = project(id='foo') %}{{ foo.alias }} {{ foo.type }} {{ read_file(path=foo.source ~ '/version.txt') }}"
+                ),
+                "@scope/foo library 1.2.3"
+            );
+        }
+
+        #[test]
+        fn doesnt_load_projects_when_not_used() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("version.txt", "1.2.3");
+
+            let mut template = create_template();
+            template.register_workspace_functions(
+                sandbox.path(),
+                Arc::new(|| panic!("Projects should not be loaded!")),
+            );
+
+            assert_eq!(
+                render(&mut template, "{{ read_file(path='version.txt') }}"),
+                "1.2.3"
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "No project has been configured with the name or alias `bar`.")]
+        fn errors_for_unknown_project() {
+            let mut template = create_template();
+            template.register_workspace_functions(
+                &PathBuf::from("."),
+                Arc::new(|| Ok(Arc::new(FxHashMap::default()))),
+            );
+
+            template
+                .engine
+                .render_str("{{ project(id='bar') }}", &create_context())
+                .map_err(|error| format!("{:?}", error.source()))
+                .unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "must be within the workspace")]
+        fn errors_for_files_outside_workspace() {
+            let sandbox = create_empty_sandbox();
+            let mut template = create_template();
+            template.register_workspace_functions(
+                sandbox.path(),
+                Arc::new(|| Ok(Arc::new(FxHashMap::default()))),
+            );
+
+            template
+                .engine
+                .render_str("{{ read_file(path='../outside.txt') }}", &create_context())
+                .map_err(|error| format!("{:?}", error.source()))
+                .unwrap();
+        }
+    }
+}
+
+mod template_lock {
+    use super::*;
+
+    fn set_state(template: &mut Template, name: &str, state: FileState) {
+        template
+            .files
+            .values_mut()
+            .find(|f| f.name == name)
+            .unwrap()
+            .state = state;
+    }
+
+    #[test]
+    fn records_rendered_content_of_generated_files() {
+        let sandbox = create_empty_sandbox();
+        let mut template = create_template();
+
+        template
+            .load_files(sandbox.path(), &create_context())
+            .unwrap();
+        set_state(&mut template, "file.ts", FileState::Replace);
+
+        let lock = TemplateLock::new(&template, sandbox.path(), &create_context(), None).unwrap();
+
+        assert_eq!(
+            lock.files.get(&RelativePathBuf::from("file.txt")).unwrap(),
+            "2\n"
+        );
+        assert_eq!(
+            lock.files.get(&RelativePathBuf::from("file.ts")).unwrap(),
+            "export {};\n"
+        );
+    }
+
+    #[test]
+    fn doesnt_record_kept_or_merged_files() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("file.ts", "export const user = true;\n");
+        sandbox.create_file("folder/nested-file.ts", "export const user = true;\n");
+
+        let mut template = create_template();
+
+        template
+            .load_files(sandbox.path(), &create_context())
+            .unwrap();
+        set_state(&mut template, "file.ts", FileState::Merge);
+        set_state(&mut template, "folder/nested-file.ts", FileState::Skip);
+
+        let lock = TemplateLock::new(&template, sandbox.path(), &create_context(), None).unwrap();
+
+        assert!(!lock.files.contains_key(&RelativePathBuf::from("file.ts")));
+        assert!(!lock
+            .files
+            .contains_key(&RelativePathBuf::from("folder/nested-file.ts")));
+        assert!(lock.files.contains_key(&RelativePathBuf::from("file.txt")));
+    }
+
+    #[test]
+    fn doesnt_record_untouched_files() {
+        let sandbox = create_empty_sandbox();
+        let mut template = create_template();
+
+        template
+            .load_files(sandbox.path(), &create_context())
+            .unwrap();
+        set_state(&mut template, "file.ts", FileState::Skip);
+
+        // Matches the template, but was never generated by it
+        sandbox.create_file("file.ts", "export {};\n");
+
+        let lock = TemplateLock::new(&template, sandbox.path(), &create_context(), None).unwrap();
+
+        assert!(!lock.files.contains_key(&RelativePathBuf::from("file.ts")));
+    }
+
+    #[test]
+    fn inherits_previous_content_of_skipped_files() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("folder/nested-file.ts", "export const user = true;\n");
+
+        let mut template = create_template();
+
+        template
+            .load_files(sandbox.path(), &create_context())
+            .unwrap();
+        set_state(&mut template, "folder/nested-file.ts", FileState::Skip);
+
+        let mut previous = TemplateLock::default();
+        previous
+            .files
+            .insert("folder/nested-file.ts".into(), "previous\n".into());
+
+        let lock = TemplateLock::new(
+            &template,
+            sandbox.path(),
+            &create_context(),
+            Some(&previous),
+        )
+        .unwrap();
+
+        assert_eq!(
+            lock.files
+                .get(&RelativePathBuf::from("folder/nested-file.ts"))
+                .unwrap(),
+            "previous\n"
+        );
+    }
 }
@@ -1,14 +1,14 @@
-use moon_config::{CodeownersConfig, OwnersConfig, OwnersPaths, VcsProvider};
-use starbase_utils::fs::{self, FsError};
-use std::fs::File;
-use std::io::Write;
+use crate::codeowners_rule::CodeownersRule;
+use crate::providers::{create_codeowners_provider, BoxedCodeownersProvider};
+use moon_config::{CodeownersConfig, OwnersConfig, VcsProvider};
+use starbase_utils::fs;
 use std::path::{Path, PathBuf};
 use tracing::{debug, trace};
 
 pub struct CodeownersGenerator {
+    pub content: String,
     pub file_path: PathBuf,
-    file: File,
-    provider: VcsProvider,
+    provider: BoxedCodeownersProvider,
 }
 
 impl CodeownersGenerator {
@@ -18,20 +18,16 @@ impl CodeownersGenerator {
     ) -> miette::Result<CodeownersGenerator> {
         debug!("Aggregating code owners");
 
-        let file_path = workspace_root.join(match provider {
-            VcsProvider::GitHub => ".github/CODEOWNERS",
-            VcsProvider::GitLab => ".gitlab/CODEOWNERS",
-            _ => "CODEOWNERS",
-        });
+        let provider = create_codeowners_provider(provider);
 
         let mut generator = CodeownersGenerator {
-            file: fs::create_file(&file_path)?,
-            file_path,
+            content: String::new(),
+            file_path: workspace_root.join(provider.get_file_path()),
             provider,
         };
 
-        generator.write("# Automatically generated by moon. DO NOT MODIFY!")?;
-        generator.write("# https://moonrepo.dev/docs/guides/codeowners")?;
+        generator.write("# Automatically generated by moon. DO NOT MODIFY!");
+        generator.write("# https://moonrepo.dev/docs/guides/codeowners");
 
         Ok(generator)
     }
@@ -49,83 +45,29 @@ impl CodeownersGenerator {
 
         trace!(project_id = id, source, "Adding project entries");
 
-        self.write("")?;
+        self.write("");
 
         // Render the header
-        self.write(format!("# {}", id))?;
+        self.write(format!("# {}", id));
 
         let required_approvals = config
             .required_approvals
             .or(root_config.required_approvals)
             .unwrap_or(0);
 
-        match &self.provider {
-            VcsProvider::Bitbucket => {
-                if required_approvals > 0 {
-                    if let Some(default_owner) = &config.default_owner {
-                        self.write(format!(
-                            "Check({} >= {})",
-                            default_owner, required_approvals
-                        ))?;
-                    }
-                }
-            }
-
-            VcsProvider::GitLab => {
-                let mut header = format!("[{id}]");
-
-                if config.optional {
-                    header = format!("^{header}")
-                }
-
-                if required_approvals > 0 {
-                    header = format!("{header}[{}]", required_approvals);
-                }
-
-                if matches!(config.paths, OwnersPaths::List(_)) {
-                    header = format!("{header} {}", config.default_owner.as_ref().unwrap());
-                }
-
-                self.write(header)?;
-            }
-            _ => {}
-        };
+        for line in self
+            .provider
+            .render_project_header(id, config, required_approvals)
+        {
+            self.write(line);
+        }
 
         // Render the owner entries
-        let root = PathBuf::from("/").join(source);
-
-        match &config.paths {
-            OwnersPaths::List(paths) => {
-                for path in paths {
-                    if matches!(self.provider, VcsProvider::GitLab) {
-                        self.write(self.format_path(root.join(path)))?;
-                    } else {
-                        self.write(format!(
-                            "{} {}",
-                            self.format_path(root.join(path)),
-                            config.default_owner.as_ref().unwrap()
-                        ))?;
-                    }
-                }
-            }
-            OwnersPaths::Map(map) => {
-                for (path, owners) in map {
-                    if owners.is_empty() {
-                        self.write(format!(
-                            "{} {}",
-                            self.format_path(root.join(path)),
-                            config.default_owner.as_ref().unwrap()
-                        ))?;
-                    } else {
-                        self.write(format!(
-                            "{} {}",
-                            self.format_path(root.join(path)),
-                            owners.join(" ")
-                        ))?;
-                    }
-                }
-            }
-        };
+        for rule in CodeownersRule::from_project(id, source, config) {
+            let line = self.provider.render_rule(&rule, Some(config));
+
+            self.write(line);
+        }
 
         Ok(())
     }
@@ -137,17 +79,13 @@ impl CodeownersGenerator {
 
         trace!("Adding workspace entries");
 
-        self.write("")?;
-        self.write("# (workspace)")?;
-
-        for (path, owners) in &config.global_paths {
-            if !owners.is_empty() {
-                self.write(format!(
-                    "{} {}",
-                    self.format_path(PathBuf::from(path)),
-                    owners.join(" ")
-                ))?;
-            }
+        self.write("");
+        self.write("# (workspace)");
+
+        for rule in CodeownersRule::from_workspace(config) {
+            let line = self.provider.render_rule(&rule, None);
+
+            self.write(line);
         }
 
         Ok(())
@@ -156,38 +94,21 @@ impl CodeownersGenerator {
     pub fn cleanup(self) -> miette::Result<()> {
         debug!(file = ?self.file_path, "Removing CODEOWNERS file");
 
-        drop(self.file);
-
         fs::remove_file(&self.file_path)?;
 
         Ok(())
     }
 
-    pub fn generate(mut self) -> miette::Result<bool> {
+    pub fn generate(self) -> miette::Result<bool> {
         debug!(file = ?self.file_path, "Generating and writing CODEOWNERS file");
 
-        self.file.flush().map_err(|error| FsError::Create {
-            path: self.file_path.to_path_buf(),
-            error: Box::new(error),
-        })?;
+        fs::write_file(&self.file_path, self.content)?;
 
         Ok(true)
     }
 
-    fn format_path(&self, path: PathBuf) -> String {
-        path.to_string_lossy()
-            // Always use forward slashes
-            .replace('\\', "/")
-            // Escape spaces
-            .replace(' ', "\\ ")
-    }
-
-    fn write<T: AsRef<str>>(&mut self, message: T) -> miette::Result<()> {
-        writeln!(self.file, "{}", message.as_ref()).map_err(|error| FsError::Create {
-            path: self.file_path.to_path_buf(),
-            error: Box::new(error),
-        })?;
-
-        Ok(())
+    fn write<T: AsRef<str>>(&mut self, message: T) {
+        self.content.push_str(message.as_ref());
+        self.content.push('\n');
     }
 }
@@ -0,0 +1,128 @@
+use crate::codeowners_rule::CodeownersRule;
+use moon_config::{CodeownersConfig, OwnersConfig};
+use serde::{Deserialize, Serialize};
+use starbase_utils::{fs, json};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use tracing::{debug, trace};
+
+/// Ownership information for a single project.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CodeownersMapProject {
+    /// The default owner of the project, if configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_owner: Option<String>,
+
+    /// Whether approval from the owners is optional.
+    pub optional: bool,
+
+    /// All unique owners across the project's rules.
+    pub owners: BTreeSet<String>,
+
+    /// The number of approvals required, inherited from the workspace
+    /// when not configured by the project.
+    pub required_approvals: u8,
+
+    /// The project's source path, relative from the workspace root.
+    pub source: String,
+}
+
+/// A provider agnostic map of all ownership rules, for consumption by custom tooling.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct CodeownersMap {
+    /// Ownership information for each project with owners.
+    pub projects: BTreeMap<String, CodeownersMapProject>,
+
+    /// All rules in the same order as the `CODEOWNERS` file,
+    /// where the last matching rule takes precedence.
+    pub rules: Vec<CodeownersRule>,
+}
+
+pub struct CodeownersMapGenerator {
+    pub file_path: PathBuf,
+    pub map: CodeownersMap,
+}
+
+impl CodeownersMapGenerator {
+    pub fn new(workspace_root: &Path) -> CodeownersMapGenerator {
+        debug!("Aggregating code owners map");
+
+        CodeownersMapGenerator {
+            file_path: workspace_root.join(".moon").join("codeowners.json"),
+            map: CodeownersMap::default(),
+        }
+    }
+
+    pub fn add_project_entry(
+        &mut self,
+        id: &str,
+        source: &str,
+        config: &OwnersConfig,
+        root_config: &CodeownersConfig,
+    ) -> miette::Result<()> {
+        if config.paths.is_empty() {
+            return Ok(());
+        }
+
+        trace!(project_id = id, source, "Adding project map entries");
+
+        let rules = CodeownersRule::from_project(id, source, config);
+
+        self.map.projects.insert(
+            id.to_owned(),
+            CodeownersMapProject {
+                default_owner: config.default_owner.clone(),
+                optional: config.optional,
+                owners: rules
+                    .iter()
+                    .flat_map(|rule| rule.owners.iter().cloned())
+                    .collect(),
+                required_approvals: config
+                    .required_approvals
+                    .or(root_config.required_approvals)
+                    .unwrap_or(0),
+                source: source.to_owned(),
+            },
+        );
+
+        self.map.rules.extend(rules);
+
+        Ok(())
+    }
+
+    pub fn add_workspace_entries(&mut self, config: &CodeownersConfig) -> miette::Result<()> {
+        if config.global_paths.is_empty() {
+            return Ok(());
+        }
+
+        trace!("Adding workspace map entries");
+
+        self.map
+            .rules
+            .extend(CodeownersRule::from_workspace(config));
+
+        Ok(())
+    }
+
+    pub fn render(&self) -> miette::Result<String> {
+        Ok(json::format(&self.map, true)?)
+    }
+
+    pub fn cleanup(self) -> miette::Result<()> {
+        debug!(file = ?self.file_path, "Removing code owners map file");
+
+        fs::remove_file(&self.file_path)?;
+
+        Ok(())
+    }
+
+    pub fn generate(self) -> miette::Result<bool> {
+        debug!(file = ?self.file_path, "Generating and writing code owners map file");
+
+        fs::write_file(&self.file_path, self.render()?)?;
+
+        Ok(true)
+    }
+}
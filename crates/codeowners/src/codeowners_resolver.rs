@@ -0,0 +1,106 @@
+use crate::codeowners_rule::CodeownersRule;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use miette::IntoDiagnostic;
+use moon_config::{CodeownersConfig, OwnersConfig};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::{debug, trace};
+
+/// The result of resolving ownership for a file.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CodeownersMatch {
+    /// Owners of the file, derived from the last matching rule.
+    pub owners: Vec<String>,
+
+    /// All rules that matched the file, in the order they were defined.
+    pub rules: Vec<CodeownersRule>,
+}
+
+impl CodeownersMatch {
+    pub fn is_owned(&self) -> bool {
+        !self.owners.is_empty()
+    }
+}
+
+/// Resolves the owners of files using the same rules, in the same order,
+/// as the generated `CODEOWNERS` file. Like `CODEOWNERS`, the last matching
+/// rule takes precedence.
+pub struct CodeownersResolver {
+    rules: Vec<(CodeownersRule, Gitignore)>,
+    workspace_root: PathBuf,
+}
+
+impl CodeownersResolver {
+    pub fn new(workspace_root: &Path) -> CodeownersResolver {
+        debug!("Aggregating code owners rules");
+
+        CodeownersResolver {
+            rules: vec![],
+            workspace_root: workspace_root.to_path_buf(),
+        }
+    }
+
+    pub fn add_project_entry(
+        &mut self,
+        id: &str,
+        source: &str,
+        config: &OwnersConfig,
+    ) -> miette::Result<()> {
+        if config.paths.is_empty() {
+            return Ok(());
+        }
+
+        trace!(project_id = id, source, "Adding project rules");
+
+        for rule in CodeownersRule::from_project(id, source, config) {
+            self.add_rule(rule)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn add_workspace_entries(&mut self, config: &CodeownersConfig) -> miette::Result<()> {
+        if config.global_paths.is_empty() {
+            return Ok(());
+        }
+
+        trace!("Adding workspace rules");
+
+        for rule in CodeownersRule::from_workspace(config) {
+            self.add_rule(rule)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_rules(&self) -> Vec<&CodeownersRule> {
+        self.rules.iter().map(|(rule, _)| rule).collect()
+    }
+
+    /// Resolve the owners of a file, relative from the workspace root.
+    pub fn resolve(&self, file: &str) -> CodeownersMatch {
+        let file = file.trim_start_matches('/');
+        let mut result = CodeownersMatch::default();
+
+        for (rule, matcher) in &self.rules {
+            if matcher.matched_path_or_any_parents(file, false).is_ignore() {
+                result.rules.push(rule.to_owned());
+            }
+        }
+
+        if let Some(last) = result.rules.last() {
+            result.owners = last.owners.clone();
+        }
+
+        result
+    }
+
+    fn add_rule(&mut self, rule: CodeownersRule) -> miette::Result<()> {
+        let mut builder = GitignoreBuilder::new(&self.workspace_root);
+        builder.add_line(None, &rule.pattern).into_diagnostic()?;
+
+        self.rules.push((rule, builder.build().into_diagnostic()?));
+
+        Ok(())
+    }
+}
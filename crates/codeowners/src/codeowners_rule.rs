@@ -0,0 +1,79 @@
+use moon_config::{CodeownersConfig, OwnersConfig, OwnersPaths};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub(crate) fn format_codeowners_path(path: PathBuf) -> String {
+    path.to_string_lossy()
+        // Always use forward slashes
+        .replace('\\', "/")
+        // Escape spaces
+        .replace(' ', "\\ ")
+}
+
+/// A single ownership rule, as it would be rendered in a `CODEOWNERS` file.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CodeownersRule {
+    /// The path pattern, relative from the workspace root.
+    pub pattern: String,
+
+    /// Owners of files that match the pattern.
+    pub owners: Vec<String>,
+
+    /// The project the rule was defined in, or `None` for workspace rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+impl CodeownersRule {
+    /// Create rules from a project's `owners` setting. Paths without explicit
+    /// owners will fallback to the default owner.
+    pub fn from_project(id: &str, source: &str, config: &OwnersConfig) -> Vec<CodeownersRule> {
+        let root = PathBuf::from("/").join(source);
+        let default_owners = config
+            .default_owner
+            .as_ref()
+            .map(|owner| vec![owner.to_owned()])
+            .unwrap_or_default();
+
+        let create_rule = |path: &String, owners: Vec<String>| CodeownersRule {
+            pattern: format_codeowners_path(root.join(path)),
+            owners,
+            project: Some(id.to_owned()),
+        };
+
+        match &config.paths {
+            OwnersPaths::List(paths) => paths
+                .iter()
+                .map(|path| create_rule(path, default_owners.clone()))
+                .collect(),
+            OwnersPaths::Map(map) => map
+                .iter()
+                .map(|(path, owners)| {
+                    create_rule(
+                        path,
+                        if owners.is_empty() {
+                            default_owners.clone()
+                        } else {
+                            owners.to_owned()
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Create rules from the workspace `codeowners.globalPaths` setting.
+    /// Paths without owners are ignored.
+    pub fn from_workspace(config: &CodeownersConfig) -> Vec<CodeownersRule> {
+        config
+            .global_paths
+            .iter()
+            .filter(|(_, owners)| !owners.is_empty())
+            .map(|(path, owners)| CodeownersRule {
+                pattern: format_codeowners_path(PathBuf::from(path)),
+                owners: owners.to_owned(),
+                project: None,
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,12 @@
 mod codeowners_generator;
 mod codeowners_hash;
+mod codeowners_map;
+mod codeowners_resolver;
+mod codeowners_rule;
+pub mod providers;
 
 pub use codeowners_generator::*;
 pub use codeowners_hash::*;
+pub use codeowners_map::*;
+pub use codeowners_resolver::*;
+pub use codeowners_rule::*;
@@ -0,0 +1,29 @@
+use super::CodeownersProvider;
+use moon_config::OwnersConfig;
+
+/// Code Owners for Bitbucket (3rd-party app).
+/// https://marketplace.atlassian.com/apps/1218598/code-owners-for-bitbucket
+pub struct BitbucketProvider;
+
+impl CodeownersProvider for BitbucketProvider {
+    fn get_file_path(&self) -> &str {
+        "CODEOWNERS"
+    }
+
+    fn render_project_header(
+        &self,
+        _id: &str,
+        config: &OwnersConfig,
+        required_approvals: u8,
+    ) -> Vec<String> {
+        match &config.default_owner {
+            Some(default_owner) if required_approvals > 0 => {
+                vec![format!(
+                    "Check({} >= {})",
+                    default_owner, required_approvals
+                )]
+            }
+            _ => vec![],
+        }
+    }
+}
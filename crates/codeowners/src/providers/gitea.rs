@@ -0,0 +1,139 @@
+use super::CodeownersProvider;
+use crate::codeowners_rule::CodeownersRule;
+use moon_config::OwnersConfig;
+
+/// Gitea, and Forgejo (a fork of Gitea), match paths using regular expressions
+/// instead of globs, so patterns must be converted.
+/// https://docs.gitea.com/usage/code-owners
+pub struct GiteaProvider {
+    file_path: &'static str,
+}
+
+impl GiteaProvider {
+    pub fn gitea() -> Self {
+        Self {
+            file_path: ".gitea/CODEOWNERS",
+        }
+    }
+
+    pub fn forgejo() -> Self {
+        Self {
+            file_path: ".forgejo/CODEOWNERS",
+        }
+    }
+}
+
+impl CodeownersProvider for GiteaProvider {
+    fn get_file_path(&self) -> &str {
+        self.file_path
+    }
+
+    fn render_rule(&self, rule: &CodeownersRule, _config: Option<&OwnersConfig>) -> String {
+        format!(
+            "{} {}",
+            escape_token(&convert_glob_to_regex(&rule.pattern)),
+            rule.owners.join(" ")
+        )
+    }
+}
+
+/// Convert a `CODEOWNERS` (gitignore-like) glob into a regular expression.
+/// Gitea anchors the expression to the start and end of the relative file path.
+pub fn convert_glob_to_regex(pattern: &str) -> String {
+    let (anchored, pattern) = match pattern.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        // Patterns with a separator in the middle are relative from the root
+        None => (pattern.trim_end_matches('/').contains('/'), pattern),
+    };
+    let is_dir = pattern.ends_with('/');
+    let mut chars = pattern.trim_end_matches('/').chars().peekable();
+    let mut in_braces = false;
+    let mut regex = String::new();
+
+    if !anchored {
+        regex.push_str("(.*/)?");
+    }
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '*' => {
+                if chars.next_if_eq(&'*').is_none() {
+                    regex.push_str("[^/]*");
+                } else if chars.next_if_eq(&'/').is_some() {
+                    regex.push_str("(.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '?' => {
+                regex.push_str("[^/]");
+            }
+            '{' => {
+                in_braces = true;
+                regex.push('(');
+            }
+            '}' if in_braces => {
+                in_braces = false;
+                regex.push(')');
+            }
+            ',' if in_braces => {
+                regex.push('|');
+            }
+            '[' => {
+                regex.push('[');
+
+                if chars.next_if_eq(&'!').is_some() {
+                    regex.push('^');
+                }
+
+                for class_ch in chars.by_ref() {
+                    regex.push(class_ch);
+
+                    if class_ch == ']' {
+                        break;
+                    }
+                }
+            }
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    push_literal(&mut regex, escaped);
+                }
+            }
+            _ => {
+                push_literal(&mut regex, ch);
+            }
+        };
+    }
+
+    // Directories match all nested files, while other patterns
+    // may match either a file or a directory
+    regex.push_str(if is_dir { "/.*" } else { "(/.*)?" });
+    regex
+}
+
+fn push_literal(regex: &mut String, ch: char) {
+    if matches!(
+        ch,
+        '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$' | '\\'
+    ) {
+        regex.push('\\');
+    }
+
+    regex.push(ch);
+}
+
+// Gitea tokenizes each line before compiling the expression,
+// so backslashes, spaces, and comments must be escaped
+fn escape_token(value: &str) -> String {
+    let mut token = String::with_capacity(value.len());
+
+    for ch in value.chars() {
+        if matches!(ch, '\\' | ' ' | '#') {
+            token.push('\\');
+        }
+
+        token.push(ch);
+    }
+
+    token
+}
@@ -0,0 +1,10 @@
+use super::CodeownersProvider;
+
+/// https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/about-code-owners
+pub struct GitHubProvider;
+
+impl CodeownersProvider for GitHubProvider {
+    fn get_file_path(&self) -> &str {
+        ".github/CODEOWNERS"
+    }
+}
@@ -0,0 +1,44 @@
+use super::CodeownersProvider;
+use crate::codeowners_rule::CodeownersRule;
+use moon_config::{OwnersConfig, OwnersPaths};
+
+/// https://docs.gitlab.com/ee/user/project/codeowners/reference.html
+pub struct GitLabProvider;
+
+impl CodeownersProvider for GitLabProvider {
+    fn get_file_path(&self) -> &str {
+        ".gitlab/CODEOWNERS"
+    }
+
+    fn render_project_header(
+        &self,
+        id: &str,
+        config: &OwnersConfig,
+        required_approvals: u8,
+    ) -> Vec<String> {
+        let mut header = format!("[{id}]");
+
+        if config.optional {
+            header = format!("^{header}")
+        }
+
+        if required_approvals > 0 {
+            header = format!("{header}[{}]", required_approvals);
+        }
+
+        if matches!(config.paths, OwnersPaths::List(_)) {
+            header = format!("{header} {}", config.default_owner.as_ref().unwrap());
+        }
+
+        vec![header]
+    }
+
+    fn render_rule(&self, rule: &CodeownersRule, config: Option<&OwnersConfig>) -> String {
+        // Default owners are inherited from the section header
+        if config.is_some_and(|cfg| matches!(cfg.paths, OwnersPaths::List(_))) {
+            return rule.pattern.clone();
+        }
+
+        format!("{} {}", rule.pattern, rule.owners.join(" "))
+    }
+}
@@ -0,0 +1,49 @@
+mod bitbucket;
+mod gitea;
+mod github;
+mod gitlab;
+mod other;
+
+pub use bitbucket::*;
+pub use gitea::*;
+pub use github::*;
+pub use gitlab::*;
+pub use other::*;
+
+use crate::codeowners_rule::CodeownersRule;
+use moon_config::{OwnersConfig, VcsProvider};
+
+/// Renders ownership rules in the `CODEOWNERS` syntax of a specific provider.
+pub trait CodeownersProvider: Send + Sync {
+    /// Path to the `CODEOWNERS` file, relative from the workspace root.
+    fn get_file_path(&self) -> &str;
+
+    /// Render lines that precede a project's rules, after its comment header.
+    fn render_project_header(
+        &self,
+        _id: &str,
+        _config: &OwnersConfig,
+        _required_approvals: u8,
+    ) -> Vec<String> {
+        vec![]
+    }
+
+    /// Render a single rule. Project rules will also receive the project's
+    /// `owners` configuration.
+    fn render_rule(&self, rule: &CodeownersRule, _config: Option<&OwnersConfig>) -> String {
+        format!("{} {}", rule.pattern, rule.owners.join(" "))
+    }
+}
+
+pub type BoxedCodeownersProvider = Box<dyn CodeownersProvider>;
+
+pub fn create_codeowners_provider(provider: VcsProvider) -> BoxedCodeownersProvider {
+    match provider {
+        VcsProvider::Bitbucket => Box::new(BitbucketProvider),
+        VcsProvider::Forgejo => Box::new(GiteaProvider::forgejo()),
+        VcsProvider::Gitea => Box::new(GiteaProvider::gitea()),
+        VcsProvider::GitHub => Box::new(GitHubProvider),
+        VcsProvider::GitLab => Box::new(GitLabProvider),
+        VcsProvider::Other => Box::new(OtherProvider),
+    }
+}
@@ -0,0 +1,10 @@
+use super::CodeownersProvider;
+
+/// A basic `CODEOWNERS` syntax for unsupported providers.
+pub struct OtherProvider;
+
+impl CodeownersProvider for OtherProvider {
+    fn get_file_path(&self) -> &str {
+        "CODEOWNERS"
+    }
+}
@@ -71,3 +71,127 @@ fn generates_other() {
 
     assert_snapshot!(fs::read_to_string(sandbox.path().join("CODEOWNERS")).unwrap());
 }
+
+#[test]
+fn generates_gitea() {
+    let sandbox = load_generator(VcsProvider::Gitea);
+
+    assert_snapshot!(fs::read_to_string(sandbox.path().join(".gitea/CODEOWNERS")).unwrap());
+}
+
+#[test]
+fn generates_forgejo() {
+    let sandbox = load_generator(VcsProvider::Forgejo);
+
+    assert_snapshot!(fs::read_to_string(sandbox.path().join(".forgejo/CODEOWNERS")).unwrap());
+}
+
+mod ownership_map {
+    use super::*;
+    use moon_codeowners::{CodeownersMap, CodeownersMapGenerator};
+    use starbase_utils::json;
+
+    #[test]
+    fn generates_json() {
+        let sandbox = create_empty_sandbox();
+        let config_loader = ConfigLoader::default();
+
+        sandbox.create_file(
+            ".moon/workspace.yml",
+            fs::read_to_string(locate_fixture("workspace").join("workspace.yml")).unwrap(),
+        );
+
+        let mut generator = CodeownersMapGenerator::new(sandbox.path());
+        let workspace_config = config_loader.load_workspace_config(sandbox.path()).unwrap();
+
+        generator
+            .add_workspace_entries(&workspace_config.codeowners)
+            .unwrap();
+
+        for project_fixture in ["list-paths", "map-paths", "no-paths"] {
+            sandbox.create_file(
+                format!("{}/moon.yml", project_fixture),
+                fs::read_to_string(locate_fixture(project_fixture).join("moon.yml")).unwrap(),
+            );
+
+            let project_config = config_loader
+                .load_project_config_from_source(sandbox.path(), project_fixture)
+                .unwrap();
+
+            generator
+                .add_project_entry(
+                    project_fixture,
+                    project_fixture,
+                    &project_config.owners,
+                    &workspace_config.codeowners,
+                )
+                .unwrap();
+        }
+
+        generator.generate().unwrap();
+
+        let content = fs::read_to_string(sandbox.path().join(".moon/codeowners.json")).unwrap();
+        let map: CodeownersMap = json::parse(&content).unwrap();
+
+        assert_eq!(map.rules.len(), 7);
+        assert_eq!(
+            map.projects.keys().collect::<Vec<_>>(),
+            ["list-paths", "map-paths"]
+        );
+
+        assert_snapshot!(content);
+    }
+}
+
+mod gitea_patterns {
+    use moon_codeowners::providers::convert_glob_to_regex;
+    use regex::Regex;
+
+    fn is_match(pattern: &str, file: &str) -> bool {
+        Regex::new(&format!("^{}$", convert_glob_to_regex(pattern)))
+            .unwrap()
+            .is_match(file)
+    }
+
+    #[test]
+    fn matches_any_depth_without_separator() {
+        assert!(is_match("*", "file.txt"));
+        assert!(is_match("*", "nested/file.txt"));
+        assert!(is_match("*.rs", "src/lib.rs"));
+        assert!(!is_match("*.rs", "src/lib.ts"));
+    }
+
+    #[test]
+    fn anchors_to_root_with_leading_separator() {
+        assert!(is_match("/*.js", "index.js"));
+        assert!(!is_match("/*.js", "nested/index.js"));
+        assert!(is_match("/config", "config/app.yml"));
+        assert!(!is_match("/config", "nested/config/app.yml"));
+    }
+
+    #[test]
+    fn matches_nested_files_of_directories() {
+        assert!(is_match("/map-paths/config/", "map-paths/config/app.yml"));
+        assert!(is_match(
+            "/map-paths/config/",
+            "map-paths/config/nested/app.yml"
+        ));
+        assert!(!is_match("/map-paths/config/", "map-paths/config"));
+    }
+
+    #[test]
+    fn supports_globstars_and_alternates() {
+        assert!(is_match("/src/**/*.{js,ts}", "src/index.ts"));
+        assert!(is_match("/src/**/*.{js,ts}", "src/a/b/index.js"));
+        assert!(!is_match("/src/**/*.{js,ts}", "src/index.rs"));
+        assert!(is_match("/src/file?.[!a]s", "src/file1.rs"));
+        assert!(!is_match("/src/file?.[!a]s", "src/file1.as"));
+    }
+
+    #[test]
+    fn escapes_special_characters() {
+        assert!(is_match("/path/with\\ space/", "path/with space/file"));
+        assert!(is_match("/a+b.txt", "a+b.txt"));
+        assert!(!is_match("/a+b.txt", "aab.txt"));
+    }
+}
@@ -0,0 +1,128 @@
+use moon_codeowners::CodeownersResolver;
+use moon_config::ConfigLoader;
+use starbase_sandbox::{create_empty_sandbox, locate_fixture, Sandbox};
+use std::fs;
+
+fn load_resolver() -> (Sandbox, CodeownersResolver) {
+    let sandbox = create_empty_sandbox();
+    let config_loader = ConfigLoader::default();
+
+    sandbox.create_file(
+        ".moon/workspace.yml",
+        fs::read_to_string(locate_fixture("workspace").join("workspace.yml")).unwrap(),
+    );
+
+    let mut resolver = CodeownersResolver::new(sandbox.path());
+    let workspace_config = config_loader.load_workspace_config(sandbox.path()).unwrap();
+
+    resolver
+        .add_workspace_entries(&workspace_config.codeowners)
+        .unwrap();
+
+    for project_fixture in ["custom-groups", "list-paths", "map-paths", "no-paths"] {
+        sandbox.create_file(
+            format!("{}/moon.yml", project_fixture),
+            fs::read_to_string(locate_fixture(project_fixture).join("moon.yml")).unwrap(),
+        );
+
+        let project_config = config_loader
+            .load_project_config_from_source(sandbox.path(), project_fixture)
+            .unwrap();
+
+        resolver
+            .add_project_entry(project_fixture, project_fixture, &project_config.owners)
+            .unwrap();
+    }
+
+    (sandbox, resolver)
+}
+
+#[test]
+fn returns_no_owners_without_rules() {
+    let sandbox = create_empty_sandbox();
+    let resolver = CodeownersResolver::new(sandbox.path());
+    let result = resolver.resolve("src/index.ts");
+
+    assert!(!result.is_owned());
+    assert!(result.rules.is_empty());
+}
+
+#[test]
+fn aggregates_rules_in_order() {
+    let (_sandbox, resolver) = load_resolver();
+
+    assert_eq!(
+        resolver
+            .get_rules()
+            .into_iter()
+            .map(|rule| rule.pattern.as_str())
+            .collect::<Vec<_>>(),
+        [
+            "*",
+            "/config",
+            "/*.js",
+            "/custom-groups/path/with\\ space/",
+            "/list-paths/*.{js,ts,tsx}",
+            "/list-paths/*.config.js",
+            "/map-paths/*.rs",
+            "/map-paths/config/",
+        ]
+    );
+}
+
+#[test]
+fn resolves_workspace_rules() {
+    let (_sandbox, resolver) = load_resolver();
+
+    assert_eq!(resolver.resolve("README.md").owners, ["@admins"]);
+    assert_eq!(resolver.resolve("config/file.yml").owners, ["@infra"]);
+    assert_eq!(resolver.resolve("index.js").owners, ["@infra", "@frontend"]);
+    assert_eq!(resolver.resolve("nested/index.js").owners, ["@admins"]);
+}
+
+#[test]
+fn resolves_project_rules() {
+    let (_sandbox, resolver) = load_resolver();
+
+    assert_eq!(
+        resolver.resolve("list-paths/index.tsx").owners,
+        ["@frontend"]
+    );
+    assert_eq!(
+        resolver.resolve("map-paths/lib.rs").owners,
+        ["@rust-dev", "@api-team"]
+    );
+    assert_eq!(
+        resolver
+            .resolve("custom-groups/path/with space/file.txt")
+            .owners,
+        ["@@@group"]
+    );
+}
+
+#[test]
+fn falls_back_to_default_owner() {
+    let (_sandbox, resolver) = load_resolver();
+
+    assert_eq!(
+        resolver.resolve("map-paths/config/app.yml").owners,
+        ["@backend"]
+    );
+}
+
+#[test]
+fn last_matching_rule_wins() {
+    let (_sandbox, resolver) = load_resolver();
+    let result = resolver.resolve("list-paths/index.js");
+
+    assert_eq!(result.owners, ["@frontend"]);
+    assert_eq!(
+        result
+            .rules
+            .iter()
+            .map(|rule| rule.pattern.as_str())
+            .collect::<Vec<_>>(),
+        ["*", "/list-paths/*.{js,ts,tsx}"]
+    );
+    assert_eq!(result.rules[1].project.as_deref(), Some("list-paths"));
+}
@@ -0,0 +1,143 @@
+use serde::Serialize;
+use starbase_utils::{fs, json};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+thread_local! {
+    static CURRENT_WRITER: RefCell<Option<FileWriter>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PendingWrite {
+    /// Contents of the file before it was first written (`None` if it did not exist).
+    pub current: Option<String>,
+
+    /// Contents that would be written (`None` if the file would be removed).
+    pub next: Option<String>,
+}
+
+/// Writes files to the file system, or when only checking (via `--check`),
+/// captures the writes in memory so that they can be reported instead.
+/// Clones share the same captured writes.
+#[derive(Clone, Debug, Default)]
+pub struct FileWriter {
+    check_only: bool,
+    pending: Arc<Mutex<BTreeMap<PathBuf, PendingWrite>>>,
+}
+
+impl FileWriter {
+    pub fn new(check_only: bool) -> Self {
+        Self {
+            check_only,
+            ..Default::default()
+        }
+    }
+
+    /// Return the writer of the current scope (via [`FileWriter::scope`]),
+    /// or a writer that writes directly to the file system.
+    pub fn current() -> Self {
+        CURRENT_WRITER.with(|current| current.borrow().clone().unwrap_or_default())
+    }
+
+    pub fn is_check_only(&self) -> bool {
+        self.check_only
+    }
+
+    /// Run the function with this writer as the current writer, so that
+    /// code without access to a writer (like legacy config caches) can use it.
+    pub fn scope<T>(&self, func: impl FnOnce() -> T) -> T {
+        let previous = CURRENT_WRITER.with(|current| current.replace(Some(self.clone())));
+        let result = func();
+
+        CURRENT_WRITER.with(|current| current.replace(previous));
+
+        result
+    }
+
+    /// Take all writes that were captured while only checking,
+    /// excluding files whose contents would not have changed.
+    pub fn take_pending_writes(&self) -> BTreeMap<PathBuf, PendingWrite> {
+        let writes = std::mem::take(&mut *self.pending.lock().unwrap());
+
+        writes
+            .into_iter()
+            .filter(|(_, write)| write.current != write.next)
+            .collect()
+    }
+
+    fn capture(&self, path: &Path, next: Option<String>) -> miette::Result<()> {
+        let mut writes = self.pending.lock().unwrap();
+
+        if let Some(write) = writes.get_mut(path) {
+            write.next = next;
+        } else {
+            writes.insert(
+                path.to_path_buf(),
+                PendingWrite {
+                    current: if path.exists() {
+                        Some(fs::read_file(path)?)
+                    } else {
+                        None
+                    },
+                    next,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Write the contents to the file, or capture it when only checking.
+    pub fn write_file(&self, path: &Path, content: impl AsRef<str>) -> miette::Result<()> {
+        if self.check_only {
+            return self.capture(path, Some(content.as_ref().to_owned()));
+        }
+
+        fs::write_file(path, content.as_ref())?;
+
+        Ok(())
+    }
+
+    /// Format and write the JSON to the file while respecting `.editorconfig`,
+    /// or capture it when only checking.
+    pub fn write_json_file_with_config<D: Serialize>(
+        &self,
+        path: &Path,
+        data: &D,
+    ) -> miette::Result<()> {
+        let editor_config = fs::get_editor_config_props(path)?;
+        let mut content = json::format_with_identation(data, &editor_config.indent)?;
+
+        editor_config.apply_eof(&mut content);
+
+        self.write_file(path, content)
+    }
+
+    /// Remove the file, or capture it when only checking.
+    pub fn remove_file(&self, path: &Path) -> miette::Result<()> {
+        if self.check_only {
+            return self.capture(path, None);
+        }
+
+        fs::remove_file(path)?;
+
+        Ok(())
+    }
+
+    /// Rename the file, or capture it as a removal and a write when only checking.
+    pub fn rename_file(&self, from: &Path, to: &Path) -> miette::Result<()> {
+        if self.check_only {
+            let content = fs::read_file(from)?;
+
+            self.capture(to, Some(content))?;
+
+            return self.capture(from, None);
+        }
+
+        fs::rename(from, to)?;
+
+        Ok(())
+    }
+}
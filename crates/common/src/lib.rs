@@ -1,6 +1,8 @@
 pub mod consts;
 #[cfg(not(target_arch = "wasm32"))]
 mod env;
+#[cfg(not(target_arch = "wasm32"))]
+mod file_writer;
 mod helpers;
 mod id;
 mod macros;
@@ -9,6 +11,8 @@ pub mod serde;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use env::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use file_writer::*;
 pub use helpers::*;
 pub use id::*;
 pub use starbase_styles::*;
@@ -16,9 +16,24 @@ static NPM: Lazy<Regex> = Lazy::new(|| {
         .unwrap()
 });
 
+static CHECKSUM: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^(sha256:)?(?<hash>[a-f0-9]{64})$").unwrap());
+
+const ARCHIVE_EXTENSIONS: [&str; 4] = [".tar.gz", ".tgz", ".tar", ".zip"];
+
+fn is_archive(value: &str) -> bool {
+    let value = value.to_lowercase();
+
+    ARCHIVE_EXTENSIONS.iter().any(|ext| value.ends_with(ext))
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(untagged, try_from = "String", into = "String")]
 pub enum TemplateLocator {
+    Archive {
+        url: String,
+        checksum: Option<String>,
+    },
     File {
         path: FilePath,
     },
@@ -35,6 +50,10 @@ pub enum TemplateLocator {
 impl fmt::Display for TemplateLocator {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TemplateLocator::Archive { url, checksum } => match checksum {
+                Some(checksum) => write!(f, "{url}#sha256:{checksum}"),
+                None => write!(f, "{url}"),
+            },
             TemplateLocator::File { path } => write!(f, "file://{path}"),
             TemplateLocator::Git {
                 remote_url,
@@ -63,7 +82,37 @@ impl FromStr for TemplateLocator {
                 inner_value = &value[index + 3..];
             }
 
+            let (location, fragment) = match inner_value.split_once('#') {
+                Some((location, fragment)) => (location, Some(fragment)),
+                None => (inner_value, None),
+            };
+
+            let parse_checksum = |fragment: Option<&str>| {
+                match fragment {
+                Some(fragment) => match CHECKSUM.captures(&fragment.to_lowercase()) {
+                    Some(result) => Ok(Some(result.name("hash").unwrap().as_str().to_owned())),
+                    None => Err(ParseError::new(format!(
+                        "Invalid archive template locator checksum, must be a SHA-256 hash in the format of `{protocol}://url#sha256:hash`"
+                    ))),
+                },
+                None => Ok(None),
+            }
+            };
+
             match protocol {
+                "http" | "https" => {
+                    if is_archive(location) {
+                        return Ok(TemplateLocator::Archive {
+                            url: format!("{protocol}://{location}"),
+                            checksum: parse_checksum(fragment)?,
+                        });
+                    }
+
+                    return Err(ParseError::new(format!(
+                        "Invalid archive template locator, must end with one of {}",
+                        ARCHIVE_EXTENSIONS.join(", ")
+                    )));
+                }
                 "git" | "git+http" | "git+https" => {
                     if let Some(result) = GIT.captures(inner_value) {
                         return Ok(TemplateLocator::Git {
@@ -90,9 +139,16 @@ impl FromStr for TemplateLocator {
                     )));
                 }
                 "file" => {
+                    if is_archive(location) {
+                        return Ok(TemplateLocator::Archive {
+                            url: format!("file://{location}"),
+                            checksum: parse_checksum(fragment)?,
+                        });
+                    }
+
                     return Ok(TemplateLocator::File {
                         path: FilePath::from_str(inner_value)?,
-                    })
+                    });
                 }
                 other => {
                     return Err(ParseError::new(format!(
@@ -1,9 +1,10 @@
 use moon_common::Id;
+use regex::Regex;
 use rustc_hash::FxHashMap;
 use schematic::{validate, Config, ValidateError};
 
 macro_rules! var_setting {
-    ($name:ident, $ty:ty) => {
+    ($name:ident, $ty:ty $(, { $($field:tt)* })?) => {
         /// Configuration for a template variable.
         #[derive(Clone, Config, Debug, Eq, PartialEq)]
         pub struct $name {
@@ -22,13 +23,108 @@ macro_rules! var_setting {
 
             /// Marks the variable as required, and will not accept an empty value.
             pub required: Option<bool>,
+
+            $($($field)*)?
         }
     };
 }
 
+fn validate_pattern<D, C>(
+    pattern: &str,
+    _data: &D,
+    _ctx: &C,
+    _finalize: bool,
+) -> Result<(), ValidateError> {
+    if let Err(error) = Regex::new(pattern) {
+        return Err(ValidateError::new(format!(
+            "invalid regex pattern: {error}"
+        )));
+    }
+
+    Ok(())
+}
+
 var_setting!(TemplateVariableBoolSetting, bool);
-var_setting!(TemplateVariableNumberSetting, isize);
-var_setting!(TemplateVariableStringSetting, String);
+var_setting!(TemplateVariableNumberSetting, isize, {
+    /// The maximum value allowed.
+    pub max: Option<isize>,
+
+    /// A custom error message to display when validation fails.
+    pub message: Option<String>,
+
+    /// The minimum value allowed.
+    pub min: Option<isize>,
+});
+var_setting!(TemplateVariableStringSetting, String, {
+    /// The maximum number of characters allowed.
+    pub max_length: Option<usize>,
+
+    /// A custom error message to display when validation fails.
+    pub message: Option<String>,
+
+    /// The minimum number of characters allowed.
+    pub min_length: Option<usize>,
+
+    /// A regex pattern that the value must match.
+    #[setting(validate = validate_pattern)]
+    pub pattern: Option<String>,
+});
+
+impl TemplateVariableNumberSetting {
+    /// Validate the provided value against the configured constraints,
+    /// and return an error message if invalid.
+    pub fn validate_value(&self, value: isize) -> Result<(), String> {
+        let error = if self.min.is_some_and(|min| value < min) {
+            Some(format!("Must be at least {}", self.min.unwrap()))
+        } else if self.max.is_some_and(|max| value > max) {
+            Some(format!("Must be at most {}", self.max.unwrap()))
+        } else {
+            None
+        };
+
+        match error {
+            Some(error) => Err(self.message.clone().unwrap_or(error)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TemplateVariableStringSetting {
+    /// Validate the provided value against the configured constraints,
+    /// and return an error message if invalid.
+    pub fn validate_value(&self, value: &str) -> Result<(), String> {
+        let length = value.chars().count();
+
+        let error = if self.min_length.is_some_and(|min| length < min) {
+            Some(format!(
+                "Must be at least {} characters",
+                self.min_length.unwrap()
+            ))
+        } else if self.max_length.is_some_and(|max| length > max) {
+            Some(format!(
+                "Must be at most {} characters",
+                self.max_length.unwrap()
+            ))
+        } else if self
+            .pattern
+            .as_ref()
+            .and_then(|pattern| Regex::new(pattern).ok())
+            .is_some_and(|pattern| !pattern.is_match(value))
+        {
+            Some(format!(
+                "Must match the pattern {}",
+                self.pattern.as_ref().unwrap()
+            ))
+        } else {
+            None
+        };
+
+        match error {
+            Some(error) => Err(self.message.clone().unwrap_or(error)),
+            None => Ok(()),
+        }
+    }
+}
 
 #[derive(Clone, Config, Debug, Eq, PartialEq)]
 pub struct TemplateVariableEnumValueConfig {
@@ -216,6 +312,18 @@ impl TemplateVariable {
     }
 }
 
+/// Commands to run before and after a template is generated.
+#[derive(Clone, Config, Debug, Eq, PartialEq)]
+pub struct TemplateHooksConfig {
+    /// A list of commands or moon targets to run after files have been
+    /// generated, from within the destination.
+    pub post_generate: Vec<String>,
+
+    /// A list of commands or moon targets to run before files are
+    /// generated, from within the destination.
+    pub pre_generate: Vec<String>,
+}
+
 /// Configures a template and its files to be scaffolded.
 /// Docs: https://moonrepo.dev/docs/config/template
 #[derive(Clone, Config, Debug, PartialEq)]
@@ -237,9 +345,22 @@ pub struct TemplateConfig {
     /// Extends one or many other templates.
     pub extends: Vec<Id>,
 
+    /// A mapping of file globs (relative from the template root) to conditions,
+    /// rendered with variables. When a condition is falsy, all matching files
+    /// will be excluded from rendering and generation.
+    pub files: FxHashMap<String, String>,
+
+    /// Commands or moon targets to run before and after generating.
+    #[setting(nested)]
+    pub hooks: TemplateHooksConfig,
+
     /// Overrides the ID of the template, instead of using the folder name.
     pub id: Option<Id>,
 
+    /// Records the generated files and variables in a lockfile within the
+    /// destination, so that the template can be re-applied with `--update`.
+    pub lock: bool,
+
     /// A human-readable title for the template.
     #[setting(validate = validate::not_empty)]
     pub title: String,
@@ -25,6 +25,10 @@ cacheable!(
         /// How to order ownership rules within the generated file.
         pub order_by: CodeownersOrderBy,
 
+        /// Generates a JSON ownership map at `.moon/codeowners.json`, alongside
+        /// the `CODEOWNERS` file, for consumption by custom tooling.
+        pub ownership_map: bool,
+
         /// Bitbucket and GitLab only. The number of approvals required for the
         /// request to be satisfied. This will be applied to all paths.
         pub required_approvals: Option<u8>,
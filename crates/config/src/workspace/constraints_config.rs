@@ -1,15 +1,42 @@
 use moon_common::Id;
+use moon_target::Target;
 use rustc_hash::FxHashMap;
 use schematic::Config;
 
 /// Configures boundaries and constraints between projects.
 #[derive(Clone, Config, Debug, PartialEq)]
 pub struct ConstraintsConfig {
+    /// Forbids projects from depending on other projects. Requires a mapping
+    /// of project IDs or `#tag`s, to a list of banned project IDs or `#tag`s.
+    pub banned_dependencies: FxHashMap<String, Vec<String>>,
+
+    /// Forbids tasks from being depended on by other tasks. Requires a list
+    /// of targets, using the `:task`, `project:task`, or `#tag:task` scopes.
+    pub banned_task_dependencies: Vec<Target>,
+
     /// Enforces relationships between projects based on each project's
     /// `type` setting.
     #[setting(default = true)]
     pub enforce_project_type_relationships: bool,
 
+    /// Enforces that tasks that can run in CI do not depend on tasks
+    /// with the `runInCI` option disabled.
+    #[setting(default = true)]
+    pub enforce_run_in_ci_dependencies: bool,
+
+    /// Enforces architectural layers between projects based on each project's
+    /// `tags` setting. Requires an ordered list of tags, from the highest layer
+    /// to the lowest layer, where lower layers cannot depend on higher layers.
+    pub layers: Vec<Id>,
+
+    /// The maximum depth of a project's dependency chain, where direct
+    /// dependencies have a depth of 1.
+    pub max_dependency_depth: Option<usize>,
+
+    /// Requires projects to have specific tasks. Requires a mapping of
+    /// project types or `#tag`s, to a list of required task IDs.
+    pub required_tasks: FxHashMap<String, Vec<Id>>,
+
     /// Enforces relationships between projects based on each project's
     /// `tags` setting. Requires a mapping of tags, to acceptable tags.
     pub tag_relationships: FxHashMap<Id, Vec<Id>>,
@@ -2,6 +2,24 @@ use crate::portable_path::GlobPath;
 use moon_common::cacheable;
 use schematic::Config;
 
+cacheable!(
+    /// Configures aspects of the Dockerfile generation process.
+    #[derive(Clone, Config, Debug, Eq, PartialEq)]
+    pub struct DockerFileConfig {
+        /// Mount BuildKit caches for package manager stores and the moon
+        /// hydration cache, so that they persist between builds.
+        pub cache_mounts: bool,
+
+        /// Copy files with `COPY --link`, so that copied layers do not
+        /// depend on the layers before them.
+        pub copy_link: bool,
+
+        /// Install the toolchain in a separate stage, that is only rebuilt
+        /// when `.prototools` or toolchain configs change.
+        pub toolchain_stage: bool,
+    }
+);
+
 cacheable!(
     /// Configures aspects of the Docker pruning process.
     #[derive(Clone, Config, Debug, Eq, PartialEq)]
@@ -37,6 +55,10 @@ cacheable!(
     /// Configures our Docker integration.
     #[derive(Clone, Config, Debug, Eq, PartialEq)]
     pub struct DockerConfig {
+        /// Configures aspects of the Dockerfile generation process.
+        #[setting(nested)]
+        pub file: DockerFileConfig,
+
         /// Configures aspects of the Docker pruning process.
         #[setting(nested)]
         pub prune: DockerPruneConfig,
@@ -22,7 +22,9 @@ cacheable!(
         // #[setting(default = true)]
         pub strict_project_ids: bool,
 
-        /// Disallow task relationships with different `runInCI` options.
+        /// No longer used. Use the `constraints.enforceRunInCiDependencies`
+        /// setting instead.
+        #[deprecated]
         #[setting(default = true)]
         pub disallow_run_in_ci_mismatch: bool,
 
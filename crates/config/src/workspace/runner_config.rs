@@ -16,6 +16,11 @@ pub struct RunnerConfig {
     #[setting(default = "7 days")]
     pub cache_lifetime: String,
 
+    /// List of dependencies in root manifests (like `typescript` in `package.json`)
+    /// that are used by all projects without being declared. When changed, all
+    /// tasks that use the manifest as an input are marked as affected.
+    pub hoisted_dependencies: Vec<String>,
+
     /// Automatically inherit color settings for all tasks being ran.
     #[setting(default = true)]
     pub inherit_colors_for_piped_tasks: bool,
@@ -1,6 +1,20 @@
 use rustc_hash::FxHashMap;
 use schematic::{derive_enum, Config, ConfigEnum};
 
+derive_enum!(
+    /// The backend used to interact with the VCS.
+    #[derive(ConfigEnum, Copy, Default)]
+    pub enum VcsBackend {
+        /// Spawn the VCS binary for each query.
+        #[default]
+        Cli,
+
+        /// Read the repository in-process using gitoxide,
+        /// and fall back to the binary for unsupported queries.
+        Gitoxide,
+    }
+);
+
 derive_enum!(
     /// The VCS being utilized by the repository.
     #[derive(ConfigEnum, Copy, Default)]
@@ -17,6 +31,10 @@ derive_enum!(
     pub enum VcsProvider {
         Bitbucket,
 
+        Forgejo,
+
+        Gitea,
+
         #[default]
         #[serde(rename = "github")]
         GitHub,
@@ -41,6 +59,9 @@ derive_enum!(
 /// Configures the version control system (VCS).
 #[derive(Clone, Config, Debug, PartialEq)]
 pub struct VcsConfig {
+    /// The backend used to interact with the VCS.
+    pub backend: VcsBackend,
+
     /// The default branch / base.
     #[setting(default = "master")]
     pub default_branch: String,
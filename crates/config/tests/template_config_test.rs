@@ -14,7 +14,7 @@ mod template_config {
 
     #[test]
     #[should_panic(
-        expected = "unknown field `unknown`, expected one of `$schema`, `description`, `destination`, `extends`, `id`, `title`, `variables`"
+        expected = "unknown field `unknown`, expected one of `$schema`, `description`, `destination`, `extends`, `files`, `hooks`, `id`, `title`, `variables`"
     )]
     fn error_unknown_field() {
         test_load_config("template.yml", "unknown: 123", |path| {
@@ -75,6 +75,67 @@ mod template_config {
         }
     }
 
+    mod files {
+        use super::*;
+
+        #[test]
+        fn loads_conditions() {
+            let config = test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+files:
+  'docker/**': '{{ withDocker }}'
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.files,
+                FxHashMap::from_iter([("docker/**".into(), "{{ withDocker }}".into())])
+            );
+        }
+    }
+
+    mod hooks {
+        use super::*;
+        use moon_config::TemplateHooksConfig;
+
+        #[test]
+        fn loads_commands() {
+            let config = test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+hooks:
+  preGenerate: ['echo pre']
+  postGenerate: ['npm install', 'app:format']
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.hooks,
+                TemplateHooksConfig {
+                    post_generate: vec!["npm install".into(), "app:format".into()],
+                    pre_generate: vec!["echo pre".into()],
+                }
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "invalid type: string \"echo\", expected a sequence")]
+        fn invalid_type() {
+            test_load_config(
+                "template.yml",
+                "title: title\ndescription: description\nhooks:\n  postGenerate: echo",
+                load_config_from_root,
+            );
+        }
+    }
+
     mod variables {
         use super::*;
         use moon_config::{
@@ -171,7 +232,38 @@ variables:
                     internal: false,
                     order: None,
                     prompt: Some("prompt".into()),
-                    required: Some(false)
+                    required: Some(false),
+                    ..Default::default()
+                })
+            );
+        }
+
+        #[test]
+        fn loads_number_validation() {
+            let config = test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+variables:
+  num:
+    type: number
+    default: 5
+    min: 1
+    max: 10
+    message: 'Pick 1-10'
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                *config.variables.get("num").unwrap(),
+                TemplateVariable::Number(TemplateVariableNumberSetting {
+                    default: 5,
+                    max: Some(10),
+                    message: Some("Pick 1-10".into()),
+                    min: Some(1),
+                    ..Default::default()
                 })
             );
         }
@@ -193,6 +285,53 @@ variables:
             );
         }
 
+        #[test]
+        fn loads_string_validation() {
+            let config = test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+variables:
+  name:
+    type: string
+    default: ''
+    minLength: 2
+    maxLength: 20
+    pattern: '^[a-z-]+$'
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                *config.variables.get("name").unwrap(),
+                TemplateVariable::String(TemplateVariableStringSetting {
+                    max_length: Some(20),
+                    min_length: Some(2),
+                    pattern: Some("^[a-z-]+$".into()),
+                    ..Default::default()
+                })
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "invalid regex pattern")]
+        fn invalid_string_pattern() {
+            test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+variables:
+  name:
+    type: string
+    default: ''
+    pattern: '[a-z'
+",
+                load_config_from_root,
+            );
+        }
+
         #[test]
         fn loads_string() {
             let config = test_load_config(
@@ -215,7 +354,8 @@ variables:
                     internal: false,
                     order: None,
                     prompt: None,
-                    required: None
+                    required: None,
+                    ..Default::default()
                 })
             );
         }
@@ -439,7 +579,8 @@ variables:
                                 internal: false,
                                 order: Some(1),
                                 prompt: Some("Why?".into()),
-                                required: None
+                                required: None,
+                                ..Default::default()
                             })
                         ),
                         (
@@ -449,7 +590,8 @@ variables:
                                 internal: true,
                                 order: None,
                                 prompt: Some("Why?".into()),
-                                required: None
+                                required: None,
+                                ..Default::default()
                             })
                         ),
                     ]),
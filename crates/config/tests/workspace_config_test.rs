@@ -3,8 +3,8 @@ mod utils;
 use httpmock::prelude::*;
 use moon_common::Id;
 use moon_config::{
-    ConfigLoader, ExtensionConfig, FilePath, TemplateLocator, VcsProvider, WorkspaceConfig,
-    WorkspaceProjects,
+    ConfigLoader, ExtensionConfig, FilePath, TemplateLocator, VcsBackend, VcsProvider,
+    WorkspaceConfig, WorkspaceProjects,
 };
 use proto_core::warpgate::UrlLocator;
 use rustc_hash::FxHashMap;
@@ -46,6 +46,15 @@ mod workspace_config {
         assert!(config.version_constraint.is_none());
     }
 
+    #[test]
+    fn supports_deprecated_experiments() {
+        test_load_config(
+            FILENAME,
+            "experiments:\n  disallowRunInCiMismatch: false",
+            load_config_from_root,
+        );
+    }
+
     mod extends {
         use super::*;
 
@@ -298,6 +307,7 @@ projects:
 
     mod constraints {
         use super::*;
+        use moon_target::Target;
 
         #[test]
         fn loads_defaults() {
@@ -307,6 +317,68 @@ projects:
 
             assert!(config.constraints.enforce_project_type_relationships);
             assert!(config.constraints.tag_relationships.is_empty());
+            assert!(config.constraints.banned_dependencies.is_empty());
+            assert!(config.constraints.layers.is_empty());
+            assert_eq!(config.constraints.max_dependency_depth, None);
+            assert!(config.constraints.required_tasks.is_empty());
+            assert!(config.constraints.banned_task_dependencies.is_empty());
+        }
+
+        #[test]
+        fn can_set_dependency_rules() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+constraints:
+  bannedDependencies:
+    '#frontend': ['#backend', 'legacy']
+  layers: ['app', 'util']
+  maxDependencyDepth: 3
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.constraints.banned_dependencies,
+                FxHashMap::from_iter([(
+                    "#frontend".to_owned(),
+                    vec!["#backend".to_owned(), "legacy".to_owned()]
+                )])
+            );
+            assert_eq!(
+                config.constraints.layers,
+                vec![Id::raw("app"), Id::raw("util")]
+            );
+            assert_eq!(config.constraints.max_dependency_depth, Some(3));
+        }
+
+        #[test]
+        fn can_set_task_rules() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+constraints:
+  bannedTaskDependencies: [':deploy', '#release:publish']
+  requiredTasks:
+    application: ['build', 'test']
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.constraints.banned_task_dependencies,
+                vec![
+                    Target::parse(":deploy").unwrap(),
+                    Target::parse("#release:publish").unwrap()
+                ]
+            );
+            assert_eq!(
+                config.constraints.required_tasks,
+                FxHashMap::from_iter([(
+                    "application".to_owned(),
+                    vec![Id::raw("build"), Id::raw("test")]
+                )])
+            );
         }
 
         #[test]
@@ -366,6 +438,38 @@ constraints:
         }
     }
 
+    mod docker {
+        use super::*;
+
+        #[test]
+        fn loads_defaults() {
+            let config = test_load_config(FILENAME, "docker: {}", load_config_from_root);
+
+            assert!(!config.docker.file.cache_mounts);
+            assert!(!config.docker.file.copy_link);
+            assert!(!config.docker.file.toolchain_stage);
+        }
+
+        #[test]
+        fn can_set_file_settings() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+docker:
+  file:
+    cacheMounts: true
+    copyLink: true
+    toolchainStage: true
+",
+                load_config_from_root,
+            );
+
+            assert!(config.docker.file.cache_mounts);
+            assert!(config.docker.file.copy_link);
+            assert!(config.docker.file.toolchain_stage);
+        }
+    }
+
     mod generator {
         use super::*;
 
@@ -478,6 +582,68 @@ generator:
             );
         }
 
+        #[test]
+        fn can_set_archive_locations() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+generator:
+  templates:
+    - https://artifacts.com/templates/v1.tar.gz
+    - https://artifacts.com/templates/v2.zip#sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08
+    - file://./archives/templates.tgz
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.generator.templates,
+                vec![
+                    TemplateLocator::Archive {
+                        url: "https://artifacts.com/templates/v1.tar.gz".into(),
+                        checksum: None,
+                    },
+                    TemplateLocator::Archive {
+                        url: "https://artifacts.com/templates/v2.zip".into(),
+                        checksum: Some(
+                            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+                                .into()
+                        ),
+                    },
+                    TemplateLocator::Archive {
+                        url: "file://./archives/templates.tgz".into(),
+                        checksum: None,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Invalid archive template locator, must end with one of")]
+        fn errors_for_non_archive_url() {
+            test_load_config(
+                FILENAME,
+                r"
+generator:
+  templates: ['https://artifacts.com/templates']
+",
+                load_config_from_root,
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Invalid archive template locator checksum")]
+        fn errors_for_invalid_archive_checksum() {
+            test_load_config(
+                FILENAME,
+                r"
+generator:
+  templates: ['https://artifacts.com/templates.zip#md5:abc']
+",
+                load_config_from_root,
+            );
+        }
+
         #[test]
         #[should_panic(
             expected = "Invalid Git template locator, must be in the format of `git://url#revision`"
@@ -636,12 +802,14 @@ notifier:
                 r"
 runner:
   cacheLifetime: 10 hours
+  hoistedDependencies: ['typescript']
   inheritColorsForPipedTasks: false
 ",
                 load_config_from_root,
             );
 
             assert_eq!(config.runner.cache_lifetime, "10 hours");
+            assert_eq!(config.runner.hoisted_dependencies, vec!["typescript"]);
             assert!(!config.runner.inherit_colors_for_piped_tasks);
         }
 
@@ -683,6 +851,7 @@ runner:
         fn loads_defaults() {
             let config = test_load_config(FILENAME, "vcs: {}", load_config_from_root);
 
+            assert_eq!(config.vcs.backend, VcsBackend::Cli);
             assert_eq!(config.vcs.default_branch, "master");
             assert_eq!(
                 config.vcs.remote_candidates,
@@ -696,13 +865,17 @@ runner:
                 FILENAME,
                 r"
 vcs:
+  backend: gitoxide
   defaultBranch: main
+  provider: forgejo
   remoteCandidates: [next]
 ",
                 load_config_from_root,
             );
 
+            assert_eq!(config.vcs.backend, VcsBackend::Gitoxide);
             assert_eq!(config.vcs.default_branch, "main");
+            assert_eq!(config.vcs.provider, VcsProvider::Forgejo);
             assert_eq!(config.vcs.remote_candidates, vec!["next".to_string()]);
         }
 
@@ -853,6 +1026,7 @@ extensions:
                         vec!["@admins".to_owned()]
                     )]),
                     order_by: CodeownersOrderBy::ProjectName,
+                    ownership_map: false,
                     required_approvals: Some(1),
                     sync_on_run: true,
                 }
@@ -860,7 +1034,12 @@ extensions:
             assert_eq!(
                 config.constraints,
                 ConstraintsConfig {
+                    banned_dependencies: FxHashMap::default(),
+                    banned_task_dependencies: vec![],
                     enforce_project_type_relationships: false,
+                    layers: vec![],
+                    max_dependency_depth: None,
+                    required_tasks: FxHashMap::default(),
                     tag_relationships: FxHashMap::from_iter([(
                         Id::raw("a"),
                         vec![Id::raw("b"), Id::raw("c")]
@@ -870,6 +1049,11 @@ extensions:
             assert_eq!(
                 config.docker,
                 DockerConfig {
+                    file: DockerFileConfig {
+                        cache_mounts: true,
+                        copy_link: true,
+                        toolchain_stage: true,
+                    },
                     prune: DockerPruneConfig {
                         delete_vendor_directories: false,
                         install_toolchain_deps: false
@@ -930,6 +1114,7 @@ extensions:
             assert_eq!(
                 config.vcs,
                 VcsConfig {
+                    backend: VcsBackend::Cli,
                     default_branch: "main".into(),
                     hooks: FxHashMap::from_iter([(
                         "pre-commit".into(),
@@ -0,0 +1,88 @@
+use std::collections::BTreeSet;
+
+pub const DOCKER_IGNORE_START: &str = "# Automatically generated by moon. DO NOT MODIFY!";
+pub const DOCKER_IGNORE_END: &str = "# End of moon generated entries";
+
+/// Normalize an ignore entry so that it can be compared against others,
+/// by removing leading and trailing slashes.
+pub fn normalize_ignore_entry(entry: &str) -> &str {
+    entry
+        .trim()
+        .trim_start_matches("./")
+        .trim_start_matches('/')
+        .trim_end_matches('/')
+}
+
+/// Generate the contents of a `.dockerignore` file, by replacing the moon
+/// generated block within the existing contents with the provided entries.
+/// Manual entries outside of the block are preserved as-is, and are not
+/// duplicated within the block. If the block has no end marker, only the
+/// start marker is replaced, so that manual entries after it are not lost.
+pub fn generate_docker_ignore<I, V>(existing: &str, entries: I) -> String
+where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+{
+    let mut before = vec![];
+    let mut after = vec![];
+    let mut in_block = false;
+    let mut found_block = false;
+    let existing_lines = existing.lines().collect::<Vec<_>>();
+
+    for (index, line) in existing_lines.iter().copied().enumerate() {
+        if line.trim() == DOCKER_IGNORE_START {
+            in_block = existing_lines[index + 1..]
+                .iter()
+                .any(|next| next.trim() == DOCKER_IGNORE_END);
+            found_block = true;
+        } else if line.trim() == DOCKER_IGNORE_END {
+            in_block = false;
+        } else if in_block {
+            continue;
+        } else if found_block {
+            after.push(line);
+        } else {
+            before.push(line);
+        }
+    }
+
+    let manual = before
+        .iter()
+        .chain(after.iter())
+        .map(|line| normalize_ignore_entry(line))
+        .collect::<BTreeSet<_>>();
+
+    let generated = entries
+        .into_iter()
+        .map(|entry| normalize_ignore_entry(entry.as_ref()).to_owned())
+        .filter(|entry| !entry.is_empty() && !manual.contains(entry.as_str()))
+        .collect::<BTreeSet<_>>();
+
+    // Trim blank lines around the block, so they don't accumulate
+    while before.last().is_some_and(|line| line.trim().is_empty()) {
+        before.pop();
+    }
+
+    while after.first().is_some_and(|line| line.trim().is_empty()) {
+        after.remove(0);
+    }
+
+    let mut lines = before.into_iter().map(String::from).collect::<Vec<_>>();
+
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines.push(DOCKER_IGNORE_START.into());
+    lines.extend(generated);
+    lines.push(DOCKER_IGNORE_END.into());
+
+    if !after.is_empty() {
+        lines.push(String::new());
+        lines.extend(after.into_iter().map(String::from));
+    }
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    output
+}
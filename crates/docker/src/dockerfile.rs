@@ -1,16 +1,60 @@
+use crate::dockerfile_error::DockerfileError;
 use miette::IntoDiagnostic;
 use moon_common::Id;
 use moon_target::Target;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use tera::{Context, Tera};
 
 #[derive(Debug, Default)]
-pub struct GenerateDockerfileOptions {
+pub struct GenerateDockerfileProject {
     pub build_task: Option<Target>,
+    pub id: Id,
+    pub start_task: Option<Target>,
+}
+
+#[derive(Debug, Default)]
+pub struct GenerateDockerfileOptions {
+    /// Mount BuildKit caches for package manager stores and moon's hydration cache.
+    pub cache_mounts: bool,
+    /// Use `COPY --link` for all copies.
+    pub copy_link: bool,
     pub disable_toolchain: bool,
     pub image: String,
-    pub project: Id,
+    /// Absolute paths to package manager stores within the image.
+    pub package_stores: Vec<String>,
+    pub projects: Vec<GenerateDockerfileProject>,
     pub prune: bool,
-    pub start_task: Option<Target>,
+    /// Workspace relative toolchain configs to copy into the toolchain stage.
+    pub toolchain_files: Vec<String>,
+    /// Install the toolchain in a separate stage.
+    pub toolchain_stage: bool,
+}
+
+#[derive(Serialize)]
+struct ProjectContext<'a> {
+    build_task: Option<&'a Target>,
+    id: &'a Id,
+    stage: String,
+    start_task: Option<&'a Target>,
+}
+
+// Stage names must be lowercase and cannot contain special characters
+fn create_stage_name(id: &Id) -> String {
+    id.as_str()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' || ch == '.' {
+                ch.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+fn create_cache_mount(target: &str) -> String {
+    format!("--mount=type=cache,target={target} ")
 }
 
 pub fn generate_dockerfile(mut options: GenerateDockerfileOptions) -> miette::Result<String> {
@@ -22,20 +66,72 @@ pub fn generate_dockerfile(mut options: GenerateDockerfileOptions) -> miette::Re
         options.disable_toolchain = true;
     }
 
-    let mut context = Context::new();
-    context.insert("disable_toolchain", &options.disable_toolchain);
-    context.insert("image", &options.image);
-    context.insert("project", &options.project);
-    context.insert("prune", &options.prune);
+    let mut projects = vec![];
+    let mut stages = BTreeMap::<String, &Id>::default();
+
+    for project in &options.projects {
+        let stage = create_stage_name(&project.id);
 
-    if let Some(task) = &options.build_task {
-        context.insert("build_task", task);
+        // Different IDs may map to the same stage name, like `webApp` and `webapp`
+        if let Some(other_id) = stages.insert(stage.clone(), &project.id) {
+            return Err(DockerfileError::StageNameCollision {
+                id: project.id.clone(),
+                other_id: other_id.to_owned(),
+                stage,
+            }
+            .into());
+        }
+
+        projects.push(ProjectContext {
+            build_task: project.build_task.as_ref(),
+            id: &project.id,
+            stage,
+            start_task: project.start_task.as_ref(),
+        });
     }
 
-    if let Some(task) = &options.start_task {
-        context.insert("start_task", task);
+    let mut setup_mounts = String::new();
+    let mut build_mounts = String::new();
+
+    if options.cache_mounts {
+        for store in &options.package_stores {
+            setup_mounts.push_str(&create_cache_mount(store));
+        }
+
+        build_mounts.push_str(&setup_mounts);
+
+        // Only mount the directories used for hydration, as mounting the entire
+        // moon cache would hide the state that `moon docker setup` writes
+        for dir in ["outputs", "hashes"] {
+            build_mounts.push_str(&create_cache_mount(&format!("/app/.moon/cache/{dir}")));
+        }
     }
 
+    // There's nothing to install when relying on system binaries
+    let toolchain_files = if options.toolchain_stage && !options.disable_toolchain {
+        options.toolchain_files
+    } else {
+        vec![]
+    };
+
+    let mut context = Context::new();
+    context.insert("build_mounts", &build_mounts);
+    context.insert(
+        "copy",
+        if options.copy_link {
+            "COPY --link"
+        } else {
+            "COPY"
+        },
+    );
+    context.insert("disable_toolchain", &options.disable_toolchain);
+    context.insert("image", &options.image);
+    context.insert("projects", &projects);
+    context.insert("prune", &options.prune);
+    context.insert("setup_mounts", &setup_mounts);
+    context.insert("syntax", &(options.cache_mounts || options.copy_link));
+    context.insert("toolchain_files", &toolchain_files);
+
     let result = Tera::one_off(
         include_str!("../templates/Dockerfile.tera"),
         &context,
@@ -0,0 +1,18 @@
+use miette::Diagnostic;
+use moon_common::{Id, Style, Stylize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Diagnostic)]
+pub enum DockerfileError {
+    #[diagnostic(
+        code(docker::dockerfile::stage_collision),
+        help = "Stage names are derived from project IDs, by lowercasing them and replacing special characters."
+    )]
+    #[error(
+        "Unable to generate Dockerfile, as projects {} and {} would both use the stage name {}.",
+        .id.style(Style::Id),
+        .other_id.style(Style::Id),
+        .stage.style(Style::Symbol),
+    )]
+    StageNameCollision { id: Id, other_id: Id, stage: String },
+}
@@ -1,3 +1,7 @@
+mod docker_ignore;
 mod dockerfile;
+mod dockerfile_error;
 
+pub use docker_ignore::*;
 pub use dockerfile::*;
+pub use dockerfile_error::*;
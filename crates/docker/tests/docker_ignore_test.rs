@@ -0,0 +1,60 @@
+use moon_docker::{generate_docker_ignore, DOCKER_IGNORE_END, DOCKER_IGNORE_START};
+
+mod docker_ignore {
+    use super::*;
+
+    #[test]
+    fn creates_block_when_empty() {
+        assert_eq!(
+            generate_docker_ignore("", [".moon/cache", "app/dist"]),
+            format!("{DOCKER_IGNORE_START}\n.moon/cache\napp/dist\n{DOCKER_IGNORE_END}\n")
+        );
+    }
+
+    #[test]
+    fn appends_block_after_manual_entries() {
+        assert_eq!(
+            generate_docker_ignore(".git\n*.log\n\n", ["app/dist"]),
+            format!(".git\n*.log\n\n{DOCKER_IGNORE_START}\napp/dist\n{DOCKER_IGNORE_END}\n")
+        );
+    }
+
+    #[test]
+    fn replaces_existing_block_and_preserves_surrounding_entries() {
+        let existing =
+            format!(".git\n\n{DOCKER_IGNORE_START}\nold/dist\n{DOCKER_IGNORE_END}\n\n*.log\n");
+
+        assert_eq!(
+            generate_docker_ignore(&existing, ["app/dist"]),
+            format!(".git\n\n{DOCKER_IGNORE_START}\napp/dist\n{DOCKER_IGNORE_END}\n\n*.log\n")
+        );
+    }
+
+    #[test]
+    fn preserves_entries_after_start_marker_without_end_marker() {
+        let existing = format!(".git\n\n{DOCKER_IGNORE_START}\n*.log\nnode_modules\n");
+
+        assert_eq!(
+            generate_docker_ignore(&existing, ["app/dist"]),
+            format!(
+                ".git\n\n{DOCKER_IGNORE_START}\napp/dist\n{DOCKER_IGNORE_END}\n\n*.log\nnode_modules\n"
+            )
+        );
+    }
+
+    #[test]
+    fn doesnt_duplicate_manual_entries() {
+        assert_eq!(
+            generate_docker_ignore("/.moon/cache/\n", [".moon/cache", "./app/dist/"]),
+            format!("/.moon/cache/\n\n{DOCKER_IGNORE_START}\napp/dist\n{DOCKER_IGNORE_END}\n")
+        );
+    }
+
+    #[test]
+    fn sorts_and_dedupes_entries() {
+        assert_eq!(
+            generate_docker_ignore("", ["b", "a", "b"]),
+            format!("{DOCKER_IGNORE_START}\na\nb\n{DOCKER_IGNORE_END}\n")
+        );
+    }
+}
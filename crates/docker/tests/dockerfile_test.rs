@@ -1,11 +1,14 @@
 use moon_common::Id;
-use moon_docker::{generate_dockerfile, GenerateDockerfileOptions};
+use moon_docker::{generate_dockerfile, GenerateDockerfileOptions, GenerateDockerfileProject};
 use moon_target::Target;
 use starbase_sandbox::assert_snapshot;
 
 fn create_options() -> GenerateDockerfileOptions {
     GenerateDockerfileOptions {
-        project: Id::raw("app"),
+        projects: vec![GenerateDockerfileProject {
+            id: Id::raw("app"),
+            ..Default::default()
+        }],
         ..Default::default()
     }
 }
@@ -29,8 +32,8 @@ mod dockerfile {
     #[test]
     fn with_tasks() {
         let mut options = create_options();
-        options.build_task = Some(Target::parse("app:compile").unwrap());
-        options.start_task = Some(Target::parse("app:serve").unwrap());
+        options.projects[0].build_task = Some(Target::parse("app:compile").unwrap());
+        options.projects[0].start_task = Some(Target::parse("app:serve").unwrap());
 
         assert_snapshot!(generate_dockerfile(options).unwrap());
     }
@@ -39,9 +42,131 @@ mod dockerfile {
     fn with_prune() {
         let mut options = create_options();
         options.prune = true;
-        options.build_task = Some(Target::parse("app:compile").unwrap());
-        options.start_task = Some(Target::parse("app:serve").unwrap());
+        options.projects[0].build_task = Some(Target::parse("app:compile").unwrap());
+        options.projects[0].start_task = Some(Target::parse("app:serve").unwrap());
 
         assert_snapshot!(generate_dockerfile(options).unwrap());
     }
 }
+
+mod dockerfile_multiple {
+    use super::*;
+
+    fn create_multiple_options() -> GenerateDockerfileOptions {
+        GenerateDockerfileOptions {
+            projects: vec![
+                GenerateDockerfileProject {
+                    build_task: Some(Target::parse("app:compile").unwrap()),
+                    id: Id::raw("app"),
+                    start_task: Some(Target::parse("app:serve").unwrap()),
+                },
+                GenerateDockerfileProject {
+                    build_task: None,
+                    id: Id::raw("Worker.Queue"),
+                    start_task: Some(Target::parse("Worker.Queue:run").unwrap()),
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn renders_stages_per_project() {
+        assert_snapshot!(generate_dockerfile(create_multiple_options()).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "would both use the stage name webapp")]
+    fn errors_for_stage_name_collisions() {
+        let mut options = create_multiple_options();
+        options.projects = vec![
+            GenerateDockerfileProject {
+                id: Id::raw("webApp"),
+                ..Default::default()
+            },
+            GenerateDockerfileProject {
+                id: Id::raw("webapp"),
+                ..Default::default()
+            },
+        ];
+
+        generate_dockerfile(options).unwrap();
+    }
+
+    #[test]
+    fn with_prune() {
+        let mut options = create_multiple_options();
+        options.prune = true;
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+}
+
+mod dockerfile_optimized {
+    use super::*;
+
+    fn create_optimized_options() -> GenerateDockerfileOptions {
+        let mut options = create_options();
+        options.prune = true;
+        options.projects[0].build_task = Some(Target::parse("app:compile").unwrap());
+        options.projects[0].start_task = Some(Target::parse("app:serve").unwrap());
+        options
+    }
+
+    #[test]
+    fn with_cache_mounts() {
+        let mut options = create_optimized_options();
+        options.cache_mounts = true;
+        options.package_stores = vec!["/root/.npm".into()];
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+
+    #[test]
+    fn with_copy_link() {
+        let mut options = create_optimized_options();
+        options.copy_link = true;
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+
+    #[test]
+    fn with_toolchain_stage() {
+        let mut options = create_optimized_options();
+        options.toolchain_stage = true;
+        options.toolchain_files = vec![
+            ".prototools".into(),
+            ".moon/toolchain.yml".into(),
+            ".moon/workspace.yml".into(),
+        ];
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+
+    #[test]
+    fn skips_toolchain_stage_when_toolchain_disabled() {
+        let mut options = create_optimized_options();
+        options.disable_toolchain = true;
+        options.toolchain_stage = true;
+        options.toolchain_files = vec![".prototools".into()];
+
+        let file = generate_dockerfile(options).unwrap();
+
+        assert!(!file.contains("AS toolchain"));
+        assert!(file.contains("FROM base AS skeleton"));
+    }
+
+    #[test]
+    fn mounts_hydration_cache_only_when_building() {
+        let mut options = create_optimized_options();
+        options.cache_mounts = true;
+
+        let file = generate_dockerfile(options).unwrap();
+
+        assert!(file.contains("RUN moon docker setup"));
+        assert!(file.contains(
+            "RUN --mount=type=cache,target=/app/.moon/cache/outputs --mount=type=cache,target=/app/.moon/cache/hashes moon run app:compile"
+        ));
+        assert!(!file.contains("target=/app/.moon/cache "));
+    }
+}
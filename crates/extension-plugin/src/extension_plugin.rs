@@ -1,6 +1,10 @@
 use async_trait::async_trait;
+use miette::IntoDiagnostic;
+use moon_common::serde::{disable_wasm_bridge, enable_wasm_bridge};
 use moon_pdk_api::*;
 use moon_plugin::{Plugin, PluginContainer, PluginId, PluginRegistration, PluginType};
+use moon_workspace_graph::WorkspaceGraph;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::sync::Arc;
 use tracing::instrument;
@@ -13,10 +17,26 @@ pub struct ExtensionPlugin {
 }
 
 impl ExtensionPlugin {
-    #[instrument(skip(self, context))]
-    pub async fn execute(&self, args: Vec<String>, context: MoonContext) -> miette::Result<()> {
+    #[instrument(skip(self, parsed_args, workspace_graph, context))]
+    pub async fn execute(
+        &self,
+        args: Vec<String>,
+        parsed_args: BTreeMap<String, serde_json::Value>,
+        workspace_graph: Option<&WorkspaceGraph>,
+        context: MoonContext,
+    ) -> miette::Result<()> {
         self.plugin
-            .call_func_without_output("execute_extension", ExecuteExtensionInput { args, context })
+            .call_func_without_output(
+                "execute_extension",
+                ExecuteExtensionInput {
+                    args,
+                    context,
+                    parsed_args,
+                    workspace_graph: workspace_graph
+                        .map(create_workspace_graph_snapshot)
+                        .transpose()?,
+                },
+            )
             .await?;
 
         Ok(())
@@ -49,6 +69,30 @@ impl Plugin for ExtensionPlugin {
     }
 }
 
+fn create_workspace_graph_snapshot(
+    workspace_graph: &WorkspaceGraph,
+) -> miette::Result<WorkspaceGraphSnapshot> {
+    let mut projects = vec![];
+
+    for project in workspace_graph.get_projects()? {
+        projects.push(workspace_graph.get_project_with_tasks(&project.id)?);
+    }
+
+    // Serialize in the same shape as the `load_project` host function
+    enable_wasm_bridge();
+
+    let result = projects
+        .into_iter()
+        .map(|project| Ok((project.id.clone(), serde_json::to_value(&project)?)))
+        .collect::<Result<BTreeMap<_, _>, serde_json::Error>>();
+
+    disable_wasm_bridge();
+
+    Ok(WorkspaceGraphSnapshot {
+        projects: result.into_diagnostic()?,
+    })
+}
+
 impl fmt::Debug for ExtensionPlugin {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ExtensionPlugin")
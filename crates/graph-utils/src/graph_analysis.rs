@@ -0,0 +1,202 @@
+use crate::graph_traits::*;
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::hash::Hash;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphNodeStats<K> {
+    pub id: K,
+
+    /// Total number of nodes that depend on this node, both directly
+    /// and transitively.
+    pub blast_radius: usize,
+
+    /// Number of nodes this node directly depends on.
+    pub fan_out: usize,
+
+    /// Number of nodes that directly depend on this node.
+    pub fan_in: usize,
+}
+
+fn count_deep_dependents<N, E>(
+    graph: &DiGraph<N, E>,
+    index: NodeIndex,
+    include_edge: &impl Fn(&E) -> bool,
+) -> usize {
+    let mut visited = FxHashSet::default();
+    let mut queue = vec![index];
+
+    while let Some(next) = queue.pop() {
+        for edge in graph.edges_directed(next, Direction::Incoming) {
+            let dependent = edge.source();
+
+            if include_edge(edge.weight()) && dependent != index && visited.insert(dependent) {
+                queue.push(dependent);
+            }
+        }
+    }
+
+    visited.len()
+}
+
+fn count_edges<N, E>(
+    graph: &DiGraph<N, E>,
+    index: NodeIndex,
+    direction: Direction,
+    include_edge: &impl Fn(&E) -> bool,
+) -> usize {
+    graph
+        .edges_directed(index, direction)
+        .filter(|edge| include_edge(edge.weight()))
+        .count()
+}
+
+/// Return the longest chain for a node, and whether it's complete. A chain is
+/// incomplete when a cycle was cut short, as it depends on the nodes that were
+/// being visited at the time, and must not be cached.
+fn find_longest_chain<N, E>(
+    graph: &DiGraph<N, E>,
+    index: NodeIndex,
+    include_edge: &impl Fn(&E) -> bool,
+    chains: &mut FxHashMap<NodeIndex, Vec<NodeIndex>>,
+    visiting: &mut FxHashSet<NodeIndex>,
+) -> (Vec<NodeIndex>, bool) {
+    if let Some(chain) = chains.get(&index) {
+        return (chain.clone(), true);
+    }
+
+    // Avoid infinite recursion when there's a cycle
+    if !visiting.insert(index) {
+        return (vec![], false);
+    }
+
+    let mut longest = vec![];
+    let mut complete = true;
+
+    for edge in graph.edges_directed(index, Direction::Outgoing) {
+        if !include_edge(edge.weight()) {
+            continue;
+        }
+
+        let (chain, chain_complete) =
+            find_longest_chain(graph, edge.target(), include_edge, chains, visiting);
+
+        complete = complete && chain_complete;
+
+        if chain.len() > longest.len() {
+            longest = chain;
+        }
+    }
+
+    longest.insert(0, index);
+
+    visiting.remove(&index);
+
+    if complete {
+        chains.insert(index, longest.clone());
+    }
+
+    (longest, complete)
+}
+
+/// Find groups of nodes that form a cycle, using the strongly connected
+/// components of a graph built from the provided nodes and their declared
+/// dependencies. Graphs drop edges that would create a cycle while being built,
+/// so this must operate on the declared dependencies instead.
+pub fn find_cycles<K: Clone + Hash + Eq>(
+    nodes: impl IntoIterator<Item = (K, Vec<K>)>,
+) -> Vec<Vec<K>> {
+    let mut graph = DiGraph::<K, ()>::new();
+    let mut indices = FxHashMap::default();
+    let mut edges = vec![];
+
+    for (node, deps) in nodes {
+        let index = *indices
+            .entry(node.clone())
+            .or_insert_with(|| graph.add_node(node));
+
+        edges.push((index, deps));
+    }
+
+    for (index, deps) in edges {
+        for dep in deps {
+            // Ignore dependencies that don't exist in the graph
+            if let Some(dep_index) = indices.get(&dep) {
+                graph.add_edge(index, *dep_index, ());
+            }
+        }
+    }
+
+    tarjan_scc(&graph)
+        .into_iter()
+        .filter(|component| component.len() > 1 || graph.contains_edge(component[0], component[0]))
+        .map(|component| {
+            component
+                .into_iter()
+                .map(|index| graph[index].clone())
+                .collect()
+        })
+        .collect()
+}
+
+pub trait GraphAnalysis<N, E, K: Hash + Eq>: GraphData<N, E, K> {
+    /// Return true if the edge should be included when analyzing the graph.
+    fn is_analyzed_edge(&self, _edge: &E) -> bool {
+        true
+    }
+
+    /// Return the fan-in, fan-out, and blast radius of every node in the graph,
+    /// ordered from the largest blast radius to the smallest.
+    fn get_node_stats(&self) -> Vec<GraphNodeStats<K>> {
+        let graph = self.get_graph();
+        let include_edge = |edge: &E| self.is_analyzed_edge(edge);
+
+        let mut stats = graph
+            .node_indices()
+            .map(|index| GraphNodeStats {
+                id: self.get_node_key(&graph[index]),
+                blast_radius: count_deep_dependents(graph, index, &include_edge),
+                fan_in: count_edges(graph, index, Direction::Incoming, &include_edge),
+                fan_out: count_edges(graph, index, Direction::Outgoing, &include_edge),
+            })
+            .collect::<Vec<_>>();
+
+        stats.sort_by_key(|node| Reverse(node.blast_radius));
+        stats
+    }
+
+    /// Return the longest chain of dependencies for every node in the graph,
+    /// starting with the node itself, ordered from the longest to the shortest.
+    fn get_longest_chains(&self) -> Vec<Vec<K>> {
+        let graph = self.get_graph();
+        let include_edge = |edge: &E| self.is_analyzed_edge(edge);
+        let mut chains = FxHashMap::default();
+
+        let mut longest = graph
+            .node_indices()
+            .map(|index| {
+                let (chain, _) = find_longest_chain(
+                    graph,
+                    index,
+                    &include_edge,
+                    &mut chains,
+                    &mut FxHashSet::default(),
+                );
+
+                chain
+                    .into_iter()
+                    .map(|index| self.get_node_key(&graph[index]))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        longest.sort_by_key(|chain| Reverse(chain.len()));
+        longest
+    }
+}
@@ -12,6 +12,157 @@ pub struct GraphCache<'graph, N, E> {
     // data: &'graph FxHashMap<K, N>,
 }
 
+#[derive(Serialize)]
+pub struct CytoscapeData {
+    pub id: String,
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct CytoscapeElement {
+    pub data: CytoscapeData,
+}
+
+#[derive(Serialize)]
+pub struct CytoscapeElements {
+    pub nodes: Vec<CytoscapeElement>,
+    pub edges: Vec<CytoscapeElement>,
+}
+
+#[derive(Serialize)]
+pub struct CytoscapeGraph {
+    pub elements: CytoscapeElements,
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('|', "#124;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Format a graph as a Mermaid flowchart, where edges point from
+/// a node to its dependencies.
+pub fn format_mermaid<N: Display, E: Display>(graph: &DiGraph<N, E>) -> String {
+    let mut lines = vec!["flowchart TD".to_owned()];
+
+    for index in graph.node_indices() {
+        lines.push(format!(
+            "  n{}[\"{}\"]",
+            index.index(),
+            escape_mermaid(&graph[index].to_string())
+        ));
+    }
+
+    for edge in graph.edge_references() {
+        let label = edge.weight().to_string();
+
+        lines.push(if label.is_empty() {
+            format!(
+                "  n{} --> n{}",
+                edge.source().index(),
+                edge.target().index()
+            )
+        } else {
+            format!(
+                "  n{} -->|{}| n{}",
+                edge.source().index(),
+                escape_mermaid(&label),
+                edge.target().index()
+            )
+        });
+    }
+
+    lines.join("\n")
+}
+
+/// Format a graph as a GraphML document, which can be opened in
+/// tools like yEd and Gephi.
+pub fn format_graphml<N: Display, E: Display>(graph: &DiGraph<N, E>) -> String {
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_owned(),
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#.to_owned(),
+        r#"  <key id="node_label" for="node" attr.name="label" attr.type="string"/>"#.to_owned(),
+        r#"  <key id="edge_label" for="edge" attr.name="label" attr.type="string"/>"#.to_owned(),
+        r#"  <graph id="G" edgedefault="directed">"#.to_owned(),
+    ];
+
+    for index in graph.node_indices() {
+        lines.push(format!(r#"    <node id="n{}">"#, index.index()));
+        lines.push(format!(
+            r#"      <data key="node_label">{}</data>"#,
+            escape_xml(&graph[index].to_string())
+        ));
+        lines.push("    </node>".into());
+    }
+
+    for edge in graph.edge_references() {
+        lines.push(format!(
+            r#"    <edge id="e{}" source="n{}" target="n{}">"#,
+            edge.id().index(),
+            edge.source().index(),
+            edge.target().index()
+        ));
+        lines.push(format!(
+            r#"      <data key="edge_label">{}</data>"#,
+            escape_xml(&edge.weight().to_string())
+        ));
+        lines.push("    </edge>".into());
+    }
+
+    lines.push("  </graph>".into());
+    lines.push("</graphml>".into());
+    lines.join("\n")
+}
+
+/// Format a graph as Cytoscape.js compatible JSON elements.
+pub fn format_cytoscape<N: Display, E: Display>(graph: &DiGraph<N, E>) -> miette::Result<String> {
+    let nodes = graph
+        .node_indices()
+        .map(|index| CytoscapeElement {
+            data: CytoscapeData {
+                id: format!("n{}", index.index()),
+                label: graph[index].to_string(),
+                source: None,
+                target: None,
+            },
+        })
+        .collect();
+
+    let edges = graph
+        .edge_references()
+        .map(|edge| CytoscapeElement {
+            data: CytoscapeData {
+                id: format!("e{}", edge.id().index()),
+                label: edge.weight().to_string(),
+                source: Some(format!("n{}", edge.source().index())),
+                target: Some(format!("n{}", edge.target().index())),
+            },
+        })
+        .collect();
+
+    Ok(json::format(
+        &CytoscapeGraph {
+            elements: CytoscapeElements { nodes, edges },
+        },
+        true,
+    )?)
+}
+
 pub trait GraphToDot<N: Debug + Display, E: Debug + Display, K: Display>:
     GraphData<N, E, K>
 {
@@ -54,3 +205,24 @@ pub trait GraphToJson<N: Serialize, E: Serialize, K>: GraphData<N, E, K> {
         )?)
     }
 }
+
+pub trait GraphToMermaid<N: Display, E: Display, K>: GraphData<N, E, K> {
+    /// Format graph as a Mermaid flowchart string.
+    fn to_mermaid(&self) -> String {
+        format_mermaid(self.get_graph())
+    }
+}
+
+pub trait GraphToGraphml<N: Display, E: Display, K>: GraphData<N, E, K> {
+    /// Format graph as a GraphML string.
+    fn to_graphml(&self) -> String {
+        format_graphml(self.get_graph())
+    }
+}
+
+pub trait GraphToCytoscape<N: Display, E: Display, K>: GraphData<N, E, K> {
+    /// Format graph as a Cytoscape.js compatible JSON string.
+    fn to_cytoscape(&self) -> miette::Result<String> {
+        format_cytoscape(self.get_graph())
+    }
+}
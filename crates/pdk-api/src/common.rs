@@ -1,3 +1,6 @@
+use moon_common::Id;
+use moon_config::{LanguageType, ProjectType, TaskType};
+use moon_target::Target;
 use warpgate_api::{api_enum, api_struct, ExecCommandInput, VirtualPath};
 
 api_struct!(
@@ -26,10 +29,89 @@ impl MoonContext {
     }
 }
 
+api_struct!(
+    /// Input for the operation that writes a file.
+    pub struct FileWriteInput {
+        /// Contents to write to the file.
+        pub content: String,
+
+        /// Virtual path to the file.
+        pub path: VirtualPath,
+    }
+);
+
+api_struct!(
+    /// Input for the operation that deletes a file.
+    pub struct FileDeleteInput {
+        /// Virtual path to the file.
+        pub path: VirtualPath,
+    }
+);
+
+api_struct!(
+    /// Input for operations that deep merge data into a file.
+    /// If the file does not exist, it will be created.
+    pub struct FileMergeInput {
+        /// Data to merge into the file's current contents.
+        pub data: serde_json::Value,
+
+        /// Virtual path to the file.
+        pub path: VirtualPath,
+    }
+);
+
 api_enum!(
     /// An operation to perform within moon (the host) itself.
     #[serde(tag = "type", rename_all = "kebab-case")]
     pub enum Operation {
+        FileDelete(FileDeleteInput),
+        FileWrite(FileWriteInput),
+        JsonMerge(FileMergeInput),
         ProcessExecution(ExecCommandInput),
+        TomlMerge(FileMergeInput),
+    }
+);
+
+api_enum!(
+    /// A strategy for extending a list of command arguments.
+    #[serde(tag = "strategy", content = "args", rename_all = "kebab-case")]
+    pub enum ExtendArgs {
+        Append(Vec<String>),
+        Prepend(Vec<String>),
+        Replace(Vec<String>),
+    }
+);
+
+api_struct!(
+    /// Fragment of a project, including important fields.
+    pub struct ProjectFragment {
+        /// Alias of the project, typically from a language manifest.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub alias: Option<String>,
+
+        /// ID of the project.
+        pub id: Id,
+
+        /// Primary programming language of the project.
+        pub language: LanguageType,
+
+        /// Relative path from the workspace root to the project root.
+        pub source: String,
+
+        /// The type of project.
+        #[serde(rename = "type")]
+        pub type_of: ProjectType,
+    }
+);
+
+api_struct!(
+    /// Fragment of a task, including important fields.
+    pub struct TaskFragment {
+        /// Target of the task.
+        pub target: Target,
+
+        /// The type of task.
+        #[serde(rename = "type")]
+        pub type_of: TaskType,
     }
 );
@@ -1,9 +1,67 @@
 use crate::common::MoonContext;
+use moon_common::Id;
 use schematic::Schema;
+use std::collections::BTreeMap;
 use warpgate_api::*;
 
 // METADATA
 
+api_enum!(
+    /// Type of value that an extension argument accepts.
+    #[derive(Default)]
+    #[serde(rename_all = "lowercase")]
+    pub enum ExtensionArgType {
+        /// A flag without a value.
+        Boolean,
+
+        /// A numeric value.
+        Number,
+
+        /// A string value.
+        #[default]
+        String,
+    }
+);
+
+api_struct!(
+    /// An argument (flag, option, or positional) that an extension accepts.
+    #[serde(default)]
+    pub struct ExtensionArg {
+        /// Name of the argument. Is used as the long name (`--name`) for
+        /// options, and as the value name for positionals.
+        pub name: String,
+
+        /// Description of the argument, displayed in `--help`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Default value when the argument was not passed.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_value: Option<String>,
+
+        /// Whether the argument accepts multiple values.
+        pub multiple: bool,
+
+        /// List of possible values that can be chosen from.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub choices: Vec<String>,
+
+        /// Whether the argument is positional instead of an option.
+        pub positional: bool,
+
+        /// Whether the argument is required.
+        pub required: bool,
+
+        /// Short name of the argument (`-n`), for options only.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub short: Option<char>,
+
+        /// Type of value the argument accepts.
+        #[serde(rename = "type")]
+        pub type_of: ExtensionArgType,
+    }
+);
+
 api_struct!(
     /// Input passed to the `register_extension` function.
     pub struct ExtensionMetadataInput {
@@ -15,6 +73,11 @@ api_struct!(
 api_struct!(
     /// Output returned from the `register_extension` function.
     pub struct ExtensionMetadataOutput {
+        /// Arguments that the extension accepts. When defined,
+        /// arguments will be validated and parsed before executing.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub args: Vec<ExtensionArg>,
+
         /// Schema shape of the tool's configuration.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub config_schema: Option<Schema>,
@@ -27,11 +90,25 @@ api_struct!(
 
         /// Version of the plugin.
         pub plugin_version: String,
+
+        /// Whether the extension requires the workspace graph,
+        /// which will be provided when executing.
+        #[serde(default)]
+        pub requires_workspace_graph: bool,
     }
 );
 
 // EXECUTE
 
+api_struct!(
+    /// A snapshot of the workspace graph.
+    pub struct WorkspaceGraphSnapshot {
+        /// All projects in the workspace, including their tasks,
+        /// serialized in the same shape as the `load_project` host function.
+        pub projects: BTreeMap<Id, serde_json::Value>,
+    }
+);
+
 api_struct!(
     /// Input passed to the `execute_extension` function.
     pub struct ExecuteExtensionInput {
@@ -40,5 +117,13 @@ api_struct!(
 
         /// Current moon context.
         pub context: MoonContext,
+
+        /// Arguments parsed according to the declared `args`, keyed by name.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub parsed_args: BTreeMap<String, serde_json::Value>,
+
+        /// The workspace graph, if requested with `requires_workspace_graph`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub workspace_graph: Option<WorkspaceGraphSnapshot>,
     }
 );
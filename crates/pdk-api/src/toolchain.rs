@@ -1,5 +1,10 @@
 use crate::common::*;
+use moon_common::Id;
+use moon_config::{DependencyConfig, TaskConfig};
+use rustc_hash::FxHashMap;
 use schematic::Schema;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use warpgate_api::api_struct;
 
 // METADATA
@@ -19,11 +24,67 @@ api_struct!(
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub config_schema: Option<Schema>,
 
+        /// List of manifest file names (relative to a project root) that
+        /// signify the toolchain is used by a project, e.g. `package.json`.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub manifest_file_names: Vec<String>,
+
         /// Version of the plugin.
         pub plugin_version: String,
     }
 );
 
+// PROJECT GRAPH
+
+api_struct!(
+    /// Input passed to the `extend_project_graph` function.
+    pub struct ExtendProjectGraphInput {
+        /// Current moon context.
+        pub context: MoonContext,
+
+        /// Map of project IDs to their source (relative from the workspace root).
+        pub project_sources: BTreeMap<Id, String>,
+    }
+);
+
+api_struct!(
+    /// Output returned from the `extend_project_graph` function.
+    pub struct ExtendProjectGraphOutput {
+        /// Map of project IDs to an alias, typically derived
+        /// from a language manifest (`package.json`, `go.mod`, etc).
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub aliases: BTreeMap<Id, String>,
+    }
+);
+
+api_struct!(
+    /// Input passed to the `extend_project` function.
+    pub struct ExtendProjectInput {
+        /// Current moon context.
+        pub context: MoonContext,
+
+        /// ID of the project being built.
+        pub project_id: Id,
+
+        /// Relative path from the workspace root to the project root.
+        pub project_source: String,
+    }
+);
+
+api_struct!(
+    /// Output returned from the `extend_project` function.
+    pub struct ExtendProjectOutput {
+        /// Implicit dependencies on other projects, typically
+        /// inferred from a language manifest.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub dependencies: Vec<DependencyConfig>,
+
+        /// Tasks inferred from the project, like scripts in a manifest.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub tasks: BTreeMap<Id, TaskConfig>,
+    }
+);
+
 // SYNC WORKSPACE
 
 api_struct!(
@@ -44,13 +105,99 @@ api_struct!(
 
 // SYNC PROJECT
 
-// api_struct!(
-//     /// Input passed to the `sync_project` function.
-//     pub struct SyncProjectInput {
-//         /// Current moon context.
-//         pub context: MoonContext,
+api_struct!(
+    /// Input passed to the `sync_project` function.
+    pub struct SyncProjectInput {
+        /// Current moon context.
+        pub context: MoonContext,
+
+        /// Other projects that the project being synced depends on.
+        pub dependencies: Vec<ProjectFragment>,
+
+        /// The project being synced.
+        pub project: ProjectFragment,
+    }
+);
 
-//         /// Other projects that the project being synced depends on.
-//         // pub dependencies: FxHashMap<Id, SyncProjectRecord>,
-//     }
-// );
+api_struct!(
+    /// Output returned from the `sync_project` function.
+    pub struct SyncProjectOutput {
+        /// Operations to perform.
+        pub operations: Vec<Operation>,
+    }
+);
+
+// HASHING
+
+api_struct!(
+    /// Input passed to the `hash_task_contents` function.
+    pub struct HashTaskContentsInput {
+        /// Current moon context.
+        pub context: MoonContext,
+
+        /// The project that owns the task.
+        pub project: ProjectFragment,
+
+        /// The task being hashed.
+        pub task: TaskFragment,
+    }
+);
+
+api_struct!(
+    /// Output returned from the `hash_task_contents` function.
+    pub struct HashTaskContentsOutput {
+        /// Contents to include in the task's hash, like dependency
+        /// versions from a manifest or lockfile.
+        pub contents: Vec<serde_json::Value>,
+    }
+);
+
+// RUNNING
+
+api_struct!(
+    /// Input passed to the `extend_task_command` function.
+    pub struct ExtendTaskCommandInput {
+        /// Arguments that will be passed to the command.
+        pub args: Vec<String>,
+
+        /// The command (binary) that will be executed.
+        pub command: String,
+
+        /// Current moon context.
+        pub context: MoonContext,
+
+        /// Environment variables that will be passed to the command.
+        pub env: FxHashMap<String, String>,
+
+        /// The project that owns the task.
+        pub project: ProjectFragment,
+
+        /// The task being executed.
+        pub task: TaskFragment,
+    }
+);
+
+api_struct!(
+    /// Output returned from the `extend_task_command` function.
+    pub struct ExtendTaskCommandOutput {
+        /// Arguments to extend the current arguments with.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub args: Option<ExtendArgs>,
+
+        /// A new command (binary) to execute, overwriting the current command.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub command: Option<String>,
+
+        /// Environment variables to set.
+        #[serde(default, skip_serializing_if = "FxHashMap::is_empty")]
+        pub env: FxHashMap<String, String>,
+
+        /// Environment variables to remove.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub env_remove: Vec<String>,
+
+        /// Paths to prepend to the `PATH` environment variable.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub paths: Vec<PathBuf>,
+    }
+);
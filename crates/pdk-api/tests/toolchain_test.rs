@@ -0,0 +1,68 @@
+use moon_common::Id;
+use moon_config::{DependencyConfig, DependencyScope, DependencySource, TaskArgs, TaskConfig};
+use moon_pdk_api::{ExtendProjectGraphOutput, ExtendProjectOutput};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+mod extend_project {
+    use super::*;
+
+    #[test]
+    fn supports_empty_output() {
+        let output: ExtendProjectOutput = serde_json::from_value(json!({})).unwrap();
+
+        assert_eq!(output, ExtendProjectOutput::default());
+    }
+
+    #[test]
+    fn round_trips_dependencies_and_tasks() {
+        let output = ExtendProjectOutput {
+            dependencies: vec![DependencyConfig {
+                id: Id::raw("foo"),
+                scope: DependencyScope::Development,
+                source: DependencySource::Implicit,
+                via: Some("go.mod".into()),
+            }],
+            tasks: BTreeMap::from_iter([(
+                Id::raw("build"),
+                TaskConfig {
+                    command: TaskArgs::String("go build".into()),
+                    ..Default::default()
+                },
+            )]),
+        };
+
+        let value = serde_json::to_value(&output).unwrap();
+
+        assert_eq!(
+            serde_json::from_value::<ExtendProjectOutput>(value).unwrap(),
+            output
+        );
+    }
+}
+
+mod extend_project_graph {
+    use super::*;
+
+    #[test]
+    fn supports_empty_output() {
+        let output: ExtendProjectGraphOutput = serde_json::from_value(json!({})).unwrap();
+
+        assert!(output.aliases.is_empty());
+    }
+
+    #[test]
+    fn parses_aliases() {
+        let output: ExtendProjectGraphOutput = serde_json::from_value(json!({
+            "aliases": {
+                "foo": "github.com/org/foo",
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            output.aliases.get("foo").map(|alias| alias.as_str()),
+            Some("github.com/org/foo")
+        );
+    }
+}
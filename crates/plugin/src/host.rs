@@ -5,7 +5,7 @@ use moon_target::Target;
 use moon_workspace_graph::WorkspaceGraph;
 use proto_core::ProtoEnvironment;
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use tracing::{instrument, trace};
 use warpgate::host::{create_host_functions as create_shared_host_functions, HostData};
 
@@ -13,7 +13,10 @@ use warpgate::host::{create_host_functions as create_shared_host_functions, Host
 pub struct PluginHostData {
     pub moon_env: Arc<MoonEnvironment>,
     pub proto_env: Arc<ProtoEnvironment>,
-    pub workspace_graph: WorkspaceGraph,
+
+    /// The workspace graph is lazily set once it has been built, as some
+    /// plugins are required while building the graph itself.
+    pub workspace_graph: Arc<OnceLock<WorkspaceGraph>>,
 }
 
 impl fmt::Debug for PluginHostData {
@@ -51,6 +54,12 @@ fn map_error(error: miette::Report) -> Error {
     Error::msg(error.to_string())
 }
 
+fn get_workspace_graph(data: &PluginHostData) -> Result<&WorkspaceGraph, Error> {
+    data.workspace_graph.get().ok_or_else(|| {
+        Error::msg("Unable to access the workspace graph, as it has not been built yet.")
+    })
+}
+
 #[instrument(name = "host_load_project", skip_all)]
 fn load_project(
     plugin: &mut CurrentPlugin,
@@ -71,7 +80,9 @@ fn load_project(
 
     let data = user_data.get()?;
     let data = data.lock().unwrap();
-    let project = data.workspace_graph.get_project(&id).map_err(map_error)?;
+    let project = get_workspace_graph(&data)?
+        .get_project(&id)
+        .map_err(map_error)?;
 
     trace!(
         plugin = &uuid,
@@ -115,7 +126,9 @@ fn load_task(
 
     let data = user_data.get()?;
     let data = data.lock().unwrap();
-    let task = data.workspace_graph.get_task(&target).map_err(map_error)?;
+    let task = get_workspace_graph(&data)?
+        .get_task(&target)
+        .map_err(map_error)?;
 
     trace!(
         plugin = &uuid,
@@ -1,7 +1,9 @@
 use miette::Diagnostic;
 use moon_common::{Id, Style, Stylize};
-use moon_config::{DependencyScope, StackType};
+use moon_config::{ConfigFinder, DependencyScope, StackType};
 use moon_project::{Project, ProjectType};
+use moon_task::{Target, TargetScope};
+use rustc_hash::FxHashMap;
 use thiserror::Error;
 
 #[derive(Error, Debug, Diagnostic)]
@@ -34,6 +36,134 @@ pub enum ProjectConstraintsError {
         dep_id: Id,
         allowed: String,
     },
+
+    #[diagnostic(code(project_constraints::banned_dependency))]
+    #[error(
+        "Banned project dependency. Project {} cannot depend on project {}, as {} is banned for {}.\n\nThis can be customized with the {} setting.",
+        .source_id.style(Style::Id),
+        .dep_id.style(Style::Id),
+        .banned.style(Style::Label),
+        .selector.style(Style::Label),
+        "constraints.bannedDependencies".style(Style::Property),
+    )]
+    BannedDependency {
+        source_id: Id,
+        selector: String,
+        dep_id: Id,
+        banned: String,
+    },
+
+    #[diagnostic(code(project_constraints::invalid_layer_relationship))]
+    #[error(
+        "Invalid layer relationship. Project {} in layer #{source_layer} cannot depend on project {} in the higher layer #{dep_layer}.",
+        .source_id.style(Style::Id),
+        .dep_id.style(Style::Id),
+    )]
+    InvalidLayerRelationship {
+        source_id: Id,
+        source_layer: Id,
+        dep_id: Id,
+        dep_layer: Id,
+    },
+
+    #[diagnostic(code(project_constraints::max_dependency_depth))]
+    #[error(
+        "Project {} has a dependency chain with a depth of {depth}, exceeding the maximum of {max}: {chain}",
+        .source_id.style(Style::Id),
+    )]
+    MaxDependencyDepth {
+        source_id: Id,
+        depth: usize,
+        max: usize,
+        chain: String,
+    },
+
+    #[diagnostic(code(project_constraints::banned_task_dependency))]
+    #[error(
+        "Banned task dependency. Task {} cannot depend on task {}, as {} cannot be depended on. Remove the dependency from {}.\n\nThis can be customized with the {} setting.",
+        .task.style(Style::Label),
+        .dep.style(Style::Label),
+        .banned.style(Style::Label),
+        .config_path.style(Style::File),
+        "constraints.bannedTaskDependencies".style(Style::Property),
+    )]
+    BannedTaskDependency {
+        task: Target,
+        dep: Target,
+        banned: Target,
+        config_path: String,
+    },
+
+    #[diagnostic(code(project_constraints::missing_required_tasks))]
+    #[error(
+        "Project {} is missing the tasks {tasks}, which are required for {}.\n\nConfigure the tasks in {}, or inherit them from {}.",
+        .source_id.style(Style::Id),
+        .selector.style(Style::Label),
+        .config_path.style(Style::File),
+        ".moon/tasks".style(Style::File),
+    )]
+    MissingRequiredTasks {
+        source_id: Id,
+        selector: String,
+        tasks: String,
+        config_path: String,
+    },
+
+    #[diagnostic(code(project_constraints::violations))]
+    #[error("Found {} project constraint violations.", .errors.len())]
+    Violations {
+        #[related]
+        errors: Vec<ProjectConstraintsError>,
+    },
+}
+
+/// Combine all violations into a single error. A single violation will
+/// be returned as-is, while multiple will be reported together.
+pub fn report_violations(violations: Vec<miette::Report>) -> miette::Result<()> {
+    let mut errors = vec![];
+
+    for violation in violations {
+        errors.push(violation.downcast::<ProjectConstraintsError>()?);
+    }
+
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.remove(0).into()),
+        _ => Err(ProjectConstraintsError::Violations { errors }.into()),
+    }
+}
+
+fn matches_selector(project: &Project, selector: &str) -> bool {
+    match selector.strip_prefix('#') {
+        Some(tag) => project.config.tags.iter().any(|t| t == tag),
+        None => project.id == selector || project.alias.as_deref() == Some(selector),
+    }
+}
+
+/// Return the project's config file, or the default file name
+/// if the project has not been configured.
+fn get_project_config_path(project: &Project) -> String {
+    let names = ConfigFinder::with_pkl().get_project_file_names();
+
+    let name = names
+        .iter()
+        .find(|name| project.root.join(name).exists())
+        .unwrap_or(&names[0]);
+
+    project.source.join(name).to_string()
+}
+
+fn matches_target(project: &Project, target: &Target, selector: &Target) -> bool {
+    if target.task_id != selector.task_id {
+        return false;
+    }
+
+    match &selector.scope {
+        TargetScope::All => true,
+        TargetScope::Project(id) => project.matches_locator(id),
+        TargetScope::Tag(tag) => project.config.tags.contains(tag),
+        TargetScope::Deps | TargetScope::OwnSelf => false,
+    }
 }
 
 pub fn enforce_project_type_relationships(
@@ -169,3 +299,162 @@ pub fn enforce_tag_relationships(
     }
     .into())
 }
+
+pub fn enforce_banned_dependencies(
+    source: &Project,
+    dependency: &Project,
+    dependency_scope: &DependencyScope,
+    banned_dependencies: &FxHashMap<String, Vec<String>>,
+) -> miette::Result<()> {
+    // The root-level project is implicitly depended on
+    if matches!(dependency_scope, DependencyScope::Root) {
+        return Ok(());
+    }
+
+    for (selector, banned_list) in banned_dependencies {
+        if !matches_selector(source, selector) {
+            continue;
+        }
+
+        if let Some(banned) = banned_list
+            .iter()
+            .find(|banned| matches_selector(dependency, banned))
+        {
+            return Err(ProjectConstraintsError::BannedDependency {
+                source_id: source.id.clone(),
+                selector: selector.to_owned(),
+                dep_id: dependency.id.clone(),
+                banned: banned.to_owned(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn enforce_layer_relationships(
+    source: &Project,
+    dependency: &Project,
+    dependency_scope: &DependencyScope,
+    layers: &[Id],
+) -> miette::Result<()> {
+    // The root-level project is implicitly depended on
+    if matches!(dependency_scope, DependencyScope::Root) {
+        return Ok(());
+    }
+
+    let find_layers = |project: &Project| {
+        layers
+            .iter()
+            .enumerate()
+            .filter(|(_, layer)| project.config.tags.contains(layer))
+            .collect::<Vec<_>>()
+    };
+
+    // Layers are ordered from highest to lowest, so compare the source's
+    // lowest layer against the dependency's highest layer
+    let (Some((source_index, source_layer)), Some((dep_index, dep_layer))) = (
+        find_layers(source).pop(),
+        find_layers(dependency).first().copied(),
+    ) else {
+        return Ok(());
+    };
+
+    if dep_index < source_index {
+        return Err(ProjectConstraintsError::InvalidLayerRelationship {
+            source_id: source.id.clone(),
+            source_layer: source_layer.to_owned(),
+            dep_id: dependency.id.clone(),
+            dep_layer: dep_layer.to_owned(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+pub fn enforce_max_dependency_depth(
+    source: &Project,
+    dependency_chain: &[Id],
+    max_depth: usize,
+) -> miette::Result<()> {
+    if dependency_chain.len() <= max_depth {
+        return Ok(());
+    }
+
+    Err(ProjectConstraintsError::MaxDependencyDepth {
+        source_id: source.id.clone(),
+        depth: dependency_chain.len(),
+        max: max_depth,
+        chain: [&source.id]
+            .into_iter()
+            .chain(dependency_chain)
+            .map(|id| id.as_str())
+            .collect::<Vec<_>>()
+            .join(" → "),
+    }
+    .into())
+}
+
+pub fn enforce_required_tasks(
+    source: &Project,
+    required_tasks: &FxHashMap<String, Vec<Id>>,
+) -> miette::Result<()> {
+    for (selector, task_ids) in required_tasks {
+        let applies = match selector.strip_prefix('#') {
+            Some(tag) => source.config.tags.iter().any(|t| t == tag),
+            None => source.type_of.to_string() == *selector,
+        };
+
+        if !applies {
+            continue;
+        }
+
+        let missing = task_ids
+            .iter()
+            .filter(|task_id| {
+                !source
+                    .task_targets
+                    .iter()
+                    .any(|target| target.task_id == **task_id)
+            })
+            .map(|task_id| task_id.as_str())
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            return Err(ProjectConstraintsError::MissingRequiredTasks {
+                source_id: source.id.clone(),
+                selector: selector.to_owned(),
+                tasks: missing.join(", "),
+                config_path: get_project_config_path(source),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn enforce_banned_task_dependencies(
+    source: &Target,
+    source_project: &Project,
+    dependency: &Target,
+    dependency_project: &Project,
+    banned_tasks: &[Target],
+) -> miette::Result<()> {
+    if let Some(banned) = banned_tasks
+        .iter()
+        .find(|banned| matches_target(dependency_project, dependency, banned))
+    {
+        return Err(ProjectConstraintsError::BannedTaskDependency {
+            task: source.to_owned(),
+            dep: dependency.to_owned(),
+            banned: banned.to_owned(),
+            config_path: get_project_config_path(source_project),
+        }
+        .into());
+    }
+
+    Ok(())
+}
@@ -654,3 +654,430 @@ mod by_tag {
         .unwrap();
     }
 }
+
+mod banned_dependencies {
+    use super::*;
+    use moon_project_constraints::enforce_banned_dependencies;
+    use rustc_hash::FxHashMap;
+
+    fn create_banned(source: &str, banned: &[&str]) -> FxHashMap<String, Vec<String>> {
+        FxHashMap::from_iter([(
+            source.to_owned(),
+            banned.iter().map(|b| b.to_string()).collect(),
+        )])
+    }
+
+    #[test]
+    fn ignores_unbanned_ids() {
+        enforce_banned_dependencies(
+            &create_project("foo", ProjectType::Application),
+            &create_project("bar", ProjectType::Library),
+            &DependencyScope::Production,
+            &create_banned("foo", &["baz"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ignores_other_sources() {
+        enforce_banned_dependencies(
+            &create_project("qux", ProjectType::Application),
+            &create_project("bar", ProjectType::Library),
+            &DependencyScope::Production,
+            &create_banned("foo", &["bar"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ignores_root_scope() {
+        enforce_banned_dependencies(
+            &create_project("foo", ProjectType::Application),
+            &create_project("bar", ProjectType::Library),
+            &DependencyScope::Root,
+            &create_banned("foo", &["bar"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Banned project dependency. Project foo cannot depend on project bar"
+    )]
+    fn errors_for_banned_id() {
+        enforce_banned_dependencies(
+            &create_project("foo", ProjectType::Application),
+            &create_project("bar", ProjectType::Library),
+            &DependencyScope::Production,
+            &create_banned("foo", &["bar"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Banned project dependency. Project foo cannot depend on project bar"
+    )]
+    fn errors_for_banned_tag() {
+        enforce_banned_dependencies(
+            &create_project_with_tags("foo", vec![Id::raw("frontend")]),
+            &create_project_with_tags("bar", vec![Id::raw("backend")]),
+            &DependencyScope::Development,
+            &create_banned("#frontend", &["#backend"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ignores_tags_not_in_use() {
+        enforce_banned_dependencies(
+            &create_project_with_tags("foo", vec![Id::raw("frontend")]),
+            &create_project_with_tags("bar", vec![Id::raw("shared")]),
+            &DependencyScope::Production,
+            &create_banned("#frontend", &["#backend"]),
+        )
+        .unwrap();
+    }
+}
+
+mod layers {
+    use super::*;
+    use moon_project_constraints::enforce_layer_relationships;
+
+    fn create_layers() -> Vec<Id> {
+        vec![Id::raw("app"), Id::raw("feature"), Id::raw("util")]
+    }
+
+    #[test]
+    fn higher_can_use_lower() {
+        enforce_layer_relationships(
+            &create_project_with_tags("foo", vec![Id::raw("app")]),
+            &create_project_with_tags("bar", vec![Id::raw("util")]),
+            &DependencyScope::Production,
+            &create_layers(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn same_layer_can_use_same() {
+        enforce_layer_relationships(
+            &create_project_with_tags("foo", vec![Id::raw("feature")]),
+            &create_project_with_tags("bar", vec![Id::raw("feature")]),
+            &DependencyScope::Production,
+            &create_layers(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ignores_projects_without_layers() {
+        enforce_layer_relationships(
+            &create_project_with_tags("foo", vec![Id::raw("util")]),
+            &create_project_with_tags("bar", vec![Id::raw("other")]),
+            &DependencyScope::Production,
+            &create_layers(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid layer relationship. Project foo in layer #util")]
+    fn lower_cant_use_higher() {
+        enforce_layer_relationships(
+            &create_project_with_tags("foo", vec![Id::raw("util")]),
+            &create_project_with_tags("bar", vec![Id::raw("feature")]),
+            &DependencyScope::Production,
+            &create_layers(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Project foo in layer #util cannot depend on")]
+    fn uses_lowest_source_layer_and_highest_dep_layer() {
+        enforce_layer_relationships(
+            &create_project_with_tags("foo", vec![Id::raw("app"), Id::raw("util")]),
+            &create_project_with_tags("bar", vec![Id::raw("util"), Id::raw("feature")]),
+            &DependencyScope::Production,
+            &create_layers(),
+        )
+        .unwrap();
+    }
+}
+
+mod max_dependency_depth {
+    use super::*;
+    use moon_project_constraints::enforce_max_dependency_depth;
+
+    #[test]
+    fn allows_chain_at_max() {
+        enforce_max_dependency_depth(
+            &create_project("foo", ProjectType::Application),
+            &[Id::raw("bar"), Id::raw("baz")],
+            2,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Project foo has a dependency chain with a depth of 3")]
+    fn errors_for_chain_over_max() {
+        enforce_max_dependency_depth(
+            &create_project("foo", ProjectType::Application),
+            &[Id::raw("bar"), Id::raw("baz"), Id::raw("qux")],
+            2,
+        )
+        .unwrap();
+    }
+}
+
+mod required_tasks {
+    use super::*;
+    use moon_project_constraints::enforce_required_tasks;
+    use moon_task::Target;
+    use rustc_hash::FxHashMap;
+    use starbase_sandbox::create_empty_sandbox;
+
+    fn create_project_with_tasks(
+        id: &str,
+        type_of: ProjectType,
+        tags: Vec<Id>,
+        tasks: &[&str],
+    ) -> Project {
+        let mut project = create_project_with_tags(id, tags);
+        project.type_of = type_of;
+        project.task_targets = tasks
+            .iter()
+            .map(|task| Target::new(id, task).unwrap())
+            .collect();
+        project
+    }
+
+    fn create_required(selector: &str, tasks: &[&str]) -> FxHashMap<String, Vec<Id>> {
+        FxHashMap::from_iter([(selector.to_owned(), tasks.iter().map(Id::raw).collect())])
+    }
+
+    #[test]
+    fn passes_when_tasks_exist() {
+        enforce_required_tasks(
+            &create_project_with_tasks(
+                "foo",
+                ProjectType::Application,
+                vec![],
+                &["build", "test", "lint"],
+            ),
+            &create_required("application", &["build", "test"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ignores_other_types() {
+        enforce_required_tasks(
+            &create_project_with_tasks("foo", ProjectType::Library, vec![], &[]),
+            &create_required("application", &["build", "test"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Project foo is missing the tasks test, which are required for")]
+    fn errors_when_type_missing_tasks() {
+        enforce_required_tasks(
+            &create_project_with_tasks("foo", ProjectType::Application, vec![], &["build"]),
+            &create_required("application", &["build", "test"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Configure the tasks in packages/foo/moon.yml")]
+    fn references_default_config_file() {
+        let sandbox = create_empty_sandbox();
+        let mut project =
+            create_project_with_tasks("foo", ProjectType::Application, vec![], &["build"]);
+        project.root = sandbox.path().join("packages/foo");
+        project.source = "packages/foo".into();
+
+        enforce_required_tasks(&project, &create_required("application", &["test"])).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Configure the tasks in packages/foo/moon.pkl")]
+    fn references_existing_config_file() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("packages/foo/moon.pkl", "");
+
+        let mut project =
+            create_project_with_tasks("foo", ProjectType::Application, vec![], &["build"]);
+        project.root = sandbox.path().join("packages/foo");
+        project.source = "packages/foo".into();
+
+        enforce_required_tasks(&project, &create_required("application", &["test"])).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Project foo is missing the tasks build, test, which are required")]
+    fn errors_when_tag_missing_tasks() {
+        enforce_required_tasks(
+            &create_project_with_tasks("foo", ProjectType::Library, vec![Id::raw("app")], &[]),
+            &create_required("#app", &["build", "test"]),
+        )
+        .unwrap();
+    }
+}
+
+mod banned_task_dependencies {
+    use super::*;
+    use moon_project_constraints::{enforce_banned_task_dependencies, ProjectConstraintsError};
+    use moon_task::Target;
+
+    fn create_banned(targets: &[&str]) -> Vec<Target> {
+        targets
+            .iter()
+            .map(|target| Target::parse(target).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn ignores_unbanned_tasks() {
+        enforce_banned_task_dependencies(
+            &Target::parse("foo:build").unwrap(),
+            &create_project("foo", ProjectType::Application),
+            &Target::parse("bar:build").unwrap(),
+            &create_project("bar", ProjectType::Library),
+            &create_banned(&[":deploy"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ignores_other_projects() {
+        enforce_banned_task_dependencies(
+            &Target::parse("foo:build").unwrap(),
+            &create_project("foo", ProjectType::Application),
+            &Target::parse("bar:deploy").unwrap(),
+            &create_project("bar", ProjectType::Library),
+            &create_banned(&["baz:deploy", "#release:deploy"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Banned task dependency. Task foo:build cannot depend on task")]
+    fn errors_for_all_scope() {
+        enforce_banned_task_dependencies(
+            &Target::parse("foo:build").unwrap(),
+            &create_project("foo", ProjectType::Application),
+            &Target::parse("bar:deploy").unwrap(),
+            &create_project("bar", ProjectType::Library),
+            &create_banned(&[":deploy"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Banned task dependency. Task foo:build cannot depend on task")]
+    fn errors_for_project_scope() {
+        enforce_banned_task_dependencies(
+            &Target::parse("foo:build").unwrap(),
+            &create_project("foo", ProjectType::Application),
+            &Target::parse("bar:deploy").unwrap(),
+            &create_project("bar", ProjectType::Library),
+            &create_banned(&["bar:deploy"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Banned task dependency. Task foo:build cannot depend on task")]
+    fn errors_for_tag_scope() {
+        enforce_banned_task_dependencies(
+            &Target::parse("foo:build").unwrap(),
+            &create_project("foo", ProjectType::Application),
+            &Target::parse("bar:deploy").unwrap(),
+            &create_project_with_tags("bar", vec![Id::raw("release")]),
+            &create_banned(&["#release:deploy"]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn references_source_config_file() {
+        let mut project = create_project("foo", ProjectType::Application);
+        project.source = "packages/foo".into();
+
+        let error = enforce_banned_task_dependencies(
+            &Target::parse("foo:build").unwrap(),
+            &project,
+            &Target::parse("bar:deploy").unwrap(),
+            &create_project("bar", ProjectType::Library),
+            &create_banned(&[":deploy"]),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.downcast_ref::<ProjectConstraintsError>().unwrap(),
+            ProjectConstraintsError::BannedTaskDependency { config_path, .. } if config_path == "packages/foo/moon.yml"
+        ));
+    }
+}
+
+mod violations {
+    use super::*;
+    use moon_project_constraints::{
+        enforce_max_dependency_depth, report_violations, ProjectConstraintsError,
+    };
+
+    #[test]
+    fn passes_without_violations() {
+        report_violations(vec![]).unwrap();
+    }
+
+    #[test]
+    fn returns_single_violation_as_is() {
+        let error = report_violations(vec![enforce_tag_relationships(
+            &create_project_with_tags("foo", vec![Id::raw("a")]),
+            &Id::raw("a"),
+            &create_project("bar", ProjectType::Library),
+            &[Id::raw("b")],
+        )
+        .unwrap_err()])
+        .unwrap_err();
+
+        assert!(matches!(
+            error.downcast_ref::<ProjectConstraintsError>().unwrap(),
+            ProjectConstraintsError::InvalidTagRelationship { .. }
+        ));
+    }
+
+    #[test]
+    fn collects_multiple_violations() {
+        let error = report_violations(vec![
+            enforce_project_type_relationships(
+                &create_project("foo", ProjectType::Library),
+                &create_project("bar", ProjectType::Application),
+                &DependencyScope::Production,
+            )
+            .unwrap_err(),
+            enforce_max_dependency_depth(
+                &create_project("foo", ProjectType::Library),
+                &[Id::raw("bar"), Id::raw("baz")],
+                1,
+            )
+            .unwrap_err(),
+        ])
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "Found 2 project constraint violations.");
+
+        let ProjectConstraintsError::Violations { errors } =
+            error.downcast_ref::<ProjectConstraintsError>().unwrap()
+        else {
+            panic!("Expected violations");
+        };
+
+        assert_eq!(errors.len(), 2);
+    }
+}
@@ -281,6 +281,14 @@ impl GraphData<Project, DependencyScope, Id> for ProjectGraph {
     }
 }
 
+impl GraphAnalysis<Project, DependencyScope, Id> for ProjectGraph {
+    // Dependencies on the root-level project are a special case,
+    // and are excluded, like when enforcing the max dependency depth
+    fn is_analyzed_edge(&self, edge: &DependencyScope) -> bool {
+        !matches!(edge, DependencyScope::Root)
+    }
+}
+
 impl GraphConnections<Project, DependencyScope, Id> for ProjectGraph {}
 
 impl GraphConversions<Project, DependencyScope, Id> for ProjectGraph {}
@@ -288,3 +296,9 @@ impl GraphConversions<Project, DependencyScope, Id> for ProjectGraph {}
 impl GraphToDot<Project, DependencyScope, Id> for ProjectGraph {}
 
 impl GraphToJson<Project, DependencyScope, Id> for ProjectGraph {}
+
+impl GraphToMermaid<Project, DependencyScope, Id> for ProjectGraph {}
+
+impl GraphToGraphml<Project, DependencyScope, Id> for ProjectGraph {}
+
+impl GraphToCytoscape<Project, DependencyScope, Id> for ProjectGraph {}
@@ -17,6 +17,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use starbase_events::EventState;
 use starbase_sandbox::{assert_snapshot, create_sandbox, Sandbox};
 use starbase_utils::{fs, json, string_vec};
+use std::collections::BTreeMap;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::Path;
@@ -441,6 +442,52 @@ mod project_graph {
                 })
                 .await;
             }
+
+            async fn generate_with_plugins(root: &Path, version: &str) -> String {
+                let mut mock = create_workspace_graph_mocker(root);
+                mock.with_vcs();
+
+                let mut context = mock.create_context();
+                context.plugin_file_names = string_vec!["manifest.json"];
+                context.plugin_versions = BTreeMap::from_iter([("example".into(), version.into())]);
+
+                mock.build_workspace_graph_with_options(WorkspaceMockOptions {
+                    cache: Some(CacheEngine::new(root).unwrap()),
+                    context: Some(context),
+                    ..Default::default()
+                })
+                .await;
+
+                let state: WorkspaceProjectsCacheState =
+                    json::read_file(root.join(STATE_PATH)).unwrap();
+
+                state.last_hash
+            }
+
+            #[tokio::test]
+            async fn with_plugin_file_changes() {
+                let sandbox = create_sandbox("dependencies");
+                sandbox.enable_git();
+
+                let hash1 = generate_with_plugins(sandbox.path(), "1.0.0").await;
+
+                sandbox.create_file("a/manifest.json", "{}");
+
+                let hash2 = generate_with_plugins(sandbox.path(), "1.0.0").await;
+
+                assert_ne!(hash1, hash2);
+            }
+
+            #[tokio::test]
+            async fn with_plugin_version_changes() {
+                let sandbox = create_sandbox("dependencies");
+                sandbox.enable_git();
+
+                let hash1 = generate_with_plugins(sandbox.path(), "1.0.0").await;
+                let hash2 = generate_with_plugins(sandbox.path(), "2.0.0").await;
+
+                assert_ne!(hash1, hash2);
+            }
         }
     }
 
@@ -1090,6 +1137,129 @@ mod project_graph {
         }
     }
 
+    mod inference {
+        use super::*;
+        use moon_config::{TaskArgs, TaskConfig};
+
+        // Mirrors how toolchain plugins extend projects and the graph
+        async fn generate_inference_project_graph() -> WorkspaceGraph {
+            let sandbox = create_sandbox("dependencies");
+
+            sandbox.create_file(
+                "c/moon.yml",
+                r#"
+tasks:
+  build:
+    command: explicit
+"#,
+            );
+
+            let mock = create_workspace_graph_mocker(sandbox.path());
+            let context = mock.create_context();
+
+            context
+                .extend_project_graph
+                .on(
+                    |event: Arc<ExtendProjectGraphEvent>,
+                     data: Arc<RwLock<ExtendProjectGraphData>>| async move {
+                        let mut data = data.write().await;
+
+                        for (id, _) in &event.sources {
+                            data.aliases
+                                .push((id.to_owned(), format!("@inferred/{id}")));
+                        }
+
+                        Ok(EventState::Continue)
+                    },
+                )
+                .await;
+
+            context
+                .extend_project
+                .on(
+                    |event: Arc<ExtendProjectEvent>,
+                     data: Arc<RwLock<ExtendProjectData>>| async move {
+                        let mut data = data.write().await;
+
+                        if event.project_id == "a" {
+                            data.dependencies.push(DependencyConfig {
+                                id: Id::raw("@inferred/c"),
+                                scope: DependencyScope::Development,
+                                ..Default::default()
+                            });
+                        }
+
+                        data.tasks.insert(
+                            Id::raw("build"),
+                            TaskConfig {
+                                command: TaskArgs::String("inferred".into()),
+                                ..Default::default()
+                            },
+                        );
+
+                        Ok(EventState::Continue)
+                    },
+                )
+                .await;
+
+            mock.build_workspace_graph_with_options(WorkspaceMockOptions {
+                context: Some(context),
+                ..Default::default()
+            })
+            .await
+        }
+
+        #[tokio::test]
+        async fn infers_aliases() {
+            let graph = generate_inference_project_graph().await;
+
+            assert_eq!(
+                graph.get_project("@inferred/a").unwrap(),
+                graph.get_project("a").unwrap()
+            );
+            assert_eq!(
+                graph.get_project("b").unwrap().alias,
+                Some("@inferred/b".into())
+            );
+        }
+
+        #[tokio::test]
+        async fn infers_dependencies() {
+            let graph = generate_inference_project_graph().await;
+
+            assert!(graph
+                .get_project("a")
+                .unwrap()
+                .dependencies
+                .contains(&DependencyConfig {
+                    id: Id::raw("c"),
+                    scope: DependencyScope::Development,
+                    source: DependencySource::Implicit,
+                    ..DependencyConfig::default()
+                }));
+        }
+
+        #[tokio::test]
+        async fn infers_tasks() {
+            let graph = generate_inference_project_graph().await;
+
+            assert_eq!(
+                graph.get_task_from_project("a", "build").unwrap().command,
+                "inferred"
+            );
+        }
+
+        #[tokio::test]
+        async fn doesnt_override_explicit_tasks() {
+            let graph = generate_inference_project_graph().await;
+
+            assert_eq!(
+                graph.get_task_from_project("c", "build").unwrap().command,
+                "explicit"
+            );
+        }
+    }
+
     mod type_constraints {
         use super::*;
 
@@ -1359,6 +1529,254 @@ mod project_graph {
         }
     }
 
+    mod dependency_constraints {
+        use super::*;
+
+        async fn generate_dependency_constraints_project_graph(
+            func: impl FnOnce(&Sandbox),
+            config: impl FnOnce(&mut WorkspaceMocker),
+        ) -> WorkspaceGraph {
+            let sandbox = create_sandbox("tag-constraints");
+
+            func(&sandbox);
+
+            let mut mock = create_workspace_graph_mocker(sandbox.path());
+
+            config(&mut mock);
+
+            mock.build_workspace_graph().await
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Banned project dependency. Project a cannot depend on project")]
+        async fn errors_for_banned_dependency() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(sandbox.path().join("a/moon.yml"), "dependsOn: [b]");
+                },
+                |mock| {
+                    mock.workspace_config
+                        .constraints
+                        .banned_dependencies
+                        .insert("a".into(), vec!["b".into()]);
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        async fn allows_unbanned_dependency() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(sandbox.path().join("a/moon.yml"), "dependsOn: [b]");
+                    append_file(sandbox.path().join("b/moon.yml"), "tags: [shared]");
+                },
+                |mock| {
+                    mock.workspace_config
+                        .constraints
+                        .banned_dependencies
+                        .insert("a".into(), vec!["c".into(), "#internal".into()]);
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Invalid layer relationship. Project b in layer #util")]
+        async fn errors_for_lower_layer_depending_on_higher() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(
+                        sandbox.path().join("a/moon.yml"),
+                        "dependsOn: [b]\ntags: [app]",
+                    );
+                    append_file(
+                        sandbox.path().join("b/moon.yml"),
+                        "dependsOn: [c]\ntags: [util]",
+                    );
+                    append_file(sandbox.path().join("c/moon.yml"), "tags: [app]");
+                },
+                |mock| {
+                    mock.workspace_config.constraints.layers =
+                        vec![Id::raw("app"), Id::raw("util")];
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        async fn allows_max_dependency_depth() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(sandbox.path().join("a/moon.yml"), "dependsOn: [b]");
+                    append_file(sandbox.path().join("b/moon.yml"), "dependsOn: [c]");
+                },
+                |mock| {
+                    mock.workspace_config.constraints.max_dependency_depth = Some(2);
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Project a has a dependency chain with a depth of 2")]
+        async fn errors_when_exceeding_max_dependency_depth() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(sandbox.path().join("a/moon.yml"), "dependsOn: [b]");
+                    append_file(sandbox.path().join("b/moon.yml"), "dependsOn: [c]");
+                },
+                |mock| {
+                    mock.workspace_config.constraints.max_dependency_depth = Some(1);
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Found 2 project constraint violations.")]
+        async fn reports_all_violations_together() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(
+                        sandbox.path().join("a/moon.yml"),
+                        "dependsOn: [b, c]\ntags: [util]",
+                    );
+                    append_file(sandbox.path().join("b/moon.yml"), "tags: [app]");
+                },
+                |mock| {
+                    mock.workspace_config.constraints.layers =
+                        vec![Id::raw("app"), Id::raw("util")];
+                    mock.workspace_config
+                        .constraints
+                        .banned_dependencies
+                        .insert("a".into(), vec!["c".into()]);
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Project a is missing the tasks test, which are required for")]
+        async fn errors_for_missing_required_tasks() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(
+                        sandbox.path().join("a/moon.yml"),
+                        "tasks:\n  build:\n    command: build",
+                    );
+                },
+                |mock| {
+                    mock.workspace_config
+                        .constraints
+                        .required_tasks
+                        .insert("library".into(), vec![Id::raw("build"), Id::raw("test")]);
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        async fn allows_required_tasks_for_other_tags() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(sandbox.path().join("a/moon.yml"), "tags: [app]");
+                },
+                |mock| {
+                    mock.workspace_config
+                        .constraints
+                        .required_tasks
+                        .insert("#web".into(), vec![Id::raw("build")]);
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Banned task dependency. Task a:build cannot depend on task")]
+        async fn errors_for_banned_task_dependency() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(
+                        sandbox.path().join("a/moon.yml"),
+                        "tasks:\n  build:\n    command: build\n    deps: ['b:deploy']",
+                    );
+                    append_file(
+                        sandbox.path().join("b/moon.yml"),
+                        "tasks:\n  deploy:\n    command: deploy",
+                    );
+                },
+                |mock| {
+                    mock.workspace_config.constraints.banned_task_dependencies =
+                        vec![Target::parse(":deploy").unwrap()];
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        async fn allows_unbanned_task_dependency() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(
+                        sandbox.path().join("a/moon.yml"),
+                        "tasks:\n  build:\n    command: build\n    deps: ['b:build']",
+                    );
+                    append_file(
+                        sandbox.path().join("b/moon.yml"),
+                        "tasks:\n  build:\n    command: build",
+                    );
+                },
+                |mock| {
+                    mock.workspace_config.constraints.banned_task_dependencies =
+                        vec![Target::parse(":deploy").unwrap()];
+                },
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Task a:build cannot depend on task b:local")]
+        async fn errors_for_run_in_ci_task_dependency() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(
+                        sandbox.path().join("a/moon.yml"),
+                        "tasks:\n  build:\n    command: build\n    deps: ['b:local']",
+                    );
+                    append_file(
+                        sandbox.path().join("b/moon.yml"),
+                        "tasks:\n  local:\n    command: local\n    options:\n      runInCI: false",
+                    );
+                },
+                |_| {},
+            )
+            .await;
+        }
+
+        #[tokio::test]
+        async fn allows_run_in_ci_task_dependency_when_disabled() {
+            generate_dependency_constraints_project_graph(
+                |sandbox| {
+                    append_file(
+                        sandbox.path().join("a/moon.yml"),
+                        "tasks:\n  build:\n    command: build\n    deps: ['b:local']",
+                    );
+                    append_file(
+                        sandbox.path().join("b/moon.yml"),
+                        "tasks:\n  local:\n    command: local\n    options:\n      runInCI: false",
+                    );
+                },
+                |mock| {
+                    mock.workspace_config
+                        .constraints
+                        .enforce_run_in_ci_dependencies = false;
+                },
+            )
+            .await;
+        }
+    }
+
     mod query {
         use super::*;
 
@@ -1513,6 +1931,129 @@ mod project_graph {
         }
     }
 
+    mod analysis {
+        use super::*;
+
+        #[tokio::test]
+        async fn returns_node_stats() {
+            let graph = generate_workspace_graph("dependencies").await;
+            let stats = graph.projects.get_node_stats();
+
+            assert_eq!(
+                stats[0],
+                GraphNodeStats {
+                    id: Id::raw("c"),
+                    blast_radius: 3,
+                    fan_out: 0,
+                    fan_in: 2,
+                }
+            );
+
+            let d = stats.iter().find(|node| node.id == "d").unwrap();
+
+            assert_eq!(d.blast_radius, 0);
+            assert_eq!(d.fan_in, 0);
+            assert_eq!(d.fan_out, 3);
+        }
+
+        #[tokio::test]
+        async fn returns_longest_chains() {
+            let graph = generate_workspace_graph("dependencies").await;
+            let chains = graph.projects.get_longest_chains();
+
+            assert_eq!(chains[0], ["d", "a", "b", "c"]);
+            assert_eq!(chains.last().unwrap().len(), 1);
+        }
+
+        #[tokio::test]
+        async fn excludes_root_dependencies() {
+            let sandbox = create_sandbox("dependency-types");
+            let mock = create_workspace_graph_mocker(sandbox.path());
+
+            let graph = mock
+                .build_workspace_graph_for(&["from-root-task-deps"])
+                .await;
+
+            let stats = graph.projects.get_node_stats();
+
+            for node in stats {
+                assert_eq!(node.blast_radius, 0);
+                assert_eq!(node.fan_in, 0);
+                assert_eq!(node.fan_out, 0);
+            }
+
+            let chains = graph.projects.get_longest_chains();
+
+            assert!(chains.iter().all(|chain| chain.len() == 1));
+        }
+
+        #[tokio::test]
+        async fn finds_declared_cycles() {
+            let graph = generate_workspace_graph("cycle").await;
+
+            let mut cycles =
+                find_cycles(graph.get_projects().unwrap().into_iter().map(|project| {
+                    (
+                        project.id.clone(),
+                        project
+                            .dependencies
+                            .iter()
+                            .map(|dep| dep.id.clone())
+                            .collect(),
+                    )
+                }));
+
+            assert_eq!(cycles.len(), 1);
+
+            cycles[0].sort();
+
+            assert_eq!(cycles[0], ["a", "b", "c"]);
+        }
+
+        #[tokio::test]
+        async fn finds_no_cycles() {
+            let graph = generate_workspace_graph("dependencies").await;
+
+            let cycles = find_cycles(graph.get_projects().unwrap().into_iter().map(|project| {
+                (
+                    project.id.clone(),
+                    project
+                        .dependencies
+                        .iter()
+                        .map(|dep| dep.id.clone())
+                        .collect(),
+                )
+            }));
+
+            assert!(cycles.is_empty());
+        }
+    }
+
+    mod to_formats {
+        use super::*;
+
+        #[tokio::test]
+        async fn renders_mermaid() {
+            let graph = generate_workspace_graph("dependencies").await;
+
+            assert_snapshot!(graph.projects.to_mermaid());
+        }
+
+        #[tokio::test]
+        async fn renders_graphml() {
+            let graph = generate_workspace_graph("dependencies").await;
+
+            assert_snapshot!(graph.projects.to_graphml());
+        }
+
+        #[tokio::test]
+        async fn renders_cytoscape() {
+            let graph = generate_workspace_graph("dependencies").await;
+
+            assert_snapshot!(graph.projects.to_cytoscape().unwrap());
+        }
+    }
+
     mod custom_id {
         use super::*;
 
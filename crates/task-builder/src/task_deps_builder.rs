@@ -15,6 +15,7 @@ pub trait TasksQuerent {
 
 pub struct TaskDepsBuilder<'proj> {
     pub querent: Box<dyn TasksQuerent + 'proj>,
+    pub enforce_run_in_ci_dependencies: bool,
     pub project_id: &'proj Id,
     pub project_dependencies: &'proj [DependencyConfig],
     pub task: &'proj mut Task,
@@ -128,7 +129,10 @@ impl TaskDepsBuilder<'_> {
         }
 
         // Do not depend on tasks that can't run in CI
-        if !dep_task_options.run_in_ci && self.task.options.run_in_ci {
+        if self.enforce_run_in_ci_dependencies
+            && !dep_task_options.run_in_ci
+            && self.task.options.run_in_ci
+        {
             return Err(TasksBuilderError::RunInCiDepRequirement {
                 dep: dep_task_target.to_owned(),
                 task: self.task.target.to_owned(),
@@ -16,10 +16,11 @@ pub enum TasksBuilderError {
 
     #[diagnostic(code(task_builder::dependency::run_in_ci_mismatch))]
     #[error(
-        "Task {} cannot depend on task {}, as the dependency cannot run in CI because {} is disabled. Because of this, the pipeline will not run tasks correctly.",
+        "Task {} cannot depend on task {}, as the dependency cannot run in CI because {} is disabled. Because of this, the pipeline will not run tasks correctly.\n\nThis can be disabled with the {} setting.",
         .task.style(Style::Label),
         .dep.style(Style::Label),
         "options.runInCI".style(Style::Property),
+        "constraints.enforceRunInCiDependencies".style(Style::Property),
     )]
     RunInCiDepRequirement { dep: Target, task: Target },
 
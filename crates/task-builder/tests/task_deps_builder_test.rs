@@ -57,6 +57,7 @@ fn build_task_deps_with_data(task: &mut Task, data: FxHashMap<Target, TaskOption
             data,
             tag_ids: vec![],
         }),
+        enforce_run_in_ci_dependencies: true,
         project_id: &project_id,
         project_dependencies: &project_dependencies,
         task,
@@ -108,6 +109,37 @@ mod task_deps_builder {
         );
     }
 
+    #[test]
+    fn doesnt_error_if_dep_not_run_in_ci_and_not_enforced() {
+        let mut task = create_task();
+        task.options.run_in_ci = true;
+        task.deps
+            .push(TaskDependencyConfig::new(Target::parse("no-ci").unwrap()));
+
+        let project_id = Id::raw("project");
+
+        TaskDepsBuilder {
+            querent: Box::new(TestQuerent {
+                data: FxHashMap::from_iter([(
+                    Target::parse("project:no-ci").unwrap(),
+                    TaskOptions {
+                        run_in_ci: false,
+                        ..Default::default()
+                    },
+                )]),
+                tag_ids: vec![],
+            }),
+            enforce_run_in_ci_dependencies: false,
+            project_id: &project_id,
+            project_dependencies: &[],
+            task: &mut task,
+        }
+        .build()
+        .unwrap();
+
+        assert_eq!(task.deps.len(), 1);
+    }
+
     #[test]
     fn doesnt_errors_if_dep_run_in_ci() {
         let mut task = create_task();
@@ -221,6 +253,7 @@ mod task_deps_builder {
                     ]),
                     tag_ids: vec![],
                 }),
+                enforce_run_in_ci_dependencies: true,
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
                 task: &mut task,
@@ -262,6 +295,7 @@ mod task_deps_builder {
                     ]),
                     tag_ids: vec![],
                 }),
+                enforce_run_in_ci_dependencies: true,
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
                 task: &mut task,
@@ -298,6 +332,7 @@ mod task_deps_builder {
                     data: FxHashMap::default(),
                     tag_ids: vec![],
                 }),
+                enforce_run_in_ci_dependencies: true,
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
                 task: &mut task,
@@ -528,6 +563,7 @@ mod task_deps_builder {
                     ]),
                     tag_ids: vec![Id::raw("foo"), Id::raw("baz")],
                 }),
+                enforce_run_in_ci_dependencies: true,
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
                 task: &mut task,
@@ -563,6 +599,7 @@ mod task_deps_builder {
                     ]),
                     tag_ids: vec![Id::raw("foo"), Id::raw("baz")],
                 }),
+                enforce_run_in_ci_dependencies: true,
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
                 task: &mut task,
@@ -595,6 +632,7 @@ mod task_deps_builder {
                     data: FxHashMap::from_iter([]),
                     tag_ids: vec![Id::raw("foo"), Id::raw("baz")],
                 }),
+                enforce_run_in_ci_dependencies: true,
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
                 task: &mut task,
@@ -621,6 +659,7 @@ mod task_deps_builder {
                     )]),
                     tag_ids: vec![Id::raw("project")],
                 }),
+                enforce_run_in_ci_dependencies: true,
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
                 task: &mut task,
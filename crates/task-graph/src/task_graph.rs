@@ -194,6 +194,8 @@ impl GraphData<Task, DependencyType, Target> for TaskGraph {
     }
 }
 
+impl GraphAnalysis<Task, DependencyType, Target> for TaskGraph {}
+
 impl GraphConnections<Task, DependencyType, Target> for TaskGraph {}
 
 impl GraphConversions<Task, DependencyType, Target> for TaskGraph {}
@@ -201,3 +203,9 @@ impl GraphConversions<Task, DependencyType, Target> for TaskGraph {}
 impl GraphToDot<Task, DependencyType, Target> for TaskGraph {}
 
 impl GraphToJson<Task, DependencyType, Target> for TaskGraph {}
+
+impl GraphToMermaid<Task, DependencyType, Target> for TaskGraph {}
+
+impl GraphToGraphml<Task, DependencyType, Target> for TaskGraph {}
+
+impl GraphToCytoscape<Task, DependencyType, Target> for TaskGraph {}
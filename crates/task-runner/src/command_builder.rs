@@ -1,12 +1,17 @@
+use miette::IntoDiagnostic;
 use moon_action::ActionNode;
 use moon_action_context::ActionContext;
 use moon_app_context::AppContext;
 use moon_common::consts::PROTO_CLI_VERSION;
 use moon_config::TaskOptionAffectedFiles;
+use moon_pdk_api::{ExtendArgs, ExtendTaskCommandOutput};
 use moon_platform::PlatformManager;
 use moon_process::{Command, Shell, ShellType};
 use moon_project::Project;
 use moon_task::Task;
+use moon_toolchain_plugin::ToolchainRegistry;
+use std::env;
+use std::ffi::OsStr;
 use std::path::Path;
 use tracing::{debug, instrument, trace};
 
@@ -17,6 +22,7 @@ pub struct CommandBuilder<'task> {
     task: &'task Task,
     working_dir: &'task Path,
     platform_manager: &'task PlatformManager,
+    toolchain_registry: Option<&'task ToolchainRegistry>,
 
     // To be built
     command: Command,
@@ -42,6 +48,7 @@ impl<'task> CommandBuilder<'task> {
             task,
             working_dir,
             platform_manager: PlatformManager::read(),
+            toolchain_registry: None,
             command: Command::new("noop"),
         }
     }
@@ -50,6 +57,10 @@ impl<'task> CommandBuilder<'task> {
         self.platform_manager = manager;
     }
 
+    pub fn set_toolchain_registry(&mut self, registry: &'task ToolchainRegistry) {
+        self.toolchain_registry = Some(registry);
+    }
+
     #[instrument(name = "build_command", skip_all)]
     pub async fn build(mut self, context: &ActionContext) -> miette::Result<Command> {
         self.command = self
@@ -74,13 +85,6 @@ impl<'task> CommandBuilder<'task> {
             self.command.escape_args = false;
         }
 
-        debug!(
-            task_target = self.task.target.as_str(),
-            command = self.command.bin.to_str(),
-            working_dir = ?self.working_dir,
-            "Creating task command to execute",
-        );
-
         // We need to handle non-zero exit code's manually
         self.command
             .cwd(self.working_dir)
@@ -89,6 +93,18 @@ impl<'task> CommandBuilder<'task> {
         // Order is important!
         self.inject_args(context);
         self.inject_env();
+
+        if self.task.script.is_none() {
+            self.extend_with_toolchains().await?;
+        }
+
+        debug!(
+            task_target = self.task.target.as_str(),
+            command = self.command.bin.to_str(),
+            working_dir = ?self.working_dir,
+            "Creating task command to execute",
+        );
+
         self.inject_shell();
         self.inherit_affected(context)?;
         self.inherit_config();
@@ -96,6 +112,62 @@ impl<'task> CommandBuilder<'task> {
         Ok(self.command)
     }
 
+    /// Runs after args and env have been injected, so that plugins receive
+    /// the final command, and their env takes precedence over the task's env.
+    #[instrument(skip_all)]
+    async fn extend_with_toolchains(&mut self) -> miette::Result<()> {
+        let Some(registry) = self.toolchain_registry else {
+            return Ok(());
+        };
+
+        let context = registry.create_context();
+
+        for plugin_id in registry.get_plugin_ids() {
+            let plugin = registry.load(plugin_id).await?;
+
+            if !plugin.applies_to_task(self.project, self.task) {
+                continue;
+            }
+
+            let Some(output) = plugin
+                .extend_task_command(
+                    self.project,
+                    self.task,
+                    self.command.bin.to_string_lossy().to_string(),
+                    self.command
+                        .args
+                        .iter()
+                        .map(|arg| arg.to_string_lossy().to_string())
+                        .collect(),
+                    self.command
+                        .env
+                        .iter()
+                        .map(|(key, value)| {
+                            (
+                                key.to_string_lossy().to_string(),
+                                value.to_string_lossy().to_string(),
+                            )
+                        })
+                        .collect(),
+                    context.clone(),
+                )
+                .await?
+            else {
+                continue;
+            };
+
+            trace!(
+                task_target = self.task.target.as_str(),
+                toolchain_id = plugin_id.as_str(),
+                "Extending task command with toolchain plugin"
+            );
+
+            extend_command(&mut self.command, output)?;
+        }
+
+        Ok(())
+    }
+
     #[instrument(skip_all)]
     fn inject_args(&mut self, context: &ActionContext) {
         // Must be first!
@@ -300,3 +372,196 @@ impl<'task> CommandBuilder<'task> {
         }
     }
 }
+
+fn extend_command(command: &mut Command, output: ExtendTaskCommandOutput) -> miette::Result<()> {
+    if let Some(bin) = output.command {
+        command.bin = bin.into();
+    }
+
+    match output.args {
+        Some(ExtendArgs::Append(args)) => {
+            command.args(args);
+        }
+        Some(ExtendArgs::Prepend(args)) => {
+            let current = std::mem::take(&mut command.args);
+
+            command.args(args);
+            command.args.extend(current);
+        }
+        Some(ExtendArgs::Replace(args)) => {
+            command.args.clear();
+            command.args(args);
+        }
+        None => {}
+    };
+
+    command.envs(output.env);
+
+    for key in output.env_remove {
+        command.env.remove(OsStr::new(&key));
+    }
+
+    if !output.paths.is_empty() {
+        let mut paths = output.paths;
+
+        match command.env.get(OsStr::new("PATH")) {
+            Some(path) => paths.extend(env::split_paths(path)),
+            None => paths.extend(env::split_paths(&env::var_os("PATH").unwrap_or_default())),
+        };
+
+        command.env("PATH", env::join_paths(paths).into_diagnostic()?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn create_command() -> Command {
+        let mut command = Command::new("bin");
+        command.args(["arg", "--passthrough"]);
+        command.env("KEY", "task");
+        command.env("PATH", "/usr/bin");
+        command
+    }
+
+    fn get_args(command: &Command) -> Vec<&str> {
+        command
+            .args
+            .iter()
+            .map(|arg| arg.to_str().unwrap())
+            .collect()
+    }
+
+    fn get_env<'a>(command: &'a Command, key: &str) -> Option<&'a str> {
+        command
+            .env
+            .get(OsStr::new(key))
+            .map(|value| value.to_str().unwrap())
+    }
+
+    #[test]
+    fn overrides_command() {
+        let mut command = create_command();
+
+        extend_command(
+            &mut command,
+            ExtendTaskCommandOutput {
+                command: Some("other".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(command.bin, "other");
+        assert_eq!(get_args(&command), vec!["arg", "--passthrough"]);
+    }
+
+    #[test]
+    fn appends_args_after_passthrough_args() {
+        let mut command = create_command();
+
+        extend_command(
+            &mut command,
+            ExtendTaskCommandOutput {
+                args: Some(ExtendArgs::Append(vec!["--plugin".into()])),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(get_args(&command), vec!["arg", "--passthrough", "--plugin"]);
+    }
+
+    #[test]
+    fn prepends_args() {
+        let mut command = create_command();
+
+        extend_command(
+            &mut command,
+            ExtendTaskCommandOutput {
+                args: Some(ExtendArgs::Prepend(vec!["run".into()])),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(get_args(&command), vec!["run", "arg", "--passthrough"]);
+    }
+
+    #[test]
+    fn replaces_args() {
+        let mut command = create_command();
+
+        extend_command(
+            &mut command,
+            ExtendTaskCommandOutput {
+                args: Some(ExtendArgs::Replace(vec!["new".into()])),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(get_args(&command), vec!["new"]);
+    }
+
+    #[test]
+    fn env_takes_precedence_over_task_env() {
+        let mut command = create_command();
+
+        extend_command(
+            &mut command,
+            ExtendTaskCommandOutput {
+                env: [
+                    ("KEY".into(), "plugin".into()),
+                    ("OTHER".into(), "value".into()),
+                ]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(get_env(&command, "KEY"), Some("plugin"));
+        assert_eq!(get_env(&command, "OTHER"), Some("value"));
+    }
+
+    #[test]
+    fn removes_env() {
+        let mut command = create_command();
+
+        extend_command(
+            &mut command,
+            ExtendTaskCommandOutput {
+                env_remove: vec!["KEY".into()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(get_env(&command, "KEY"), None);
+    }
+
+    #[test]
+    fn prepends_paths() {
+        let mut command = create_command();
+
+        extend_command(
+            &mut command,
+            ExtendTaskCommandOutput {
+                paths: vec![PathBuf::from("/plugin/bin")],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            env::split_paths(command.env.get(OsStr::new("PATH")).unwrap()).collect::<Vec<_>>(),
+            vec![PathBuf::from("/plugin/bin"), PathBuf::from("/usr/bin")]
+        );
+    }
+}
@@ -17,8 +17,10 @@ use moon_remote::{Digest, RemoteService};
 use moon_task::Task;
 use moon_task_hasher::TaskHasher;
 use moon_time::{is_stale, now_millis};
+use moon_toolchain_plugin::ToolchainRegistry;
 use starbase_utils::fs;
 use std::collections::BTreeMap;
+use std::sync::Arc;
 use std::time::SystemTime;
 use tracing::{debug, instrument, trace};
 
@@ -34,6 +36,7 @@ pub struct TaskRunner<'task> {
     project: &'task Project,
     pub task: &'task Task,
     platform_manager: &'task PlatformManager,
+    toolchain_registry: Option<Arc<ToolchainRegistry>>,
 
     archiver: OutputArchiver<'task>,
     hydrater: OutputHydrater<'task>,
@@ -80,6 +83,7 @@ impl<'task> TaskRunner<'task> {
                 ..Default::default()
             },
             task,
+            toolchain_registry: None,
             app,
             operations: OperationList::default(),
         })
@@ -89,6 +93,10 @@ impl<'task> TaskRunner<'task> {
         self.platform_manager = manager;
     }
 
+    pub fn set_toolchain_registry(&mut self, registry: Arc<ToolchainRegistry>) {
+        self.toolchain_registry = Some(registry);
+    }
+
     async fn internal_run(
         &mut self,
         context: &ActionContext,
@@ -451,6 +459,28 @@ impl<'task> TaskRunner<'task> {
             )
             .await?;
 
+        // Hash toolchain plugin fields
+        if let Some(registry) = &self.toolchain_registry {
+            let context = registry.create_context();
+
+            for plugin_id in registry.get_plugin_ids() {
+                let plugin = registry.load(plugin_id).await?;
+
+                if !plugin.applies_to_task(self.project, self.task) {
+                    continue;
+                }
+
+                if let Some(result) = plugin
+                    .hash_task_contents(self.project, self.task, context.clone())
+                    .await?
+                {
+                    for content in result.contents {
+                        hasher.hash_content(content)?;
+                    }
+                }
+            }
+        }
+
         let (hash, size_bytes) = hash_engine.save_manifest(hasher)?;
 
         operation.meta.set_hash(&hash);
@@ -494,6 +524,10 @@ impl<'task> TaskRunner<'task> {
         let mut builder = CommandBuilder::new(self.app, self.project, self.task, node);
         builder.set_platform_manager(self.platform_manager);
 
+        if let Some(registry) = &self.toolchain_registry {
+            builder.set_toolchain_registry(registry);
+        }
+
         let command = builder.build(context).await?;
 
         // Execute the command and gather all attempts made
@@ -108,6 +108,8 @@ impl WorkspaceMocker {
             extend_project: Emitter::<ExtendProjectEvent>::new(),
             extend_project_graph: Emitter::<ExtendProjectGraphEvent>::new(),
             inherited_tasks: &self.inherited_tasks,
+            plugin_file_names: vec![],
+            plugin_versions: BTreeMap::default(),
             toolchain_config: &self.toolchain_config,
             vcs: self.vcs.clone(),
             working_dir: &self.workspace_root,
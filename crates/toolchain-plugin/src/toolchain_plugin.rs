@@ -1,10 +1,18 @@
 use async_trait::async_trait;
+use moon_common::path::WorkspaceRelativePath;
+use moon_common::Id;
+use moon_config::ProjectsSourcesList;
 use moon_pdk_api::{
-    MoonContext, SyncWorkspaceInput, SyncWorkspaceOutput, ToolchainMetadataInput,
-    ToolchainMetadataOutput,
+    ExtendProjectGraphInput, ExtendProjectGraphOutput, ExtendProjectInput, ExtendProjectOutput,
+    ExtendTaskCommandInput, ExtendTaskCommandOutput, HashTaskContentsInput, HashTaskContentsOutput,
+    MoonContext, ProjectFragment, SyncProjectInput, SyncProjectOutput, SyncWorkspaceInput,
+    SyncWorkspaceOutput, TaskFragment, ToolchainMetadataInput, ToolchainMetadataOutput,
 };
 use moon_plugin::{Plugin, PluginContainer, PluginId, PluginRegistration, PluginType};
+use moon_project::Project;
+use moon_task::Task;
 use proto_core::Tool;
+use rustc_hash::FxHashMap;
 use std::fmt;
 use std::sync::Arc;
 use tracing::{debug, instrument};
@@ -20,6 +28,88 @@ pub struct ToolchainPlugin {
 }
 
 impl ToolchainPlugin {
+    /// Return true if the toolchain is used by the project, either through
+    /// its language or platform, or by containing a known manifest file.
+    pub fn applies_to_project(&self, project: &Project) -> bool {
+        let id = self.id.as_str();
+
+        if project.language.to_string() == id || project.platform.to_string() == id {
+            return true;
+        }
+
+        self.metadata
+            .manifest_file_names
+            .iter()
+            .any(|name| project.root.join(name).exists())
+    }
+
+    /// Return true if the toolchain is used by the task's platform,
+    /// or by the task's project.
+    pub fn applies_to_task(&self, project: &Project, task: &Task) -> bool {
+        task.platform.to_string() == self.id.as_str() || self.applies_to_project(project)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn extend_project_graph(
+        &self,
+        project_sources: &ProjectsSourcesList,
+        context: MoonContext,
+    ) -> miette::Result<Option<ExtendProjectGraphOutput>> {
+        if !self.plugin.has_func("extend_project_graph").await {
+            return Ok(None);
+        }
+
+        debug!(toolchain_id = self.id.as_str(), "Extending project graph");
+
+        let output: ExtendProjectGraphOutput = self
+            .plugin
+            .call_func_with(
+                "extend_project_graph",
+                ExtendProjectGraphInput {
+                    context,
+                    project_sources: project_sources
+                        .iter()
+                        .map(|(id, source)| (id.to_owned(), source.to_string()))
+                        .collect(),
+                },
+            )
+            .await?;
+
+        Ok(Some(output))
+    }
+
+    #[instrument(skip_all)]
+    pub async fn extend_project(
+        &self,
+        project_id: &Id,
+        project_source: &WorkspaceRelativePath,
+        context: MoonContext,
+    ) -> miette::Result<Option<ExtendProjectOutput>> {
+        if !self.plugin.has_func("extend_project").await {
+            return Ok(None);
+        }
+
+        debug!(
+            toolchain_id = self.id.as_str(),
+            project_id = project_id.as_str(),
+            "Extending project"
+        );
+
+        let output: ExtendProjectOutput = self
+            .plugin
+            .call_func_with(
+                "extend_project",
+                ExtendProjectInput {
+                    context,
+                    project_id: project_id.to_owned(),
+                    project_source: project_source.to_string(),
+                },
+            )
+            .await?;
+
+        Ok(Some(output))
+    }
+
     #[instrument(skip_all)]
     pub async fn sync_workspace(
         &self,
@@ -39,32 +129,110 @@ impl ToolchainPlugin {
         Ok(Some(output))
     }
 
-    // #[instrument(skip_all)]
-    // pub async fn sync_project(
-    //     &self,
-    //     project: SyncProjectRecord,
-    //     dependencies: FxHashMap<Id, SyncProjectRecord>,
-    //     context: MoonContext,
-    // ) -> miette::Result<()> {
-    //     if !self.plugin.has_func("sync_project").await {
-    //         return Ok(());
-    //     }
-
-    //     debug!(toolchain_id = self.id.as_str(), "Syncing project");
-
-    //     self.plugin
-    //         .call_func_without_output(
-    //             "sync_project",
-    //             SyncProjectInput {
-    //                 context,
-    //                 dependencies,
-    //                 project,
-    //             },
-    //         )
-    //         .await?;
-
-    //     Ok(())
-    // }
+    #[instrument(skip_all)]
+    pub async fn sync_project(
+        &self,
+        project: &Project,
+        dependencies: &[&Project],
+        context: MoonContext,
+    ) -> miette::Result<Option<SyncProjectOutput>> {
+        if !self.plugin.has_func("sync_project").await {
+            return Ok(None);
+        }
+
+        debug!(
+            toolchain_id = self.id.as_str(),
+            project_id = project.id.as_str(),
+            "Syncing project"
+        );
+
+        let output: SyncProjectOutput = self
+            .plugin
+            .call_func_with(
+                "sync_project",
+                SyncProjectInput {
+                    context,
+                    dependencies: dependencies
+                        .iter()
+                        .map(|dep| create_project_fragment(dep))
+                        .collect(),
+                    project: create_project_fragment(project),
+                },
+            )
+            .await?;
+
+        Ok(Some(output))
+    }
+
+    #[instrument(skip_all)]
+    pub async fn hash_task_contents(
+        &self,
+        project: &Project,
+        task: &Task,
+        context: MoonContext,
+    ) -> miette::Result<Option<HashTaskContentsOutput>> {
+        if !self.plugin.has_func("hash_task_contents").await {
+            return Ok(None);
+        }
+
+        debug!(
+            toolchain_id = self.id.as_str(),
+            task_target = task.target.as_str(),
+            "Hashing task contents"
+        );
+
+        let output: HashTaskContentsOutput = self
+            .plugin
+            .call_func_with(
+                "hash_task_contents",
+                HashTaskContentsInput {
+                    context,
+                    project: create_project_fragment(project),
+                    task: create_task_fragment(task),
+                },
+            )
+            .await?;
+
+        Ok(Some(output))
+    }
+
+    #[instrument(skip_all)]
+    pub async fn extend_task_command(
+        &self,
+        project: &Project,
+        task: &Task,
+        command: String,
+        args: Vec<String>,
+        env: FxHashMap<String, String>,
+        context: MoonContext,
+    ) -> miette::Result<Option<ExtendTaskCommandOutput>> {
+        if !self.plugin.has_func("extend_task_command").await {
+            return Ok(None);
+        }
+
+        debug!(
+            toolchain_id = self.id.as_str(),
+            task_target = task.target.as_str(),
+            "Extending task command"
+        );
+
+        let output: ExtendTaskCommandOutput = self
+            .plugin
+            .call_func_with(
+                "extend_task_command",
+                ExtendTaskCommandInput {
+                    args,
+                    command,
+                    context,
+                    env,
+                    project: create_project_fragment(project),
+                    task: create_task_fragment(task),
+                },
+            )
+            .await?;
+
+        Ok(Some(output))
+    }
 }
 
 #[async_trait]
@@ -115,3 +283,20 @@ impl fmt::Debug for ToolchainPlugin {
             .finish()
     }
 }
+
+fn create_project_fragment(project: &Project) -> ProjectFragment {
+    ProjectFragment {
+        alias: project.alias.clone(),
+        id: project.id.clone(),
+        language: project.language.clone(),
+        source: project.source.to_string(),
+        type_of: project.type_of,
+    }
+}
+
+fn create_task_fragment(task: &Task) -> TaskFragment {
+    TaskFragment {
+        target: task.target.clone(),
+        type_of: task.type_of,
+    }
+}
@@ -41,6 +41,11 @@ impl ToolchainRegistry {
         let mut set = JoinSet::new();
 
         for (id, config) in self.configs.clone() {
+            // May have already been loaded while building the workspace graph
+            if self.registry.is_registered(&id) {
+                continue;
+            }
+
             let registry = Arc::clone(&self.registry);
 
             set.spawn(async move {
@@ -3,6 +3,7 @@ use moon_config::{VcsConfig, VcsHookFormat};
 use moon_vcs::BoxedVcs;
 use rustc_hash::FxHashMap;
 use starbase_utils::fs;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use tracing::{debug, instrument, warn};
 
@@ -91,12 +92,35 @@ impl<'app> HooksGenerator<'app> {
 
         debug!("Generating {} hooks", self.config.manager);
 
-        self.sync_to_vcs(self.create_hooks()?).await?;
+        for (file_path, contents) in self.render().await? {
+            fs::write_file(&file_path, contents)?;
+            fs::update_perms(&file_path, Some(0o0775))?;
+        }
 
         Ok(true)
     }
 
-    fn create_hooks(&self) -> miette::Result<FxHashMap<&'app String, PathBuf>> {
+    /// Render the contents of all hook files (both local and within the VCS)
+    /// without writing them, keyed by their absolute file path.
+    #[instrument(skip_all)]
+    pub async fn render(&self) -> miette::Result<BTreeMap<PathBuf, String>> {
+        let mut files = BTreeMap::default();
+
+        if !self.vcs.is_enabled() {
+            return Ok(files);
+        }
+
+        let hooks = self.create_hooks(&mut files);
+
+        self.sync_to_vcs(hooks, &mut files).await?;
+
+        Ok(files)
+    }
+
+    fn create_hooks(
+        &self,
+        files: &mut BTreeMap<PathBuf, String>,
+    ) -> FxHashMap<&'app String, PathBuf> {
         let mut hooks = FxHashMap::default();
 
         for (hook_name, commands) in &self.config.hooks {
@@ -112,15 +136,19 @@ impl<'app> HooksGenerator<'app> {
 
             debug!(file = ?hook_path, "Creating {} hook", color::file(hook_name));
 
-            self.create_hook_file(&hook_path, commands, true)?;
+            files.insert(hook_path.clone(), self.render_hook_file(commands, true));
 
             hooks.insert(hook_name, hook_path);
         }
 
-        Ok(hooks)
+        hooks
     }
 
-    async fn sync_to_vcs(&self, hooks: FxHashMap<&'app String, PathBuf>) -> miette::Result<()> {
+    async fn sync_to_vcs(
+        &self,
+        hooks: FxHashMap<&'app String, PathBuf>,
+        files: &mut BTreeMap<PathBuf, String>,
+    ) -> miette::Result<()> {
         let hooks_dir = self.vcs.get_hooks_dir().await?;
         let repo_root = self.vcs.get_repository_root().await?;
 
@@ -144,14 +172,16 @@ impl<'app> HooksGenerator<'app> {
             // itself and run Bash commands within it.
             if self.is_bash_format() || cfg!(not(windows)) {
                 // pre-commit
-                self.create_hook_file(
-                    &external_path,
-                    &[format!(
-                        "{} $1 $2 $3",
-                        path::to_virtual_string(external_command)?
-                    )],
-                    false,
-                )?;
+                files.insert(
+                    external_path,
+                    self.render_hook_file(
+                        &[format!(
+                            "{} $1 $2 $3",
+                            path::to_virtual_string(external_command)?
+                        )],
+                        false,
+                    ),
+                );
             }
             // On Windows, the hook file itself is extensionless, which means we can't use PowerShell.
             // Instead we will execute our .ps1 script through PowerShell.
@@ -164,32 +194,20 @@ impl<'app> HooksGenerator<'app> {
                 };
 
                 // pre-commit
-                self.create_file(
-                    &external_path,
+                files.insert(
+                    external_path,
                     format!(
                         "#!/bin/sh\n{} -NoLogo -NoProfile -ExecutionPolicy Bypass -File \"{}\" $1 $2 $3",
                         powershell_exe, external_command.display()
                     ),
-                )?;
+                );
             }
         }
 
         Ok(())
     }
 
-    fn create_file(&self, file_path: &Path, contents: String) -> miette::Result<()> {
-        fs::write_file(file_path, contents)?;
-        fs::update_perms(file_path, Some(0o0775))?;
-
-        Ok(())
-    }
-
-    fn create_hook_file(
-        &self,
-        file_path: &Path,
-        commands: &[String],
-        with_header: bool,
-    ) -> miette::Result<()> {
+    fn render_hook_file(&self, commands: &[String], with_header: bool) -> String {
         let mut contents = vec![];
 
         if self.is_bash_format() {
@@ -219,9 +237,7 @@ impl<'app> HooksGenerator<'app> {
         }
         contents.push("\n");
 
-        self.create_file(file_path, contents.join("\n"))?;
-
-        Ok(())
+        contents.join("\n")
     }
 
     fn is_bash_format(&self) -> bool {
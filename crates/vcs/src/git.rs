@@ -11,6 +11,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rustc_hash::FxHashSet;
 use semver::Version;
+use starbase_utils::fs;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -25,6 +26,10 @@ pub static DIFF_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(A|D|M|T|U|X)$
 
 pub static DIFF_SCORE_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(C|M|R)(\d{3})$").unwrap());
 
+const UNSTAGED_PATCH: &str = "moon-unstaged.patch";
+
+const UNSTAGED_STASH_MESSAGE: &str = "moon: backup of unstaged changes";
+
 pub static VERSION_CLEAN: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"\.(windows|win|msysgit|msys|vfs)(\.\d+){1,2}").unwrap());
 
@@ -75,6 +80,28 @@ pub enum GitError {
         error: Box<ignore::Error>,
     },
 
+    #[diagnostic(code(git::index::load_failed))]
+    #[error("Failed to load the git index.")]
+    LoadIndexFailed {
+        #[source]
+        error: Box<gix::worktree::open_index::Error>,
+    },
+
+    #[diagnostic(code(git::object::load_failed))]
+    #[error("Failed to load git object {}.", .id.style(Style::Hash))]
+    LoadObjectFailed {
+        id: String,
+        #[source]
+        error: Box<gix::object::find::existing::Error>,
+    },
+
+    #[diagnostic(
+        code(git::unstaged::restore_failed),
+        help = "Unstaged changes have been kept in the patch file, and in a backup stash named \"moon: backup of unstaged changes\" (view with `git stash list`), which can be restored with `git stash pop`."
+    )]
+    #[error("Failed to restore unstaged changes from patch {}.", .path.style(Style::Path))]
+    RestoreUnstagedFailed { path: PathBuf },
+
     #[diagnostic(code(git::repository::extract_slug))]
     #[error("Failed to extract a repository slug from git remote candidates.")]
     ExtractRepoSlugFailed,
@@ -310,7 +337,9 @@ impl Git {
         base_revision: &str,
         revision: &str,
     ) -> miette::Result<TouchedFiles> {
-        let base = self.get_merge_base(base_revision, revision).await?;
+        let base = self
+            .get_merge_base_revision(base_revision, revision)
+            .await?;
 
         let output = self
             .process
@@ -326,7 +355,7 @@ impl Git {
                         // We use this option so that file names with special characters
                         // are displayed as-is and are not quoted/escaped
                         "-z",
-                        base.as_ref().map(|b| b.as_str()).unwrap_or(base_revision),
+                        base.as_str(),
                     ],
                     module.path.as_str(),
                 ),
@@ -543,7 +572,15 @@ impl Git {
         })
     }
 
-    fn to_workspace_relative_path(&self, value: &str) -> WorkspaceRelativePathBuf {
+    /// Return true if the provided workspace relative directory
+    /// is within a submodule (excluding the root).
+    pub(crate) fn is_within_submodule(&self, dir: &str) -> bool {
+        self.modules
+            .values()
+            .any(|module| !module.is_root() && dir.starts_with(module.path.as_str()))
+    }
+
+    pub(crate) fn to_workspace_relative_path(&self, value: &str) -> WorkspaceRelativePathBuf {
         let file = WorkspaceRelativePathBuf::from(value);
 
         // Convert the prefixed path back to a workspace relative one...
@@ -652,6 +689,55 @@ impl Vcs for Git {
         Ok(map)
     }
 
+    #[instrument(skip(self))]
+    async fn get_file_content_at_revision(
+        &self,
+        file: &str, // Workspace relative
+        revision: &str,
+    ) -> miette::Result<Option<Arc<String>>> {
+        let object = format!("{revision}:./{file}");
+
+        // An empty revision reads from the index, otherwise the revision
+        // must exist, so that only a missing file is treated as none
+        if !revision.is_empty() {
+            self.process
+                .run(
+                    ["rev-parse", "--verify", &format!("{revision}^{{tree}}")],
+                    true,
+                )
+                .await?;
+        }
+
+        if self
+            .process
+            .run(["cat-file", "-e", &object], true)
+            .await
+            .is_err()
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(self.process.run(["show", &object], false).await?))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_file_tree_at_revision(
+        &self,
+        dir: &str, // Workspace relative
+        revision: &str,
+    ) -> miette::Result<Vec<WorkspaceRelativePathBuf>> {
+        let output = self
+            .process
+            .run(["ls-tree", "-r", "--name-only", revision, dir], false)
+            .await?;
+
+        Ok(output
+            .split('\n')
+            .filter(|file| !file.is_empty())
+            .map(WorkspaceRelativePathBuf::from)
+            .collect())
+    }
+
     #[instrument(skip(self))]
     async fn get_file_tree(&self, dir: &str) -> miette::Result<Vec<WorkspaceRelativePathBuf>> {
         // Check to see if the requested dir is within a submodule
@@ -734,6 +820,33 @@ impl Vcs for Git {
         Err(GitError::ExtractRepoSlugFailed.into())
     }
 
+    async fn get_merge_base_revision(
+        &self,
+        base_revision: &str,
+        revision: &str,
+    ) -> miette::Result<Arc<String>> {
+        Ok(self
+            .get_merge_base(base_revision, revision)
+            .await?
+            .unwrap_or_else(|| Arc::new(base_revision.to_owned())))
+    }
+
+    async fn get_previous_revision(&self, revision: &str) -> miette::Result<Arc<String>> {
+        // If there's only 1 commit on the revision,
+        // then the diff command will error. So let's
+        // extract the commit count and handle accordingly.
+        let output = self
+            .process
+            .run(["rev-list", "--count", revision], true)
+            .await?;
+
+        Ok(Arc::new(if output.as_str() == "0" || output.is_empty() {
+            revision.to_owned()
+        } else {
+            format!("{revision}~1")
+        }))
+    }
+
     async fn get_touched_files(&self) -> miette::Result<TouchedFiles> {
         let mut touched_files = TouchedFiles::default();
 
@@ -758,19 +871,7 @@ impl Vcs for Git {
             revision
         };
 
-        // If there's only 1 commit on the revision,
-        // then the diff command will error. So let's
-        // extract the commit count and handle accordingly.
-        let output = self
-            .process
-            .run(["rev-list", "--count", revision], true)
-            .await?;
-
-        let prev_revision = if output.as_str() == "0" || output.is_empty() {
-            revision.to_owned()
-        } else {
-            format!("{revision}~1")
-        };
+        let prev_revision = self.get_previous_revision(revision).await?;
 
         self.get_touched_files_between_revisions(&prev_revision, revision)
             .await
@@ -855,6 +956,148 @@ impl Vcs for Git {
 
         Ok(result)
     }
+
+    /// Save unstaged changes to a patch file (and a backup stash), and then
+    /// reset tracked files in the working tree to the index. Untracked files
+    /// are left as-is.
+    #[instrument(skip(self))]
+    async fn hide_unstaged_changes(&self) -> miette::Result<bool> {
+        let patch = self
+            .process
+            .run_command_without_cache(
+                self.process.create_command([
+                    "--no-pager",
+                    "diff",
+                    "--binary",
+                    "--no-color",
+                    "--no-ext-diff",
+                    "--ignore-submodules",
+                    "--",
+                    ".",
+                ]),
+                false,
+            )
+            .await?;
+
+        if patch.trim().is_empty() {
+            debug!("No unstaged changes to hide");
+
+            return Ok(false);
+        }
+
+        let patch_path = self.git_root.join(UNSTAGED_PATCH);
+
+        debug!(patch = ?patch_path, "Hiding unstaged changes");
+
+        // Keep a backup in case the changes can't be restored
+        let backup = self
+            .process
+            .run_command_without_cache(self.process.create_command(["stash", "create"]), true)
+            .await?;
+
+        if !backup.is_empty() {
+            self.process
+                .run_command_without_cache(
+                    self.process.create_command([
+                        "stash",
+                        "store",
+                        "--message",
+                        UNSTAGED_STASH_MESSAGE,
+                        backup.as_str(),
+                    ]),
+                    true,
+                )
+                .await?;
+        }
+
+        fs::write_file(&patch_path, patch.as_bytes())?;
+
+        self.process
+            .run_command_without_cache(
+                self.process
+                    .create_command(["checkout", "--force", "--", "."]),
+                true,
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Apply the patch file of unstaged changes, falling back to a 3-way
+    /// merge if the staged files were modified in the meantime.
+    #[instrument(skip(self))]
+    async fn restore_unstaged_changes(&self) -> miette::Result<()> {
+        let patch_path = self.git_root.join(UNSTAGED_PATCH);
+
+        if !patch_path.exists() {
+            return Ok(());
+        }
+
+        debug!(patch = ?patch_path, "Restoring unstaged changes");
+
+        let patch_arg = patch_path.to_string_lossy().to_string();
+        let apply_args = ["apply", "--whitespace=nowarn", "--recount"];
+
+        if self
+            .process
+            .run_command_without_cache(
+                self.process
+                    .create_command(apply_args.iter().chain([&patch_arg.as_str()])),
+                true,
+            )
+            .await
+            .is_err()
+        {
+            debug!("Failed to apply unstaged changes, attempting a 3-way merge");
+
+            self.process
+                .run_command_without_cache(
+                    self.process
+                        .create_command(apply_args.iter().chain([&"--3way", &patch_arg.as_str()])),
+                    true,
+                )
+                .await
+                .map_err(|_| GitError::RestoreUnstagedFailed {
+                    path: patch_path.clone(),
+                })?;
+        }
+
+        fs::remove_file(&patch_path)?;
+
+        // Changes were restored, so the backup is no longer necessary
+        let last_stash = self
+            .process
+            .run_command_without_cache(
+                self.process
+                    .create_command(["stash", "list", "-n", "1", "--format=%gs"]),
+                true,
+            )
+            .await?;
+
+        if last_stash.as_str() == UNSTAGED_STASH_MESSAGE {
+            self.process
+                .run_command_without_cache(self.process.create_command(["stash", "drop"]), true)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn stage_files(&self, files: &[String]) -> miette::Result<()> {
+        if files.is_empty() {
+            return Ok(());
+        }
+
+        let mut args = vec!["add", "--"];
+        args.extend(files.iter().map(|file| file.as_str()));
+
+        self.process
+            .run_command_without_cache(self.process.create_command(args), true)
+            .await?;
+
+        Ok(())
+    }
 }
 
 fn extract_gitdir_from_worktree(git_file: &Path) -> miette::Result<PathBuf> {
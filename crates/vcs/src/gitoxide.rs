@@ -0,0 +1,367 @@
+use crate::git::{Git, GitError};
+use crate::touched_files::TouchedFiles;
+use crate::vcs::Vcs;
+use async_trait::async_trait;
+use gix::bstr::{BStr, ByteSlice};
+use gix::index::entry::{stat, Mode, Stat};
+use ignore::WalkBuilder;
+use moon_common::path::WorkspaceRelativePathBuf;
+use semver::Version;
+use starbase_utils::fs;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, instrument, warn};
+
+/// A git adapter that reads the repository and its index in-process using
+/// gitoxide, instead of spawning a `git` process for each query. Queries that
+/// are not supported natively fall back to the `git` binary.
+#[derive(Debug)]
+pub struct Gitoxide {
+    /// The `git` binary adapter, used as a fallback.
+    pub git: Git,
+
+    /// The repository, when it could be opened.
+    repository: Option<gix::ThreadSafeRepository>,
+}
+
+impl Gitoxide {
+    pub fn load<R: AsRef<Path>, B: AsRef<str>>(
+        workspace_root: R,
+        default_branch: B,
+        remote_candidates: &[String],
+    ) -> miette::Result<Gitoxide> {
+        let workspace_root = workspace_root.as_ref();
+        let git = Git::load(workspace_root, default_branch, remote_candidates)?;
+
+        debug!("Using gitoxide as the git backend");
+
+        let repository = if git.git_root.exists() {
+            match gix::ThreadSafeRepository::discover(workspace_root) {
+                Ok(repository) => Some(repository),
+                Err(error) => {
+                    warn!(
+                        "Failed to open repository with gitoxide, falling back to the git binary: {error}"
+                    );
+
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        Ok(Gitoxide { git, repository })
+    }
+
+    fn load_index(repository: &gix::ThreadSafeRepository) -> miette::Result<gix::worktree::Index> {
+        Ok(repository
+            .to_thread_local()
+            .index_or_empty()
+            .map_err(|error| GitError::LoadIndexFailed {
+                error: Box::new(error),
+            })?)
+    }
+
+    /// Convert a workspace relative path to a repository relative path.
+    fn to_repository_path(&self, file: &str) -> String {
+        match &self.git.root_prefix {
+            Some(prefix) => prefix.join(file).as_str().to_owned(),
+            None => file.to_owned(),
+        }
+    }
+}
+
+#[async_trait]
+impl Vcs for Gitoxide {
+    async fn get_local_branch(&self) -> miette::Result<Arc<String>> {
+        self.git.get_local_branch().await
+    }
+
+    async fn get_local_branch_revision(&self) -> miette::Result<Arc<String>> {
+        self.git.get_local_branch_revision().await
+    }
+
+    async fn get_default_branch(&self) -> miette::Result<Arc<String>> {
+        self.git.get_default_branch().await
+    }
+
+    async fn get_default_branch_revision(&self) -> miette::Result<Arc<String>> {
+        self.git.get_default_branch_revision().await
+    }
+
+    /// Reuse the object ID from the index when the file's stat information
+    /// matches the index entry, otherwise hash the file's content as a blob.
+    /// Unlike `git hash-object`, content filters (line endings, LFS, etc) are
+    /// not applied to modified files.
+    #[instrument(skip_all)]
+    async fn get_file_hashes(
+        &self,
+        files: &[String], // Workspace relative
+        allow_ignored: bool,
+        batch_size: u16,
+    ) -> miette::Result<BTreeMap<WorkspaceRelativePathBuf, String>> {
+        let Some(repository) = &self.repository else {
+            return self
+                .git
+                .get_file_hashes(files, allow_ignored, batch_size)
+                .await;
+        };
+
+        let index = Self::load_index(repository)?;
+        let index_timestamp = index.timestamp();
+        let stat_options = stat::Options::default();
+        let mut map = BTreeMap::new();
+
+        for file in files {
+            let abs_file = self.git.process.root.join(file);
+
+            if !abs_file.is_file() || (!allow_ignored && self.git.is_ignored(&abs_file)) {
+                continue;
+            }
+
+            let repo_path = self.to_repository_path(file);
+
+            let indexed_hash = index
+                .entry_by_path(BStr::new(repo_path.as_bytes()))
+                .filter(|entry| matches!(entry.mode, Mode::FILE | Mode::FILE_EXECUTABLE))
+                .filter(|entry| !entry.stat.is_racy(index_timestamp, stat_options))
+                .and_then(|entry| {
+                    let metadata = gix::index::fs::Metadata::from_path_no_follow(&abs_file).ok()?;
+                    let stat = Stat::from_fs(&metadata).ok()?;
+
+                    entry
+                        .stat
+                        .matches(&stat, stat_options)
+                        .then(|| entry.id.to_string())
+                });
+
+            let hash = match indexed_hash {
+                Some(hash) => hash,
+                None => gix::objs::compute_hash(
+                    index.object_hash(),
+                    gix::objs::Kind::Blob,
+                    &fs::read_file_bytes(&abs_file)?,
+                )
+                .to_string(),
+            };
+
+            map.insert(self.git.to_workspace_relative_path(&repo_path), hash);
+        }
+
+        Ok(map)
+    }
+
+    /// Read staged content (an empty revision) from the index in-process,
+    /// and only treat files missing from the index as none. Other revisions
+    /// fall back to the `git` binary.
+    #[instrument(skip(self))]
+    async fn get_file_content_at_revision(
+        &self,
+        file: &str,
+        revision: &str,
+    ) -> miette::Result<Option<Arc<String>>> {
+        let (Some(repository), true) = (&self.repository, revision.is_empty()) else {
+            return self.git.get_file_content_at_revision(file, revision).await;
+        };
+
+        let index = Self::load_index(repository)?;
+        let repo_path = self.to_repository_path(file);
+
+        let Some(entry) = index
+            .entry_by_path(BStr::new(repo_path.as_bytes()))
+            .filter(|entry| matches!(entry.mode, Mode::FILE | Mode::FILE_EXECUTABLE))
+        else {
+            return Ok(None);
+        };
+
+        let object = repository
+            .to_thread_local()
+            .find_object(entry.id)
+            .map_err(|error| GitError::LoadObjectFailed {
+                id: entry.id.to_string(),
+                error: Box::new(error),
+            })?;
+
+        Ok(Some(Arc::new(
+            String::from_utf8_lossy(&object.data).into_owned(),
+        )))
+    }
+
+    async fn get_file_tree_at_revision(
+        &self,
+        dir: &str,
+        revision: &str,
+    ) -> miette::Result<Vec<WorkspaceRelativePathBuf>> {
+        self.git.get_file_tree_at_revision(dir, revision).await
+    }
+
+    /// Combine tracked files from the index with untracked files that are
+    /// not ignored. Submodules fall back to the `git` binary.
+    #[instrument(skip(self))]
+    async fn get_file_tree(&self, dir: &str) -> miette::Result<Vec<WorkspaceRelativePathBuf>> {
+        let Some(repository) = &self.repository else {
+            return self.git.get_file_tree(dir).await;
+        };
+
+        let dir = dir.trim_start_matches("./").trim_matches('/');
+        let dir = if dir == "." { "" } else { dir };
+
+        if self.git.is_within_submodule(dir) {
+            return self.git.get_file_tree(dir).await;
+        }
+
+        let root = &self.git.process.root;
+        let repo_dir = self
+            .to_repository_path(dir)
+            .trim_end_matches('/')
+            .to_owned();
+        let repo_dir_prefix = if repo_dir.is_empty() {
+            String::new()
+        } else {
+            format!("{repo_dir}/")
+        };
+        let mut paths = BTreeSet::new();
+
+        // Tracked files
+        let index = Self::load_index(repository)?;
+
+        for entry in index.entries() {
+            if entry.mode.is_submodule() || entry.mode.is_sparse() {
+                continue;
+            }
+
+            let Ok(path) = entry.path(&index).to_str() else {
+                continue;
+            };
+
+            if path.starts_with(&repo_dir_prefix) {
+                let file = self.git.to_workspace_relative_path(path);
+
+                // Tracked files may have been deleted
+                if file.to_logical_path(root).is_file() {
+                    paths.insert(file);
+                }
+            }
+        }
+
+        // Untracked files
+        let walk_root = root.join(dir);
+
+        if walk_root.is_dir() {
+            let walker = WalkBuilder::new(&walk_root)
+                .hidden(false)
+                .git_ignore(true)
+                .git_exclude(true)
+                .git_global(true)
+                .require_git(false)
+                .filter_entry(|entry| {
+                    if entry.depth() == 0 || !entry.file_type().is_some_and(|ty| ty.is_dir()) {
+                        return true;
+                    }
+
+                    // Skip the git directory and nested repositories
+                    entry.file_name() != ".git" && !entry.path().join(".git").exists()
+                })
+                .build();
+
+            for entry in walker.flatten() {
+                if !entry.file_type().is_some_and(|ty| ty.is_file()) {
+                    continue;
+                }
+
+                if let Ok(file) = entry.path().strip_prefix(root) {
+                    if let Ok(file) = WorkspaceRelativePathBuf::from_path(file) {
+                        paths.insert(file);
+                    }
+                }
+            }
+        }
+
+        Ok(paths.into_iter().collect())
+    }
+
+    async fn get_hooks_dir(&self) -> miette::Result<PathBuf> {
+        self.git.get_hooks_dir().await
+    }
+
+    async fn get_repository_root(&self) -> miette::Result<PathBuf> {
+        self.git.get_repository_root().await
+    }
+
+    async fn get_repository_slug(&self) -> miette::Result<Arc<String>> {
+        self.git.get_repository_slug().await
+    }
+
+    async fn get_merge_base_revision(
+        &self,
+        base_revision: &str,
+        revision: &str,
+    ) -> miette::Result<Arc<String>> {
+        self.git
+            .get_merge_base_revision(base_revision, revision)
+            .await
+    }
+
+    async fn get_previous_revision(&self, revision: &str) -> miette::Result<Arc<String>> {
+        self.git.get_previous_revision(revision).await
+    }
+
+    async fn get_touched_files(&self) -> miette::Result<TouchedFiles> {
+        self.git.get_touched_files().await
+    }
+
+    async fn get_touched_files_against_previous_revision(
+        &self,
+        revision: &str,
+    ) -> miette::Result<TouchedFiles> {
+        self.git
+            .get_touched_files_against_previous_revision(revision)
+            .await
+    }
+
+    async fn get_touched_files_between_revisions(
+        &self,
+        base_revision: &str,
+        revision: &str,
+    ) -> miette::Result<TouchedFiles> {
+        self.git
+            .get_touched_files_between_revisions(base_revision, revision)
+            .await
+    }
+
+    async fn get_version(&self) -> miette::Result<Version> {
+        self.git.get_version().await
+    }
+
+    fn is_default_branch(&self, branch: &str) -> bool {
+        self.git.is_default_branch(branch)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.git.is_enabled()
+    }
+
+    fn is_ignored(&self, file: &Path) -> bool {
+        self.git.is_ignored(file)
+    }
+
+    async fn is_shallow_checkout(&self) -> miette::Result<bool> {
+        match &self.repository {
+            Some(repository) => Ok(repository.to_thread_local().is_shallow()),
+            None => self.git.is_shallow_checkout().await,
+        }
+    }
+
+    async fn hide_unstaged_changes(&self) -> miette::Result<bool> {
+        self.git.hide_unstaged_changes().await
+    }
+
+    async fn restore_unstaged_changes(&self) -> miette::Result<()> {
+        self.git.restore_unstaged_changes().await
+    }
+
+    async fn stage_files(&self, files: &[String]) -> miette::Result<()> {
+        self.git.stage_files(files).await
+    }
+}
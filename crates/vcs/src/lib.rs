@@ -1,10 +1,12 @@
 mod git;
 mod git_submodule;
+mod gitoxide;
 mod process_cache;
 mod touched_files;
 mod vcs;
 
 pub use git::*;
+pub use gitoxide::*;
 pub use touched_files::*;
 pub use vcs::*;
 
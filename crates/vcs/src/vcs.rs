@@ -31,10 +31,28 @@ pub trait Vcs: Debug {
         batch_size: u16,
     ) -> miette::Result<BTreeMap<WorkspaceRelativePathBuf, String>>;
 
+    /// Get the content of a file at the provided revision, or `None` if the file
+    /// does not exist at that revision. An empty revision will read the file from
+    /// the index (staging area). File *must* be relative from the workspace root.
+    async fn get_file_content_at_revision(
+        &self,
+        file: &str,
+        revision: &str,
+    ) -> miette::Result<Option<Arc<String>>>;
+
     /// Get a list of all files in the provided directory, recursing through all sub-directories.
     /// Directory *must* be relative from the workspace root.
     async fn get_file_tree(&self, dir: &str) -> miette::Result<Vec<WorkspaceRelativePathBuf>>;
 
+    /// Get a list of all files in the provided directory at the provided revision,
+    /// recursing through all sub-directories. Directory *must* be relative from the
+    /// workspace root.
+    async fn get_file_tree_at_revision(
+        &self,
+        dir: &str,
+        revision: &str,
+    ) -> miette::Result<Vec<WorkspaceRelativePathBuf>>;
+
     /// Return an absolute path to the hooks directory, when applicable.
     async fn get_hooks_dir(&self) -> miette::Result<PathBuf>;
 
@@ -44,6 +62,18 @@ pub trait Vcs: Debug {
     /// Return the repository slug ("moonrepo/moon") of the current checkout.
     async fn get_repository_slug(&self) -> miette::Result<Arc<String>>;
 
+    /// Get the revision that touched files are compared against when diffing
+    /// between 2 revisions, typically their merge base.
+    async fn get_merge_base_revision(
+        &self,
+        base_revision: &str,
+        revision: &str,
+    ) -> miette::Result<Arc<String>>;
+
+    /// Get the revision prior to the provided revision, or the revision itself
+    /// if it has no history.
+    async fn get_previous_revision(&self, revision: &str) -> miette::Result<Arc<String>>;
+
     /// Determine touched files from the local index / working tree.
     async fn get_touched_files(&self) -> miette::Result<TouchedFiles>;
 
@@ -75,6 +105,17 @@ pub trait Vcs: Debug {
     /// Return true if the current repository is a shallow checkout.
     async fn is_shallow_checkout(&self) -> miette::Result<bool>;
 
+    /// Temporarily remove unstaged changes from the working tree, so that only
+    /// staged changes remain. Returns true if any changes were hidden.
+    async fn hide_unstaged_changes(&self) -> miette::Result<bool>;
+
+    /// Restore unstaged changes that were previously hidden.
+    async fn restore_unstaged_changes(&self) -> miette::Result<()>;
+
+    /// Add the provided files to the index. Files *must* be relative from
+    /// the workspace root.
+    async fn stage_files(&self, files: &[String]) -> miette::Result<()>;
+
     /// Return true if the current binary version matches the provided requirement.
     async fn is_version_supported(&self, req: &str) -> miette::Result<bool> {
         let version = self.get_version().await?;
@@ -417,6 +417,287 @@ mod file_hashing {
     }
 }
 
+mod file_content {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_content_at_revision() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/file1.txt", "modified");
+
+        assert_eq!(
+            git.get_file_content_at_revision("foo/file1.txt", "HEAD")
+                .await
+                .unwrap()
+                .as_deref()
+                .map(|content| content.as_str()),
+            Some("")
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_staged_content_for_empty_revision() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/file1.txt", "staged");
+        sandbox.run_git(|cmd| {
+            cmd.args(["add", "foo/file1.txt"]);
+        });
+        sandbox.create_file("foo/file1.txt", "unstaged");
+
+        assert_eq!(
+            git.get_file_content_at_revision("foo/file1.txt", "")
+                .await
+                .unwrap()
+                .as_deref()
+                .map(|content| content.as_str()),
+            Some("staged")
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_if_missing_at_revision() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/extra.txt", "untracked");
+
+        assert_eq!(
+            git.get_file_content_at_revision("foo/extra.txt", "HEAD")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_if_missing_from_index() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/extra.txt", "untracked");
+
+        assert_eq!(
+            git.get_file_content_at_revision("foo/extra.txt", "")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Needed a single revision")]
+    async fn errors_if_revision_doesnt_exist() {
+        let (_sandbox, git) = create_git_sandbox("vcs");
+
+        git.get_file_content_at_revision("foo/file1.txt", "unknown-revision")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn removes_nested_workspace_prefix() {
+        let (sandbox, git) = create_nested_git_sandbox();
+
+        sandbox.create_file("frontend/file.js", "modified");
+
+        assert!(git
+            .get_file_content_at_revision("file.js", "HEAD")
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
+
+mod compared_revisions {
+    use super::*;
+
+    fn commit_file(sandbox: &Sandbox, file: &str) {
+        sandbox.create_file(file, "");
+        sandbox.run_git(|cmd| {
+            cmd.args(["add", file]);
+        });
+        sandbox.run_git(|cmd| {
+            cmd.args(["commit", "-q", "-m", file])
+                .env("GIT_AUTHOR_NAME", "Sandbox")
+                .env("GIT_AUTHOR_EMAIL", "fakeemail@somedomain.dev")
+                .env("GIT_COMMITTER_NAME", "Sandbox")
+                .env("GIT_COMMITTER_EMAIL", "fakeemail@somedomain.dev");
+        });
+    }
+
+    #[tokio::test]
+    async fn returns_previous_revision() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        commit_file(&sandbox, "extra.txt");
+
+        assert_eq!(
+            git.get_previous_revision("HEAD").await.unwrap().as_str(),
+            "HEAD~1"
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_merge_base_revision() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+        let base = git.get_local_branch_revision().await.unwrap();
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["checkout", "-q", "-b", "current"]);
+        });
+
+        commit_file(&sandbox, "extra.txt");
+
+        assert_eq!(
+            git.get_merge_base_revision("master", "current")
+                .await
+                .unwrap(),
+            base
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_base_revision_without_merge_base() {
+        let (_sandbox, git) = create_git_sandbox("vcs");
+
+        assert_eq!(
+            git.get_merge_base_revision("unknown", "HEAD")
+                .await
+                .unwrap()
+                .as_str(),
+            "unknown"
+        );
+    }
+}
+
+mod file_tree_at_revision {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_files_at_revision() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/extra.txt", "untracked");
+
+        assert_eq!(
+            git.get_file_tree_at_revision("foo", "HEAD").await.unwrap(),
+            vec![
+                WorkspaceRelativePathBuf::from("foo/file1.txt"),
+                WorkspaceRelativePathBuf::from("foo/file2.txt"),
+                WorkspaceRelativePathBuf::from("foo/file3.txt"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn excludes_files_removed_after_revision() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["rm", "-q", "baz/file5.txt"]);
+        });
+        sandbox.run_git(|cmd| {
+            cmd.args(["commit", "-q", "-m", "Remove"])
+                .env("GIT_AUTHOR_NAME", "Sandbox")
+                .env("GIT_AUTHOR_EMAIL", "fakeemail@somedomain.dev")
+                .env("GIT_COMMITTER_NAME", "Sandbox")
+                .env("GIT_COMMITTER_EMAIL", "fakeemail@somedomain.dev");
+        });
+
+        assert!(git
+            .get_file_tree_at_revision(".", "HEAD")
+            .await
+            .unwrap()
+            .iter()
+            .all(|file| file != "baz/file5.txt"));
+
+        assert!(git
+            .get_file_tree_at_revision(".", "HEAD~1")
+            .await
+            .unwrap()
+            .contains(&WorkspaceRelativePathBuf::from("baz/file5.txt")));
+    }
+
+    #[tokio::test]
+    async fn removes_nested_workspace_prefix() {
+        let (_sandbox, git) = create_nested_git_sandbox();
+
+        assert!(git
+            .get_file_tree_at_revision(".", "HEAD")
+            .await
+            .unwrap()
+            .contains(&WorkspaceRelativePathBuf::from("file.js")));
+    }
+}
+
+mod unstaged_changes {
+    use super::*;
+
+    #[tokio::test]
+    async fn does_nothing_if_no_unstaged_changes() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/extra.txt", "untracked");
+
+        assert!(!git.hide_unstaged_changes().await.unwrap());
+        assert!(sandbox.path().join("foo/extra.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn hides_and_restores_unstaged_changes() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/file1.txt", "staged");
+        sandbox.run_git(|cmd| {
+            cmd.args(["add", "foo/file1.txt"]);
+        });
+        sandbox.create_file("foo/file1.txt", "unstaged");
+        sandbox.create_file("foo/file2.txt", "unstaged");
+
+        assert!(git.hide_unstaged_changes().await.unwrap());
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("foo/file1.txt")).unwrap(),
+            "staged"
+        );
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("foo/file2.txt")).unwrap(),
+            ""
+        );
+
+        git.restore_unstaged_changes().await.unwrap();
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("foo/file1.txt")).unwrap(),
+            "unstaged"
+        );
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("foo/file2.txt")).unwrap(),
+            "unstaged"
+        );
+        assert!(!git.git_root.join("moon-unstaged.patch").exists());
+    }
+
+    #[tokio::test]
+    async fn stages_files() {
+        let (sandbox, git) = create_git_sandbox("vcs");
+
+        sandbox.create_file("foo/file1.txt", "modified");
+        sandbox.create_file("foo/extra.txt", "untracked");
+
+        git.stage_files(&["foo/file1.txt".into(), "foo/extra.txt".into()])
+            .await
+            .unwrap();
+
+        let touched_files = git.get_touched_files().await.unwrap();
+
+        assert_eq!(
+            touched_files.staged,
+            create_touched_set(["foo/file1.txt", "foo/extra.txt"])
+        );
+        assert!(touched_files.unstaged.is_empty());
+    }
+}
+
 mod file_tree {
     use super::*;
 
@@ -800,3 +1081,147 @@ mod version_cleaning {
         assert_eq!(clean_git_version("git version 1.8.3.1".into()), "1.8.3");
     }
 }
+
+mod gitoxide {
+    use super::*;
+    use moon_vcs::Gitoxide;
+
+    fn create_gitoxide_sandbox(fixture: &str) -> (Sandbox, Gitoxide) {
+        let sandbox = create_sandbox(fixture);
+        sandbox.enable_git();
+        sandbox.create_file(".gitignore", "foo/*.txt");
+
+        let git = Gitoxide::load(sandbox.path(), "master", &["origin".into()]).unwrap();
+
+        (sandbox, git)
+    }
+
+    #[tokio::test]
+    async fn hashes_match_git_binary() {
+        let (_sandbox, git) = create_gitoxide_sandbox("vcs");
+        let files = vec![
+            "foo/file1.txt".to_owned(),
+            "bar/sub/dir/file4.txt".to_owned(),
+            "baz/file5.txt".to_owned(),
+        ];
+
+        assert_eq!(
+            git.get_file_hashes(&files, true, 100).await.unwrap(),
+            git.git.get_file_hashes(&files, true, 100).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn hashes_modified_and_untracked_files() {
+        let (sandbox, git) = create_gitoxide_sandbox("vcs");
+
+        sandbox.create_file("baz/file5.txt", "modified");
+        sandbox.create_file("baz/extra.txt", "untracked");
+
+        let files = vec!["baz/file5.txt".to_owned(), "baz/extra.txt".to_owned()];
+
+        assert_eq!(
+            git.get_file_hashes(&files, false, 100).await.unwrap(),
+            git.git.get_file_hashes(&files, false, 100).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn ignores_files_when_hashing() {
+        let (_sandbox, git) = create_gitoxide_sandbox("vcs");
+
+        assert_eq!(
+            git.get_file_hashes(
+                &["foo/file1.txt".into(), "baz/file5.txt".into()],
+                false,
+                100
+            )
+            .await
+            .unwrap(),
+            BTreeMap::from([(
+                WorkspaceRelativePathBuf::from("baz/file5.txt"),
+                "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391".to_owned()
+            )])
+        );
+    }
+
+    #[tokio::test]
+    async fn file_tree_includes_untracked() {
+        let (sandbox, git) = create_gitoxide_sandbox("vcs");
+
+        sandbox.create_file("baz/extra.txt", "");
+        fs::remove_file(sandbox.path().join("baz/file5.txt")).unwrap();
+
+        assert_eq!(
+            git.get_file_tree("baz").await.unwrap(),
+            vec![
+                WorkspaceRelativePathBuf::from("baz/dir/file6.txt"),
+                WorkspaceRelativePathBuf::from("baz/extra.txt"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn file_tree_removes_nested_workspace_prefix() {
+        let sandbox = create_sandbox("nested");
+        sandbox.enable_git();
+
+        let git = Gitoxide::load(
+            sandbox.path().join("frontend"),
+            "master",
+            &["origin".into()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            git.get_file_tree(".").await.unwrap(),
+            vec![WorkspaceRelativePathBuf::from("file.js")]
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_staged_content_from_index() {
+        let (sandbox, git) = create_gitoxide_sandbox("vcs");
+
+        sandbox.create_file("baz/file5.txt", "staged");
+        sandbox.run_git(|cmd| {
+            cmd.args(["add", "baz/file5.txt"]);
+        });
+        sandbox.create_file("baz/file5.txt", "unstaged");
+        sandbox.create_file("baz/extra.txt", "untracked");
+
+        assert_eq!(
+            git.get_file_content_at_revision("baz/file5.txt", "")
+                .await
+                .unwrap()
+                .as_deref()
+                .map(|content| content.as_str()),
+            Some("staged")
+        );
+        assert_eq!(
+            git.get_file_content_at_revision("baz/extra.txt", "")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Needed a single revision")]
+    async fn errors_if_revision_doesnt_exist() {
+        let (_sandbox, git) = create_gitoxide_sandbox("vcs");
+
+        git.get_file_content_at_revision("baz/file5.txt", "unknown-revision")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn disabled_without_a_repository() {
+        let sandbox = create_sandbox("vcs");
+
+        let git = Gitoxide::load(sandbox.path(), "master", &["origin".into()]).unwrap();
+
+        assert!(!git.is_enabled());
+    }
+}
@@ -16,7 +16,11 @@ use moon_config::{
 };
 use moon_project::Project;
 use moon_project_builder::{ProjectBuilder, ProjectBuilderContext};
-use moon_project_constraints::{enforce_project_type_relationships, enforce_tag_relationships};
+use moon_project_constraints::{
+    enforce_banned_dependencies, enforce_banned_task_dependencies, enforce_layer_relationships,
+    enforce_max_dependency_depth, enforce_project_type_relationships, enforce_required_tasks,
+    enforce_tag_relationships, report_violations,
+};
 use moon_project_graph::{ProjectGraph, ProjectGraphError, ProjectGraphType, ProjectMetadata};
 use moon_task::Target;
 use moon_task_builder::TaskDepsBuilder;
@@ -40,6 +44,14 @@ pub struct WorkspaceBuilderContext<'app> {
     pub extend_project: Emitter<ExtendProjectEvent>,
     pub extend_project_graph: Emitter<ExtendProjectGraphEvent>,
     pub inherited_tasks: &'app InheritedTasksManager,
+
+    /// File names (relative to a project root) that plugins infer from,
+    /// and must invalidate the graph cache when changed.
+    pub plugin_file_names: Vec<String>,
+
+    /// Versions of plugins that extend the graph, keyed by plugin ID.
+    pub plugin_versions: BTreeMap<String, String>,
+
     pub toolchain_config: &'app ToolchainConfig,
     pub vcs: Option<Arc<BoxedVcs>>,
     pub working_dir: &'app Path,
@@ -130,9 +142,11 @@ impl<'app> WorkspaceBuilder<'app> {
         }
 
         // Hash the project graph based on the preloaded state
+        let context = graph.context();
         let mut graph_contents = WorkspaceGraphHash::default();
         graph_contents.add_projects(&graph.project_data);
         graph_contents.add_configs(graph.hash_required_configs().await?);
+        graph_contents.add_plugins(&context.plugin_versions);
         graph_contents.gather_env();
 
         let (hash, _) = cache_engine
@@ -180,9 +194,24 @@ impl<'app> WorkspaceBuilder<'app> {
 
     /// Build the project graph and return a new structure.
     #[instrument(name = "build_workspace_graph", skip_all)]
-    pub async fn build(mut self) -> miette::Result<WorkspaceGraph> {
-        self.enforce_constraints()?;
+    pub async fn build(self) -> miette::Result<WorkspaceGraph> {
+        report_violations(self.collect_constraint_violations())?;
+
+        self.build_graph().await
+    }
+
+    /// Build the project graph without failing on constraint violations,
+    /// and return the violations alongside the new structure.
+    #[instrument(name = "build_workspace_graph_with_violations", skip_all)]
+    pub async fn build_with_violations(
+        self,
+    ) -> miette::Result<(WorkspaceGraph, Vec<miette::Report>)> {
+        let violations = self.collect_constraint_violations();
 
+        Ok((self.build_graph().await?, violations))
+    }
+
+    async fn build_graph(mut self) -> miette::Result<WorkspaceGraph> {
         let context = self.context.take().unwrap();
 
         let mut graph_context = GraphExpanderContext {
@@ -473,6 +502,7 @@ impl<'app> WorkspaceBuilder<'app> {
             .internal_load_project(target.get_project_id().unwrap(), &mut FxHashSet::default())
             .await?;
 
+        let context = self.context();
         let project = self.project_graph.node_weight_mut(project_index).unwrap();
         let mut task = project.tasks.remove(&target.task_id).unwrap();
 
@@ -485,6 +515,10 @@ impl<'app> WorkspaceBuilder<'app> {
                 projects_by_tag: &self.projects_by_tag,
                 task_data: &self.task_data,
             }),
+            enforce_run_in_ci_dependencies: context
+                .workspace_config
+                .constraints
+                .enforce_run_in_ci_dependencies,
             project_id: &project.id,
             project_dependencies: &project.dependencies,
             task: &mut task,
@@ -557,25 +591,40 @@ impl<'app> WorkspaceBuilder<'app> {
         Ok(())
     }
 
-    /// Enforce project constraints and boundaries after all nodes have been inserted.
+    /// Collect project and task constraint and boundary violations after all
+    /// nodes have been inserted, so that they can be reported together.
     #[instrument(skip_all)]
-    fn enforce_constraints(&self) -> miette::Result<()> {
-        debug!("Enforcing project constraints");
+    fn collect_constraint_violations(&self) -> Vec<miette::Report> {
+        debug!("Enforcing project and task constraints");
 
         let context = self.context();
-        let type_relationships = context
-            .workspace_config
-            .constraints
-            .enforce_project_type_relationships;
-        let tag_relationships = &context.workspace_config.constraints.tag_relationships;
-
-        if !type_relationships && tag_relationships.is_empty() {
-            return Ok(());
+        let constraints = &context.workspace_config.constraints;
+        let type_relationships = constraints.enforce_project_type_relationships;
+
+        if !type_relationships
+            && constraints.tag_relationships.is_empty()
+            && constraints.banned_dependencies.is_empty()
+            && constraints.layers.is_empty()
+            && constraints.max_dependency_depth.is_none()
+            && constraints.required_tasks.is_empty()
+            && constraints.banned_task_dependencies.is_empty()
+        {
+            return vec![];
         }
 
         let default_scope = DependencyScope::Build;
+        let mut violations = vec![];
+        let mut track = |result: miette::Result<()>| {
+            if let Err(error) = result {
+                violations.push(error);
+            }
+        };
 
         for (project_index, project) in self.project_graph.node_references() {
+            if !constraints.required_tasks.is_empty() {
+                track(enforce_required_tasks(project, &constraints.required_tasks));
+            }
+
             let deps: Vec<_> = self
                 .project_graph
                 .neighbors_directed(project_index, Direction::Outgoing)
@@ -595,20 +644,134 @@ impl<'app> WorkspaceBuilder<'app> {
 
             for (dep, dep_scope) in deps {
                 if type_relationships {
-                    enforce_project_type_relationships(project, dep, dep_scope)?;
+                    track(enforce_project_type_relationships(project, dep, dep_scope));
+                }
+
+                for (source_tag, required_tags) in &constraints.tag_relationships {
+                    track(enforce_tag_relationships(
+                        project,
+                        source_tag,
+                        dep,
+                        required_tags,
+                    ));
                 }
 
-                for (source_tag, required_tags) in tag_relationships {
-                    enforce_tag_relationships(project, source_tag, dep, required_tags)?;
+                if !constraints.banned_dependencies.is_empty() {
+                    track(enforce_banned_dependencies(
+                        project,
+                        dep,
+                        dep_scope,
+                        &constraints.banned_dependencies,
+                    ));
+                }
+
+                if !constraints.layers.is_empty() {
+                    track(enforce_layer_relationships(
+                        project,
+                        dep,
+                        dep_scope,
+                        &constraints.layers,
+                    ));
                 }
             }
         }
 
-        Ok(())
+        if let Some(max_depth) = constraints.max_dependency_depth {
+            let mut chains = FxHashMap::default();
+
+            for (project_index, project) in self.project_graph.node_references() {
+                let chain = self.get_longest_dependency_chain(
+                    project_index,
+                    &mut chains,
+                    &mut FxHashSet::default(),
+                );
+
+                track(enforce_max_dependency_depth(project, &chain, max_depth));
+            }
+        }
+
+        if !constraints.banned_task_dependencies.is_empty() {
+            let get_project = |target: &Target| {
+                target
+                    .get_project_id()
+                    .and_then(|id| self.project_data.get(id))
+                    .and_then(|data| data.node_index)
+                    .and_then(|index| self.project_graph.node_weight(index))
+            };
+
+            for (task_index, task) in self.task_graph.node_references() {
+                let Some(project) = get_project(&task.target) else {
+                    continue;
+                };
+
+                for dep_index in self
+                    .task_graph
+                    .neighbors_directed(task_index, Direction::Outgoing)
+                {
+                    let dep = &self.task_graph[dep_index];
+
+                    let Some(dep_project) = get_project(&dep.target) else {
+                        continue;
+                    };
+
+                    track(enforce_banned_task_dependencies(
+                        &task.target,
+                        project,
+                        &dep.target,
+                        dep_project,
+                        &constraints.banned_task_dependencies,
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// Find the longest chain of dependencies for a project, excluding the
+    /// implicit root-level project. Chains are cached for each project.
+    fn get_longest_dependency_chain(
+        &self,
+        index: NodeIndex,
+        chains: &mut FxHashMap<NodeIndex, Vec<Id>>,
+        visiting: &mut FxHashSet<NodeIndex>,
+    ) -> Vec<Id> {
+        if let Some(chain) = chains.get(&index) {
+            return chain.clone();
+        }
+
+        // Avoid infinite recursion when there's a cycle
+        if !visiting.insert(index) {
+            return vec![];
+        }
+
+        let mut longest = vec![];
+
+        for edge in self
+            .project_graph
+            .edges_directed(index, Direction::Outgoing)
+        {
+            if matches!(edge.weight(), DependencyScope::Root) {
+                continue;
+            }
+
+            let chain = self.get_longest_dependency_chain(edge.target(), chains, visiting);
+
+            if chain.len() + 1 > longest.len() {
+                longest = vec![self.project_graph[edge.target()].id.clone()];
+                longest.extend(chain);
+            }
+        }
+
+        visiting.remove(&index);
+        chains.insert(index, longest.clone());
+
+        longest
     }
 
     /// When caching the graph, we must hash all project and workspace
-    /// config files that are required to invalidate the cache.
+    /// config files, and plugin inferred files, that are required to
+    /// invalidate the cache.
     async fn hash_required_configs(
         &self,
     ) -> miette::Result<BTreeMap<WorkspaceRelativePathBuf, String>> {
@@ -621,6 +784,10 @@ impl<'app> WorkspaceBuilder<'app> {
             for name in &config_names {
                 configs.push(build_data.source.join(name).to_string());
             }
+
+            for name in &context.plugin_file_names {
+                configs.push(build_data.source.join(name).to_string());
+            }
         }
 
         // Hash all workspace-level config files
@@ -25,6 +25,9 @@ hash_content!(
         // Environment variables required for cache invalidation.
         env: BTreeMap<String, String>,
 
+        // Versions of plugins that extend the graph.
+        plugins: BTreeMap<&'graph str, &'graph str>,
+
         // The graph stores absolute file paths, which breaks moon when
         // running tasks inside and outside of a container at the same time.
         // This flag helps to continuously bust the cache.
@@ -44,6 +47,7 @@ impl Default for WorkspaceGraphHash<'_> {
             projects: BTreeMap::default(),
             configs: BTreeMap::default(),
             env: BTreeMap::default(),
+            plugins: BTreeMap::default(),
             in_docker: is_docker(),
             version: env::var("MOON_VERSION").unwrap_or_default(),
         }
@@ -59,6 +63,14 @@ impl<'graph> WorkspaceGraphHash<'graph> {
         self.configs.extend(configs);
     }
 
+    pub fn add_plugins(&mut self, plugins: &'graph BTreeMap<String, String>) {
+        self.plugins.extend(
+            plugins
+                .iter()
+                .map(|(id, version)| (id.as_str(), version.as_str())),
+        );
+    }
+
     pub fn gather_env(&mut self) {
         for key in [
             // Task options
@@ -1,6 +1,7 @@
 // package.json
 
 use cached::proc_macro::cached;
+use moon_common::FileWriter;
 use moon_lang::config_cache_model;
 use starbase_utils::json::{self, read_file as read_json, JsonValue};
 use std::path::{Path, PathBuf};
@@ -230,7 +231,7 @@ fn write_preserved_json(path: &Path, package: &PackageJsonCache) -> miette::Resu
         };
     }
 
-    json::write_file_with_config(path, &data, true)?;
+    FileWriter::current().write_json_file_with_config(path, &data)?;
 
     Ok(())
 }
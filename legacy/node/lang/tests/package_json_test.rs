@@ -1,3 +1,4 @@
+use moon_common::FileWriter;
 use moon_node_lang::*;
 use moon_test_utils::{assert_fs::prelude::*, create_temp_dir};
 use std::collections::BTreeMap;
@@ -20,6 +21,33 @@ fn preserves_when_saving() {
     assert_eq!(std::fs::read_to_string(file.path()).unwrap(), json);
 }
 
+#[test]
+fn captures_writes_when_checking() {
+    let json = "{\n  \"name\": \"hello\"\n}\n";
+
+    let dir = create_temp_dir();
+    let file = dir.child("package.json");
+    file.write_str(json).unwrap();
+
+    let writer = FileWriter::new(true);
+    let mut package = PackageJsonCache::read(dir.path()).unwrap().unwrap();
+
+    package.add_dependency("foo", "1.0.0", false);
+    writer.scope(|| package.save()).unwrap();
+
+    assert_eq!(std::fs::read_to_string(file.path()).unwrap(), json);
+
+    let writes = writer.take_pending_writes();
+    let write = writes.get(file.path()).unwrap();
+
+    assert_eq!(write.current.as_deref(), Some(json));
+    assert!(write.next.as_ref().unwrap().contains("\"foo\": \"1.0.0\""));
+
+    // Writes are only captured by the writer that was used
+    assert!(FileWriter::new(true).take_pending_writes().is_empty());
+    assert!(!FileWriter::current().is_check_only());
+}
+
 mod add_dependency {
     use super::*;
 
@@ -359,7 +359,7 @@ impl Platform for NodePlatform {
     #[instrument(skip_all)]
     async fn sync_project(
         &self,
-        _context: &ActionContext,
+        context: &ActionContext,
         project: &Project,
         dependencies: &FxHashMap<Id, Arc<Project>>,
     ) -> miette::Result<bool> {
@@ -369,6 +369,7 @@ impl Platform for NodePlatform {
             &self.workspace_root,
             &self.config,
             &self.typescript_config,
+            &context.file_writer,
         )
         .await?;
 
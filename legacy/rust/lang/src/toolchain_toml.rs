@@ -1,7 +1,8 @@
 use cached::proc_macro::cached;
+use moon_common::FileWriter;
 use moon_lang::config_cache_container;
 use serde::{Deserialize, Serialize};
-use starbase_utils::toml::{read_file as read_toml, write_file};
+use starbase_utils::toml::{format, read_file as read_toml};
 use std::path::{Path, PathBuf};
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
@@ -48,9 +49,7 @@ impl ToolchainToml {
 }
 
 pub fn write_toml(path: &Path, toml: &ToolchainToml) -> miette::Result<()> {
-    write_file(path, toml, true)?;
-
-    Ok(())
+    FileWriter::current().write_file(path, format(toml, true)?)
 }
 
 config_cache_container!(
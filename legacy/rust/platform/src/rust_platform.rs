@@ -384,10 +384,11 @@ impl Platform for RustPlatform {
     #[instrument(skip_all)]
     async fn sync_project(
         &self,
-        _context: &ActionContext,
+        context: &ActionContext,
         project: &Project,
         _dependencies: &FxHashMap<Id, Arc<Project>>,
     ) -> miette::Result<bool> {
+        let writer = &context.file_writer;
         let mut mutated_files = false;
 
         let lockfile_path = find_cargo_lock(&project.root, &self.workspace_root);
@@ -411,14 +412,16 @@ impl Platform for RustPlatform {
             let legacy_contents = fs::read_file(&legacy_toolchain_path)?;
 
             if legacy_contents.contains("[toolchain]") {
-                fs::rename(&legacy_toolchain_path, &toolchain_path)?;
+                writer.rename_file(&legacy_toolchain_path, &toolchain_path)?;
             } else {
-                fs::remove_file(&legacy_toolchain_path)?;
+                writer.remove_file(&legacy_toolchain_path)?;
 
-                ToolchainTomlCache::write(
-                    &toolchain_path,
-                    ToolchainToml::new_with_channel(&legacy_contents),
-                )?;
+                writer.scope(|| {
+                    ToolchainTomlCache::write(
+                        &toolchain_path,
+                        ToolchainToml::new_with_channel(&legacy_contents),
+                    )
+                })?;
             }
 
             mutated_files = true;
@@ -429,22 +432,24 @@ impl Platform for RustPlatform {
             let version = self.config.version.as_ref().map(|v| v.to_string()).unwrap();
 
             if toolchain_path.exists() {
-                ToolchainTomlCache::sync(toolchain_path, |cfg| {
-                    if cfg.toolchain.channel.as_ref() != Some(&version) {
-                        debug!(
-                            target: LOG_TARGET,
-                            "Syncing {} configuration file with version {}",
-                            color::file("rust-toolchain.toml"),
-                            color::hash(&version),
-                        );
-
-                        cfg.toolchain.channel = Some(version);
-                        mutated_files = true;
-
-                        return Ok(true);
-                    }
+                writer.scope(|| {
+                    ToolchainTomlCache::sync(toolchain_path, |cfg| {
+                        if cfg.toolchain.channel.as_ref() != Some(&version) {
+                            debug!(
+                                target: LOG_TARGET,
+                                "Syncing {} configuration file with version {}",
+                                color::file("rust-toolchain.toml"),
+                                color::hash(&version),
+                            );
+
+                            cfg.toolchain.channel = Some(version);
+                            mutated_files = true;
+
+                            return Ok(true);
+                        }
 
-                    Ok(false)
+                        Ok(false)
+                    })
                 })?;
             } else {
                 debug!(
@@ -453,10 +458,12 @@ impl Platform for RustPlatform {
                     color::file("rust-toolchain.toml"),
                 );
 
-                ToolchainTomlCache::write(
-                    toolchain_path,
-                    ToolchainToml::new_with_channel(&version),
-                )?;
+                writer.scope(|| {
+                    ToolchainTomlCache::write(
+                        toolchain_path,
+                        ToolchainToml::new_with_channel(&version),
+                    )
+                })?;
 
                 mutated_files = true;
             }
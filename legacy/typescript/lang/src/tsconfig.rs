@@ -1,6 +1,7 @@
 // tsconfig.json
 
 use cached::proc_macro::cached;
+use moon_common::FileWriter;
 use moon_lang::config_cache_model;
 use moon_utils::path::to_relative_virtual_string;
 use starbase_utils::json::{self, read_file as read_json, JsonValue};
@@ -213,7 +214,7 @@ fn write_preserved_json(path: &Path, tsconfig: &TsConfigJsonCache) -> miette::Re
         }
     }
 
-    json::write_file_with_config(path, &data, true)?;
+    FileWriter::current().write_json_file_with_config(path, &data)?;
 
     Ok(())
 }
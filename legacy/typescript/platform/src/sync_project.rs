@@ -1,4 +1,4 @@
-use moon_common::Id;
+use moon_common::{FileWriter, Id};
 use moon_config::TypeScriptConfig;
 use moon_node_lang::PackageJsonCache;
 use moon_project::Project;
@@ -111,7 +111,7 @@ impl<'app> TypeScriptSyncer<'app> {
             ..TsConfigJson::default()
         };
 
-        json::write_file(&tsconfig_path, &json, true)?;
+        FileWriter::current().write_file(&tsconfig_path, json::format(&json, true)?)?;
 
         Ok(true)
     }
@@ -20,6 +20,20 @@ export interface PartialTemplateFrontmatterConfig {
 	to?: string | null;
 }
 
+/** Commands to run before and after a template is generated. */
+export interface TemplateHooksConfig {
+	/**
+	 * A list of commands or moon targets to run after files have been
+	 * generated, from within the destination.
+	 */
+	postGenerate: string[];
+	/**
+	 * A list of commands or moon targets to run before files are
+	 * generated, from within the destination.
+	 */
+	preGenerate: string[];
+}
+
 /** Configuration for a template variable. */
 export interface TemplateVariableBoolSetting {
 	/** The default value of the variable if none was provided. */
@@ -68,6 +82,12 @@ export interface TemplateVariableNumberSetting {
 	default: number;
 	/** Marks the variable as internal, and won't be overwritten via CLI arguments. */
 	internal: boolean;
+	/** The maximum value allowed. */
+	max: number | null;
+	/** A custom error message to display when validation fails. */
+	message: string | null;
+	/** The minimum value allowed. */
+	min: number | null;
 	/** The order in which variables should be prompted for. */
 	order: number | null;
 	/** Prompt the user for a value when the generator is running. */
@@ -83,8 +103,16 @@ export interface TemplateVariableStringSetting {
 	default: string;
 	/** Marks the variable as internal, and won't be overwritten via CLI arguments. */
 	internal: boolean;
+	/** The maximum number of characters allowed. */
+	maxLength: number | null;
+	/** A custom error message to display when validation fails. */
+	message: string | null;
+	/** The minimum number of characters allowed. */
+	minLength: number | null;
 	/** The order in which variables should be prompted for. */
 	order: number | null;
+	/** A regex pattern that the value must match. */
+	pattern: string | null;
 	/** Prompt the user for a value when the generator is running. */
 	prompt: string | null;
 	/** Marks the variable as required, and will not accept an empty value. */
@@ -114,8 +142,21 @@ export interface TemplateConfig {
 	destination: string | null;
 	/** Extends one or many other templates. */
 	extends: string[];
+	/**
+	 * A mapping of file globs (relative from the template root) to conditions,
+	 * rendered with variables. When a condition is falsy, all matching files
+	 * will be excluded from rendering and generation.
+	 */
+	files: Record<string, string>;
+	/** Commands or moon targets to run before and after generating. */
+	hooks: TemplateHooksConfig;
 	/** Overrides the ID of the template, instead of using the folder name. */
 	id: string | null;
+	/**
+	 * Records the generated files and variables in a lockfile within the
+	 * destination, so that the template can be re-applied with `--update`.
+	 */
+	lock: boolean;
 	/** A human-readable title for the template. */
 	title: string;
 	/**
@@ -125,6 +166,20 @@ export interface TemplateConfig {
 	variables: Record<string, TemplateVariable>;
 }
 
+/** Commands to run before and after a template is generated. */
+export interface PartialTemplateHooksConfig {
+	/**
+	 * A list of commands or moon targets to run after files have been
+	 * generated, from within the destination.
+	 */
+	postGenerate?: string[] | null;
+	/**
+	 * A list of commands or moon targets to run before files are
+	 * generated, from within the destination.
+	 */
+	preGenerate?: string[] | null;
+}
+
 /** Configuration for a template variable. */
 export interface PartialTemplateVariableBoolSetting {
 	/** The default value of the variable if none was provided. */
@@ -173,6 +228,12 @@ export interface PartialTemplateVariableNumberSetting {
 	default?: number | null;
 	/** Marks the variable as internal, and won't be overwritten via CLI arguments. */
 	internal?: boolean | null;
+	/** The maximum value allowed. */
+	max?: number | null;
+	/** A custom error message to display when validation fails. */
+	message?: string | null;
+	/** The minimum value allowed. */
+	min?: number | null;
 	/** The order in which variables should be prompted for. */
 	order?: number | null;
 	/** Prompt the user for a value when the generator is running. */
@@ -188,8 +249,16 @@ export interface PartialTemplateVariableStringSetting {
 	default?: string | null;
 	/** Marks the variable as internal, and won't be overwritten via CLI arguments. */
 	internal?: boolean | null;
+	/** The maximum number of characters allowed. */
+	maxLength?: number | null;
+	/** A custom error message to display when validation fails. */
+	message?: string | null;
+	/** The minimum number of characters allowed. */
+	minLength?: number | null;
 	/** The order in which variables should be prompted for. */
 	order?: number | null;
+	/** A regex pattern that the value must match. */
+	pattern?: string | null;
 	/** Prompt the user for a value when the generator is running. */
 	prompt?: string | null;
 	/** Marks the variable as required, and will not accept an empty value. */
@@ -219,8 +288,21 @@ export interface PartialTemplateConfig {
 	destination?: string | null;
 	/** Extends one or many other templates. */
 	extends?: string[] | null;
+	/**
+	 * A mapping of file globs (relative from the template root) to conditions,
+	 * rendered with variables. When a condition is falsy, all matching files
+	 * will be excluded from rendering and generation.
+	 */
+	files?: Record<string, string> | null;
+	/** Commands or moon targets to run before and after generating. */
+	hooks?: PartialTemplateHooksConfig | null;
 	/** Overrides the ID of the template, instead of using the folder name. */
 	id?: string | null;
+	/**
+	 * Records the generated files and variables in a lockfile within the
+	 * destination, so that the template can be re-applied with `--update`.
+	 */
+	lock?: boolean | null;
 	/** A human-readable title for the template. */
 	title?: string | null;
 	/**
@@ -21,6 +21,11 @@ export interface CodeownersConfig {
 	 * @type {'file-source' | 'project-name'}
 	 */
 	orderBy: CodeownersOrderBy;
+	/**
+	 * Generates a JSON ownership map at `.moon/codeowners.json`, alongside
+	 * the `CODEOWNERS` file, for consumption by custom tooling.
+	 */
+	ownershipMap: boolean;
 	/**
 	 * Bitbucket and GitLab only. The number of approvals required for the
 	 * request to be satisfied. This will be applied to all paths.
@@ -35,6 +40,16 @@ export interface CodeownersConfig {
 
 /** Configures boundaries and constraints between projects. */
 export interface ConstraintsConfig {
+	/**
+	 * Forbids projects from depending on other projects. Requires a mapping
+	 * of project IDs or `#tag`s, to a list of banned project IDs or `#tag`s.
+	 */
+	bannedDependencies: Record<string, string[]>;
+	/**
+	 * Forbids tasks from being depended on by other tasks. Requires a list
+	 * of targets, using the `:task`, `project:task`, or `#tag:task` scopes.
+	 */
+	bannedTaskDependencies: string[];
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `type` setting.
@@ -42,6 +57,29 @@ export interface ConstraintsConfig {
 	 * @default true
 	 */
 	enforceProjectTypeRelationships?: boolean;
+	/**
+	 * Enforces that tasks that can run in CI do not depend on tasks
+	 * with the `runInCI` option disabled.
+	 *
+	 * @default true
+	 */
+	enforceRunInCiDependencies?: boolean;
+	/**
+	 * Enforces architectural layers between projects based on each project's
+	 * `tags` setting. Requires an ordered list of tags, from the highest layer
+	 * to the lowest layer, where lower layers cannot depend on higher layers.
+	 */
+	layers: string[];
+	/**
+	 * The maximum depth of a project's dependency chain, where direct
+	 * dependencies have a depth of 1.
+	 */
+	maxDependencyDepth: number | null;
+	/**
+	 * Requires projects to have specific tasks. Requires a mapping of
+	 * project types or `#tag`s, to a list of required task IDs.
+	 */
+	requiredTasks: Record<string, string[]>;
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `tags` setting. Requires a mapping of tags, to acceptable tags.
@@ -49,6 +87,25 @@ export interface ConstraintsConfig {
 	tagRelationships: Record<string, string[]>;
 }
 
+/** Configures aspects of the Dockerfile generation process. */
+export interface DockerFileConfig {
+	/**
+	 * Mount BuildKit caches for package manager stores and the moon
+	 * hydration cache, so that they persist between builds.
+	 */
+	cacheMounts: boolean;
+	/**
+	 * Copy files with `COPY --link`, so that copied layers do not
+	 * depend on the layers before them.
+	 */
+	copyLink: boolean;
+	/**
+	 * Install the toolchain in a separate stage, that is only rebuilt
+	 * when `.prototools` or toolchain configs change.
+	 */
+	toolchainStage: boolean;
+}
+
 /** Configures aspects of the Docker pruning process. */
 export interface DockerPruneConfig {
 	/**
@@ -85,6 +142,8 @@ export interface DockerScaffoldConfig {
 
 /** Configures our Docker integration. */
 export interface DockerConfig {
+	/** Configures aspects of the Dockerfile generation process. */
+	file: DockerFileConfig;
 	/** Configures aspects of the Docker pruning process. */
 	prune: DockerPruneConfig;
 	/** Configures aspects of the Docker scaffolding process. */
@@ -99,9 +158,11 @@ export interface ExperimentsConfig {
 	 */
 	actionPipelineV2?: boolean;
 	/**
-	 * Disallow task relationships with different `runInCI` options.
+	 * No longer used. Use the `constraints.enforceRunInCiDependencies`
+	 * setting instead.
 	 *
 	 * @default true
+	 * @deprecated
 	 */
 	disallowRunInCiMismatch?: boolean;
 	/**
@@ -229,6 +290,12 @@ export interface RunnerConfig {
 	 * @default '7 days'
 	 */
 	cacheLifetime?: string;
+	/**
+	 * List of dependencies in root manifests (like `typescript` in `package.json`)
+	 * that are used by all projects without being declared. When changed, all
+	 * tasks that use the manifest as an input are marked as affected.
+	 */
+	hoistedDependencies: string[];
 	/**
 	 * Automatically inherit color settings for all tasks being ran.
 	 *
@@ -305,6 +372,9 @@ export interface RemoteConfig {
 	tls: RemoteTlsConfig | null;
 }
 
+/** The backend used to interact with the VCS. */
+export type VcsBackend = 'cli' | 'gitoxide';
+
 /** The format to use for generated VCS hook files. */
 export type VcsHookFormat = 'bash' | 'native';
 
@@ -315,10 +385,17 @@ export type VcsManager = 'git';
  * The upstream version control provider, where the repository
  * source code is stored.
  */
-export type VcsProvider = 'bitbucket' | 'github' | 'gitlab' | 'other';
+export type VcsProvider = 'bitbucket' | 'forgejo' | 'gitea' | 'github' | 'gitlab' | 'other';
 
 /** Configures the version control system (VCS). */
 export interface VcsConfig {
+	/**
+	 * The backend used to interact with the VCS.
+	 *
+	 * @default 'cli'
+	 * @type {'cli' | 'gitoxide'}
+	 */
+	backend: VcsBackend;
 	/**
 	 * The default branch / base.
 	 *
@@ -346,7 +423,7 @@ export interface VcsConfig {
 	 * source code is stored.
 	 *
 	 * @default 'github'
-	 * @type {'bitbucket' | 'github' | 'gitlab' | 'other'}
+	 * @type {'bitbucket' | 'forgejo' | 'gitea' | 'github' | 'gitlab' | 'other'}
 	 */
 	provider: VcsProvider;
 	/** List of remote's in which to compare branches against. */
@@ -418,6 +495,11 @@ export interface PartialCodeownersConfig {
 	 * @default 'file-source'
 	 */
 	orderBy?: CodeownersOrderBy | null;
+	/**
+	 * Generates a JSON ownership map at `.moon/codeowners.json`, alongside
+	 * the `CODEOWNERS` file, for consumption by custom tooling.
+	 */
+	ownershipMap?: boolean | null;
 	/**
 	 * Bitbucket and GitLab only. The number of approvals required for the
 	 * request to be satisfied. This will be applied to all paths.
@@ -432,6 +514,16 @@ export interface PartialCodeownersConfig {
 
 /** Configures boundaries and constraints between projects. */
 export interface PartialConstraintsConfig {
+	/**
+	 * Forbids projects from depending on other projects. Requires a mapping
+	 * of project IDs or `#tag`s, to a list of banned project IDs or `#tag`s.
+	 */
+	bannedDependencies?: Record<string, string[]> | null;
+	/**
+	 * Forbids tasks from being depended on by other tasks. Requires a list
+	 * of targets, using the `:task`, `project:task`, or `#tag:task` scopes.
+	 */
+	bannedTaskDependencies?: string[] | null;
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `type` setting.
@@ -439,6 +531,29 @@ export interface PartialConstraintsConfig {
 	 * @default true
 	 */
 	enforceProjectTypeRelationships?: boolean | null;
+	/**
+	 * Enforces that tasks that can run in CI do not depend on tasks
+	 * with the `runInCI` option disabled.
+	 *
+	 * @default true
+	 */
+	enforceRunInCiDependencies?: boolean | null;
+	/**
+	 * Enforces architectural layers between projects based on each project's
+	 * `tags` setting. Requires an ordered list of tags, from the highest layer
+	 * to the lowest layer, where lower layers cannot depend on higher layers.
+	 */
+	layers?: string[] | null;
+	/**
+	 * The maximum depth of a project's dependency chain, where direct
+	 * dependencies have a depth of 1.
+	 */
+	maxDependencyDepth?: number | null;
+	/**
+	 * Requires projects to have specific tasks. Requires a mapping of
+	 * project types or `#tag`s, to a list of required task IDs.
+	 */
+	requiredTasks?: Record<string, string[]> | null;
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `tags` setting. Requires a mapping of tags, to acceptable tags.
@@ -446,6 +561,25 @@ export interface PartialConstraintsConfig {
 	tagRelationships?: Record<string, string[]> | null;
 }
 
+/** Configures aspects of the Dockerfile generation process. */
+export interface PartialDockerFileConfig {
+	/**
+	 * Mount BuildKit caches for package manager stores and the moon
+	 * hydration cache, so that they persist between builds.
+	 */
+	cacheMounts?: boolean | null;
+	/**
+	 * Copy files with `COPY --link`, so that copied layers do not
+	 * depend on the layers before them.
+	 */
+	copyLink?: boolean | null;
+	/**
+	 * Install the toolchain in a separate stage, that is only rebuilt
+	 * when `.prototools` or toolchain configs change.
+	 */
+	toolchainStage?: boolean | null;
+}
+
 /** Configures aspects of the Docker pruning process. */
 export interface PartialDockerPruneConfig {
 	/**
@@ -482,6 +616,8 @@ export interface PartialDockerScaffoldConfig {
 
 /** Configures our Docker integration. */
 export interface PartialDockerConfig {
+	/** Configures aspects of the Dockerfile generation process. */
+	file?: PartialDockerFileConfig | null;
 	/** Configures aspects of the Docker pruning process. */
 	prune?: PartialDockerPruneConfig | null;
 	/** Configures aspects of the Docker scaffolding process. */
@@ -496,9 +632,11 @@ export interface PartialExperimentsConfig {
 	 */
 	actionPipelineV2?: boolean | null;
 	/**
-	 * Disallow task relationships with different `runInCI` options.
+	 * No longer used. Use the `constraints.enforceRunInCiDependencies`
+	 * setting instead.
 	 *
 	 * @default true
+	 * @deprecated
 	 */
 	disallowRunInCiMismatch?: boolean | null;
 	/**
@@ -621,6 +759,12 @@ export interface PartialRunnerConfig {
 	 * @default '7 days'
 	 */
 	cacheLifetime?: string | null;
+	/**
+	 * List of dependencies in root manifests (like `typescript` in `package.json`)
+	 * that are used by all projects without being declared. When changed, all
+	 * tasks that use the manifest as an input are marked as affected.
+	 */
+	hoistedDependencies?: string[] | null;
 	/**
 	 * Automatically inherit color settings for all tasks being ran.
 	 *
@@ -699,6 +843,12 @@ export interface PartialRemoteConfig {
 
 /** Configures the version control system (VCS). */
 export interface PartialVcsConfig {
+	/**
+	 * The backend used to interact with the VCS.
+	 *
+	 * @default 'cli'
+	 */
+	backend?: VcsBackend | null;
 	/**
 	 * The default branch / base.
 	 *
@@ -114,6 +114,7 @@ const sidebars: SidebarsConfig = {
 					label: 'docker',
 					items: [
 						'commands/docker/file',
+						'commands/docker/ignore',
 						'commands/docker/prune',
 						'commands/docker/scaffold',
 						'commands/docker/setup',
@@ -147,8 +148,11 @@ const sidebars: SidebarsConfig = {
 					type: 'category',
 					label: 'query',
 					items: [
+						'commands/query/graph-diff',
+						'commands/query/graph-stats',
 						'commands/query/hash',
 						'commands/query/hash-diff',
+						'commands/query/owners',
 						'commands/query/projects',
 						'commands/query/tasks',
 						'commands/query/touched-files',